@@ -22,19 +22,27 @@ impl PyConsole {
         }
     }
 
-    /// Print text with optional markup style.
-    #[pyo3(signature = (text, style = None))]
-    fn print(&self, text: &str, style: Option<&str>) {
-        if let Some(style_str) = style {
-            let style = Style::parse(style_str);
-            // Create owned string to ensure lifetime safety within function
-            let content = text.to_string();
-            let mut t = Text::from(content); 
-            t.spans[0].style = style;
-            self.inner.print_renderable(&t);
+    /// Print text, parsing inline markup tags (e.g. `[bold]...[/]`) by
+    /// default. Pass `markup=False` to suppress that and print `text`
+    /// verbatim. If `style` is also given, it is applied as a base style
+    /// that each span's own (possibly markup-derived) style layers on top
+    /// of, rather than overwriting it.
+    #[pyo3(signature = (text, style = None, markup = true))]
+    fn print(&self, text: &str, style: Option<&str>, markup: bool) {
+        let mut t = if markup {
+            rich_rust::markup::parse(text)
         } else {
-            self.inner.print(text);
+            Text::from(text.to_string())
+        };
+
+        if let Some(style_str) = style {
+            let base = Style::parse(style_str);
+            for span in t.spans.iter_mut() {
+                span.style = base.combine(&span.style);
+            }
         }
+
+        self.inner.print_renderable(&t);
     }
 
     /// Print a table.
@@ -0,0 +1,23 @@
+use fast_rich::markdown::Markdown;
+use pyo3::prelude::*;
+
+#[pyclass(name = "Markdown")]
+pub struct PyMarkdown {
+    pub(crate) inner: Markdown,
+}
+
+#[pymethods]
+impl PyMarkdown {
+    #[new]
+    fn new(source: &str) -> Self {
+        PyMarkdown {
+            inner: Markdown::new(source),
+        }
+    }
+
+    /// Select a named syntax-highlighting theme for fenced code blocks
+    /// (e.g. `"monokai"`, `"base16 ocean dark"`, `"solarized dark"`).
+    fn syntax_theme(&mut self, name: &str) {
+        self.inner = self.inner.clone().syntax_theme(name);
+    }
+}
@@ -2,6 +2,7 @@ use crate::style::PyStyle;
 use fast_rich::style::Style;
 use fast_rich::text::{Span, Text};
 use pyo3::prelude::*;
+use std::borrow::Cow;
 
 #[pyclass(name = "Span")]
 #[derive(Clone)]
@@ -59,10 +60,61 @@ impl PyText {
         self.inner.push_styled(text.to_string(), s);
     }
 
-    fn set_style(&mut self, _start: usize, _end: usize, _style: PyStyle) {
-        // Simple approximation: apply style to spans that overlap?
-        // fast-rich native Text doesn't support range-based styling post-construction easily yet without split_at
-        // For now, this might be a no-op or limited implementation.
-        // TODO: Implement proper span splitting for arbitrary range styling in core lib first?
+    /// Apply `style` to the character range `[start, end)`, splitting any
+    /// spans that straddle the boundaries so only the in-range portion is
+    /// restyled and the rest of the text is left untouched.
+    fn set_style(&mut self, start: usize, end: usize, style: PyStyle) {
+        if start >= end {
+            return;
+        }
+
+        let mut result: Vec<Span> = Vec::with_capacity(self.inner.spans.len());
+        let mut offset = 0usize;
+
+        for span in self.inner.spans.drain(..) {
+            let text = span.text.into_owned();
+            let char_count = text.chars().count();
+            let span_start = offset;
+            let span_end = offset + char_count;
+            offset = span_end;
+
+            // No overlap with [start, end): keep the span as-is.
+            if span_end <= start || span_start >= end {
+                result.push(Span {
+                    text: Cow::Owned(text),
+                    style: span.style,
+                    link: span.link,
+                });
+                continue;
+            }
+
+            let chars: Vec<char> = text.chars().collect();
+            let local_start = start.saturating_sub(span_start).min(chars.len());
+            let local_end = end.saturating_sub(span_start).min(chars.len());
+
+            if local_start > 0 {
+                result.push(Span {
+                    text: Cow::Owned(chars[..local_start].iter().collect()),
+                    style: span.style,
+                    link: span.link.clone(),
+                });
+            }
+
+            result.push(Span {
+                text: Cow::Owned(chars[local_start..local_end].iter().collect()),
+                style: span.style.combine(style.inner),
+                link: span.link.clone(),
+            });
+
+            if local_end < chars.len() {
+                result.push(Span {
+                    text: Cow::Owned(chars[local_end..].iter().collect()),
+                    style: span.style,
+                    link: span.link,
+                });
+            }
+        }
+
+        self.inner.spans = result;
     }
 }
@@ -31,39 +31,23 @@ fn main() {
 
     // 2. Layout (Split Views)
     console.print("[bold]2. Layout (Splits)[/]");
-    
-    // Create lead layout
+
+    // Build the split first, then reach back in by name to attach content --
+    // this is what `get_mut` is for.
     let mut root = Layout::new();
     root.split_row(vec![
-        Layout::new().with_name("Left").with_name("Left"),
-        Layout::new().with_name("Right").with_name("Right"),
+        Layout::new().with_name("Left"),
+        Layout::new().with_name("Right"),
     ]);
 
-    // Update left column
-    let mut left = Layout::new();
-    left.update(Panel::new("Left Column\nRow 1\nRow 2"));
-    
-    // Update right column
-    let mut right = Layout::new();
-    right.update(Panel::new("Right Column\nOnly 1 Row"));
+    root.get_mut("Left")
+        .unwrap()
+        .update(Panel::new("Left Column\nRow 1\nRow 2"));
+    root.get_mut("Right")
+        .unwrap()
+        .update(Panel::new("Right Column\nOnly 1 Row"));
 
-    // In a real layout engine we'd attach these, but current Layout implementation 
-    // is a tree that renders children.
-    // The current Rust implementation of Split is basic.
-    // Let's manually render them side-by-side using Columns if available, otherwise stack
-    console.print("[dim]Note: Layout engine is WIP, stacking panels:[/]");
-    
-    console.print_renderable(&Panel::new("Top Section"));
-    
-    // Columns (if supported)
-    // The current codebase has 'columns' module
-    #[cfg(feature = "std")]
-    {
-       // If Columns implemented
-    }
+    console.print_renderable(&root);
 
-    console.print_renderable(&left);
-    console.print_renderable(&right);
-    
     console.rule("[bold green]End Panel Demo[/]");
 }
@@ -36,7 +36,7 @@ fn main() {
     println!("Now using with_alternate_screen closure...\n");
     thread::sleep(Duration::from_secs(1));
 
-    with_alternate_screen(|screen| {
+    with_alternate_screen(|screen: &mut AlternateScreen| {
         screen.clear()?;
         println!("Inside alternate screen (via closure)");
         println!("This automatically cleans up when done.");
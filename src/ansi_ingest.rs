@@ -0,0 +1,252 @@
+//! Ingesting raw terminal byte streams into the crate's `Text`/`Span` model.
+//!
+//! [`ingest`] replays a captured stream of terminal output -- SGR color and
+//! attribute codes, carriage returns, backspaces, and tabs -- against a small
+//! virtual screen buffer, the same way a real terminal would, so
+//! overwrite-heavy output (progress bars, spinners, REPL prompts) ends up
+//! looking the way it would have looked live, rather than as a garbled
+//! concatenation of every byte ever written.
+
+use crate::style::{AnsiElement, AnsiElementIterator, Style};
+use crate::text::{Span, Text};
+
+/// Parse `input` as a stream of pre-existing ANSI/SGR escapes -- the kind
+/// piped in from another tool's colored output -- into a styled [`Text`],
+/// discarding the escape bytes so width measurement and re-rendering (e.g.
+/// wrapping, placement inside a [`Panel`](crate::panel::Panel)) see only the
+/// visible content. This is the same replay [`ingest`] does; the name just
+/// matches the call site you'd reach for when the input is a complete,
+/// already-captured string rather than a live stream.
+pub fn from_ansi(input: &str) -> Text {
+    ingest(input)
+}
+
+/// [`ingest`] with the default tab width (8). Most callers don't have a
+/// [`Console`](crate::console::Console) in hand to read its configured
+/// [`tab_width`](crate::console::Console::tab_width) from, so this is the
+/// entry point used when no override is needed.
+pub fn ingest(input: &str) -> Text {
+    ingest_with_tab_width(input, 8)
+}
+
+/// Replay `input` against a virtual screen (tracking SGR style, `\r`, `\b`,
+/// and `\t`) and fold the result into a [`Text`], one line per virtual
+/// screen row, collapsing runs of identically-styled cells into single
+/// spans.
+///
+/// `\t` expands to the next tab stop based on the running *visible* column
+/// -- escape sequences contribute zero width and wide glyphs count as two
+/// columns, the same accounting [`crate::style::ansi_slice_by_width`] uses
+/// -- so a tab following colored text lands on the same column it would on
+/// a real terminal, regardless of how much SGR framing precedes it.
+pub fn ingest_with_tab_width(input: &str, tab_width: usize) -> Text {
+    use unicode_width::UnicodeWidthChar;
+
+    let tab_width = tab_width.max(1);
+    let mut lines: Vec<Vec<(char, Style, Option<String>)>> = vec![Vec::new()];
+    let mut column = 0usize;
+    let mut current_style = Style::new();
+    let mut current_link: Option<String> = None;
+
+    for element in AnsiElementIterator::new(input) {
+        match element {
+            AnsiElement::Sgr(style) => current_style = style,
+            AnsiElement::Link(link) => current_link = link,
+            AnsiElement::Text(text) => {
+                for ch in text.chars() {
+                    match ch {
+                        '\n' => {
+                            lines.push(Vec::new());
+                            column = 0;
+                        }
+                        '\r' => column = 0,
+                        '\x08' => column = column.saturating_sub(1),
+                        '\t' => {
+                            let next_stop = column + (tab_width - column % tab_width);
+                            let line = lines.last_mut().expect("lines always has at least one row");
+                            while line.len() < next_stop {
+                                line.push((' ', Style::new(), None));
+                            }
+                            for cell in &mut line[column..next_stop] {
+                                *cell = (' ', current_style, current_link.clone());
+                            }
+                            column = next_stop;
+                        }
+                        _ => {
+                            let line = lines.last_mut().expect("lines always has at least one row");
+                            let width = ch.width().unwrap_or(0);
+                            let cells = width.max(1);
+                            while line.len() < column + cells {
+                                line.push((' ', Style::new(), None));
+                            }
+                            line[column] = (ch, current_style, current_link.clone());
+                            // The second column of a wide glyph is a continuation
+                            // cell, not a blank one.
+                            for cell in &mut line[column + 1..column + cells] {
+                                *cell = (WIDE_CONTINUATION, current_style, current_link.clone());
+                            }
+                            column += width;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut spans: Vec<Span> = Vec::new();
+    let last_index = lines.len() - 1;
+    for (i, line) in lines.iter().enumerate() {
+        for (run_text, style, link) in collapse_runs(line) {
+            let mut span = Span::styled(run_text, style);
+            span.link = link;
+            spans.push(span);
+        }
+        if i != last_index {
+            spans.push(Span::raw("\n".to_string()));
+        }
+    }
+    Text::from_spans(spans)
+}
+
+/// Marks the cell covered by the second column of a wide glyph.
+const WIDE_CONTINUATION: char = '\0';
+
+/// Group consecutive cells sharing the same style and hyperlink into
+/// `(text, style, link)` runs, skipping wide-glyph continuation cells.
+fn collapse_runs(line: &[(char, Style, Option<String>)]) -> Vec<(String, Style, Option<String>)> {
+    let mut runs: Vec<(String, Style, Option<String>)> = Vec::new();
+    for (ch, style, link) in line {
+        if *ch == WIDE_CONTINUATION {
+            continue;
+        }
+        match runs.last_mut() {
+            Some((text, last_style, last_link)) if *last_style == *style && *last_link == *link => {
+                text.push(*ch)
+            }
+            _ => runs.push((ch.to_string(), *style, link.clone())),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ingest_plain_text() {
+        let text = ingest("hello");
+        assert_eq!(text.plain_text(), "hello");
+    }
+
+    #[test]
+    fn test_ingest_carriage_return_overwrites() {
+        // "foo" then "\r" then "ba" overwrites the first two cells: "foo" -> "bao"
+        let text = ingest("foo\rba");
+        assert_eq!(text.plain_text(), "bao");
+    }
+
+    #[test]
+    fn test_ingest_backspace_moves_cursor_back() {
+        // "abc" then two backspaces then "X" overwrites the 'b': "aXc"
+        let text = ingest("abc\x08\x08X");
+        assert_eq!(text.plain_text(), "aXc");
+    }
+
+    #[test]
+    fn test_ingest_tracks_sgr_style_across_runs() {
+        // Style changes shouldn't disturb the underlying cell text.
+        let text = ingest("\x1b[31mred\x1b[0mplain");
+        assert_eq!(text.plain_text(), "redplain");
+    }
+
+    #[test]
+    fn test_ingest_newline_starts_new_row() {
+        let text = ingest("line1\nline2");
+        assert_eq!(text.plain_text(), "line1\nline2");
+    }
+
+    #[test]
+    fn test_from_ansi_decodes_sgr_color_and_discards_escapes() {
+        let text = from_ansi("\x1b[1;31mred bold\x1b[0m plain");
+        assert_eq!(text.plain_text(), "red bold plain");
+    }
+
+    #[test]
+    fn test_collapse_runs_groups_identical_styles() {
+        let line = vec![
+            ('a', Style::new(), None),
+            ('b', Style::new(), None),
+            ('c', Style::new().bold(), None),
+        ];
+        let runs = collapse_runs(&line);
+        assert_eq!(
+            runs,
+            vec![
+                ("ab".to_string(), Style::new(), None),
+                ("c".to_string(), Style::new().bold(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ingest_preserves_osc8_hyperlink_as_a_span_link() {
+        let text = ingest("\x1b]8;;https://example.com\x1b\\click\x1b]8;;\x1b\\ plain");
+        assert_eq!(text.plain_text(), "click plain");
+
+        let context = crate::console::RenderContext::default();
+        let segments = text.render(&context);
+        let spans: Vec<&Span> = segments.iter().flat_map(|segment| segment.spans.iter()).collect();
+
+        let link_span = spans.iter().find(|span| span.text == "click").expect("a span for the linked run");
+        assert_eq!(link_span.link.as_deref(), Some("https://example.com"));
+
+        let plain_span = spans
+            .iter()
+            .find(|span| span.text == " plain")
+            .expect("a span for the trailing plain run");
+        assert_eq!(plain_span.link, None);
+    }
+
+    #[test]
+    fn test_ingest_drops_unmodeled_osc_sequences_without_corrupting_text() {
+        // OSC 2 (set window title) isn't modeled; it should be silently
+        // consumed rather than leaking raw escape bytes into the output.
+        let text = ingest("before\x1b]2;window title\x07after");
+        assert_eq!(text.plain_text(), "beforeafter");
+    }
+
+    #[test]
+    fn test_ingest_expands_tab_to_next_stop() {
+        let text = ingest_with_tab_width("a\tb", 4);
+        assert_eq!(text.plain_text(), "a   b");
+
+        let text = ingest_with_tab_width("ab\tc", 4);
+        assert_eq!(text.plain_text(), "ab  c");
+    }
+
+    #[test]
+    fn test_ingest_tab_expansion_ignores_sgr_escape_width() {
+        // A tab's column only counts visible characters -- the SGR escapes
+        // framing "a" must not push it past the next tab stop, so both
+        // lines should expand to the exact same number of spaces.
+        let plain = ingest_with_tab_width("a\tb", 4);
+        let styled = ingest_with_tab_width("\x1b[31ma\x1b[0m\tb", 4);
+        assert_eq!(plain.plain_text(), styled.plain_text());
+        assert_eq!(styled.plain_text(), "a   b");
+    }
+
+    #[test]
+    fn test_ingest_tab_expansion_counts_wide_glyphs_as_two_columns() {
+        // "\u{4e2d}" (中) occupies two display columns, so a tab width of 4
+        // starting right after it only needs two spaces, not three.
+        let text = ingest_with_tab_width("\u{4e2d}\tb", 4);
+        assert_eq!(text.plain_text(), "\u{4e2d}  b");
+    }
+
+    #[test]
+    fn test_ingest_tab_resets_to_column_zero_on_newline() {
+        let text = ingest_with_tab_width("abc\n\td", 4);
+        assert_eq!(text.plain_text(), "abc\n    d");
+    }
+}
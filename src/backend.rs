@@ -0,0 +1,245 @@
+//! Pluggable terminal backend.
+//!
+//! [`AlternateScreen`](crate::screen::AlternateScreen) and the full-screen
+//! parts of [`Live`](crate::live::Live) need only a handful of terminal
+//! primitives -- enter/leave the alternate screen, enable/disable raw mode,
+//! show/hide the cursor, move the cursor, clear the screen, query its size,
+//! and write raw bytes. [`Backend`] abstracts exactly those, so the default
+//! [`CrosstermBackend`] can be swapped for [`TestBackend`] in tests (to
+//! assert on full-screen output deterministically, without a real tty) or
+//! for another terminal library entirely, without touching call sites.
+
+use std::io::{self, Write};
+
+/// The terminal operations needed to drive full-screen/alternate-screen
+/// rendering.
+pub trait Backend {
+    /// Switch to the alternate screen buffer.
+    fn enter_alternate_screen(&mut self) -> io::Result<()>;
+    /// Switch back to the primary screen buffer.
+    fn leave_alternate_screen(&mut self) -> io::Result<()>;
+    /// Put the terminal into raw mode (no line buffering or echo).
+    fn enable_raw_mode(&mut self) -> io::Result<()>;
+    /// Restore the terminal's normal (cooked) mode.
+    fn disable_raw_mode(&mut self) -> io::Result<()>;
+    /// Hide the cursor.
+    fn hide_cursor(&mut self) -> io::Result<()>;
+    /// Show the cursor.
+    fn show_cursor(&mut self) -> io::Result<()>;
+    /// Move the cursor to `(column, row)`, both zero-indexed.
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()>;
+    /// Clear the whole screen.
+    fn clear(&mut self) -> io::Result<()>;
+    /// The terminal's current `(columns, rows)`.
+    fn size(&self) -> io::Result<(u16, u16)>;
+    /// Write already-styled bytes to the terminal.
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()>;
+    /// Flush any buffered output.
+    fn flush(&mut self) -> io::Result<()>;
+}
+
+/// The default [`Backend`], driving the real terminal via `crossterm`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CrosstermBackend;
+
+impl Backend for CrosstermBackend {
+    fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        crossterm::execute!(io::stdout(), crossterm::terminal::EnterAlternateScreen)
+    }
+
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        crossterm::execute!(io::stdout(), crossterm::terminal::LeaveAlternateScreen)
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::disable_raw_mode()
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        crossterm::execute!(io::stdout(), crossterm::cursor::Hide)
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        crossterm::execute!(io::stdout(), crossterm::cursor::Show)
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()> {
+        crossterm::execute!(io::stdout(), crossterm::cursor::MoveTo(column, row))
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        crossterm::execute!(
+            io::stdout(),
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All)
+        )
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        io::stdout().write_all(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
+
+/// A single terminal operation, as recorded by [`TestBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendOp {
+    EnterAlternateScreen,
+    LeaveAlternateScreen,
+    EnableRawMode,
+    DisableRawMode,
+    HideCursor,
+    ShowCursor,
+    MoveTo(u16, u16),
+    Clear,
+    Write(Vec<u8>),
+    Flush,
+}
+
+/// An in-memory [`Backend`] that records every operation instead of
+/// touching a real terminal, so full-screen rendering can be asserted on
+/// deterministically in tests.
+#[derive(Debug, Default, Clone)]
+pub struct TestBackend {
+    ops: Vec<BackendOp>,
+    size: (u16, u16),
+}
+
+impl TestBackend {
+    /// Create a `TestBackend` that reports `(columns, rows)` from
+    /// [`Backend::size`].
+    pub fn new(columns: u16, rows: u16) -> Self {
+        TestBackend {
+            ops: Vec::new(),
+            size: (columns, rows),
+        }
+    }
+
+    /// The operations recorded so far, in call order.
+    pub fn ops(&self) -> &[BackendOp] {
+        &self.ops
+    }
+
+    /// All bytes passed to [`Backend::write_bytes`], concatenated in order.
+    pub fn written(&self) -> Vec<u8> {
+        self.ops
+            .iter()
+            .filter_map(|op| match op {
+                BackendOp::Write(bytes) => Some(bytes.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+impl Backend for TestBackend {
+    fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::EnterAlternateScreen);
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::LeaveAlternateScreen);
+        Ok(())
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::EnableRawMode);
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::DisableRawMode);
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::HideCursor);
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::ShowCursor);
+        Ok(())
+    }
+
+    fn move_to(&mut self, column: u16, row: u16) -> io::Result<()> {
+        self.ops.push(BackendOp::MoveTo(column, row));
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::Clear);
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.size)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.ops.push(BackendOp::Write(bytes.to_vec()));
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.ops.push(BackendOp::Flush);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_backend_reports_its_configured_size() {
+        let backend = TestBackend::new(120, 40);
+        assert_eq!(backend.size().unwrap(), (120, 40));
+    }
+
+    #[test]
+    fn test_test_backend_records_ops_in_order() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.enter_alternate_screen().unwrap();
+        backend.hide_cursor().unwrap();
+        backend.move_to(3, 5).unwrap();
+        backend.leave_alternate_screen().unwrap();
+
+        assert_eq!(
+            backend.ops(),
+            &[
+                BackendOp::EnterAlternateScreen,
+                BackendOp::HideCursor,
+                BackendOp::MoveTo(3, 5),
+                BackendOp::LeaveAlternateScreen,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_test_backend_collects_written_bytes() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.write_bytes(b"hello ").unwrap();
+        backend.write_bytes(b"world").unwrap();
+
+        assert_eq!(backend.written(), b"hello world".to_vec());
+    }
+
+    #[test]
+    fn test_test_backend_default_size_is_zero() {
+        let backend = TestBackend::default();
+        assert_eq!(backend.size().unwrap(), (0, 0));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Provides horizontal bar charts with customizable colors and widths.
 
+use crate::bidi::{self, TextDirection};
 use crate::console::RenderContext;
 use crate::renderable::{Renderable, Segment};
 use crate::style::{Color, Style};
@@ -35,6 +36,11 @@ impl BarData {
     }
 }
 
+/// Horizontal eighth-block glyphs used by [`BarChart::sub_cell`] to fill in
+/// the fractional remainder of a bar, from one eighth wide (`▏`) to a full
+/// cell (`█`). Indexed `[0]` = one eighth through `[7]` = full.
+const SUB_CELL_GLYPHS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
 /// A horizontal bar chart.
 #[derive(Debug, Clone)]
 pub struct BarChart {
@@ -43,6 +49,7 @@ pub struct BarChart {
     bar_char: char,
     default_color: Color,
     show_values: bool,
+    sub_cell: bool,
 }
 
 impl BarChart {
@@ -54,6 +61,7 @@ impl BarChart {
             bar_char: '█',
             default_color: Color::Green,
             show_values: true,
+            sub_cell: false,
         }
     }
 
@@ -92,6 +100,15 @@ impl BarChart {
         self.show_values = show;
         self
     }
+
+    /// Render each bar at eighth-cell resolution using the horizontal
+    /// block glyphs (`▏▎▍▌▋▊▉█`) instead of whole `bar_char` steps, so
+    /// small differences between close values stay visible even at a
+    /// narrow `width`.
+    pub fn sub_cell(mut self, enabled: bool) -> Self {
+        self.sub_cell = enabled;
+        self
+    }
 }
 
 impl Default for BarChart {
@@ -112,8 +129,14 @@ impl Renderable for BarChart {
             return vec![Segment::empty_line()];
         }
 
-        // Find max label width
-        let max_label_width = self.bars.iter().map(|b| b.label.len()).max().unwrap_or(0);
+        // Find max label width, measured in terminal cells rather than
+        // bytes so wide CJK labels pad correctly.
+        let max_label_width = self
+            .bars
+            .iter()
+            .map(|b| bidi::display_width(&b.label))
+            .max()
+            .unwrap_or(0);
 
         // Calculate bar width
         let value_width = if self.show_values { 12 } else { 0 }; // Space for value display
@@ -124,23 +147,55 @@ impl Renderable for BarChart {
         let mut segments = Vec::new();
 
         for bar in &self.bars {
-            // Calculate bar length
-            let bar_length = ((bar.value / max_value) * bar_width as f64).round() as usize;
-            let bar_length = bar_length.min(bar_width);
-
             // Choose color
             let color = bar.color.unwrap_or(self.default_color);
             let style = Style::new().foreground(color);
 
             let mut spans = Vec::new();
 
-            // Label (left-aligned, padded)
-            let label_padded = format!("{:<width$}", bar.label, width = max_label_width);
+            // Label. RTL labels (auto-detected, or forced via the
+            // context's direction hint) are visually reordered and
+            // right-aligned so they read correctly in a BiDi terminal;
+            // LTR labels stay left-aligned. Either way padding is sized
+            // by display width, not byte length.
+            let label_is_rtl = match context.direction {
+                TextDirection::Rtl => true,
+                TextDirection::Ltr => false,
+                TextDirection::Auto => bidi::is_rtl(&bar.label),
+            };
+            let display_label = if label_is_rtl {
+                bidi::reorder_for_display(&bar.label, TextDirection::Rtl)
+            } else {
+                bar.label.clone()
+            };
+            let pad = " ".repeat(max_label_width.saturating_sub(bidi::display_width(&display_label)));
+            let label_padded = if label_is_rtl {
+                format!("{pad}{display_label}")
+            } else {
+                format!("{display_label}{pad}")
+            };
             spans.push(Span::styled(label_padded, Style::new().dim()));
             spans.push(Span::raw(" "));
 
             // Bar
-            let bar_str = self.bar_char.to_string().repeat(bar_length);
+            let (bar_str, bar_length) = if self.sub_cell {
+                let exact = (bar.value / max_value) * bar_width as f64;
+                let exact = exact.min(bar_width as f64);
+                let full_cells = exact.floor() as usize;
+                let remainder = exact - full_cells as f64;
+                let mut s = self.bar_char.to_string().repeat(full_cells);
+                let partial_index = (remainder * 8.0).round() as usize;
+                let mut length = full_cells;
+                if partial_index > 0 {
+                    s.push(SUB_CELL_GLYPHS[(partial_index - 1).min(7)]);
+                    length += 1;
+                }
+                (s, length.min(bar_width))
+            } else {
+                let bar_length = ((bar.value / max_value) * bar_width as f64).round() as usize;
+                let bar_length = bar_length.min(bar_width);
+                (self.bar_char.to_string().repeat(bar_length), bar_length)
+            };
             spans.push(Span::styled(bar_str, style));
 
             // Value (if enabled)
@@ -159,3 +214,141 @@ impl Renderable for BarChart {
         segments
     }
 }
+
+/// Vertical eighth-block glyphs used by [`Sparkline`], from the shortest
+/// (`▁`) to the tallest (`█`).
+const SPARKLINE_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A compact single-line plot of a numeric series using the vertical
+/// eighth-block glyphs, mapping each value to one of eight heights
+/// relative to the series maximum.
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+    values: Vec<f64>,
+    style: Style,
+}
+
+impl Sparkline {
+    /// Create a sparkline over `values`.
+    pub fn new(values: impl Into<Vec<f64>>) -> Self {
+        Sparkline {
+            values: values.into(),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the style applied to the whole sparkline.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Renderable for Sparkline {
+    fn render(&self, _context: &RenderContext) -> Vec<Segment> {
+        if self.values.is_empty() {
+            return vec![Segment::empty_line()];
+        }
+
+        let max_value = self.values.iter().cloned().fold(0.0, f64::max);
+        let line: String = self
+            .values
+            .iter()
+            .map(|&v| {
+                if max_value <= 0.0 {
+                    SPARKLINE_GLYPHS[0]
+                } else {
+                    let index = ((v.max(0.0) / max_value) * 7.0).round() as usize;
+                    SPARKLINE_GLYPHS[index.min(7)]
+                }
+            })
+            .collect();
+
+        vec![Segment::line(vec![Span::styled(line, self.style)])]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_chart_pads_labels_by_display_width_not_byte_length() {
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let mut chart = BarChart::new();
+        chart.bar("ab", 1.0); // 2 display cells, 2 bytes
+        chart.bar("你好", 1.0); // 2 display cells, 6 bytes
+        let chart = chart.show_values(false);
+        let segments = chart.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        // Both labels are 2 cells wide, so the bar should start at the same
+        // column on both rows.
+        let bar_start = |line: &str| line.find('█');
+        assert_eq!(bar_start(&plain[0]), bar_start(&plain[1]));
+    }
+
+    #[test]
+    fn test_bar_chart_right_aligns_rtl_label() {
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let mut chart = BarChart::new();
+        chart.bar("abcdefgh", 1.0);
+        chart.bar("مرحبا", 1.0);
+        let chart = chart.show_values(false);
+        let segments = chart.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        // The RTL label is narrower than "abcdefgh" and right-aligned
+        // (leading padding) instead of left-aligned, so it does not start
+        // at column 0 like "abcdefgh" does.
+        assert!(plain[0].starts_with("abcdefgh"));
+        assert!(plain[1].starts_with(' '));
+    }
+
+    #[test]
+    fn test_sparkline_maps_values_to_glyph_heights() {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        let spark = Sparkline::new(vec![0.0, 1.0, 2.0, 4.0]);
+        let segments = spark.render(&context);
+        let plain: String = segments.iter().map(|s| s.plain_text()).collect();
+        assert_eq!(plain.chars().collect::<Vec<_>>(), vec!['▁', '▃', '▅', '█']);
+    }
+
+    #[test]
+    fn test_sparkline_empty_renders_blank_line() {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        let spark = Sparkline::new(vec![]);
+        let segments = spark.render(&context);
+        assert_eq!(segments.len(), 1);
+    }
+
+    #[test]
+    fn test_bar_chart_sub_cell_adds_partial_glyph() {
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let mut chart = BarChart::new();
+        chart.bar("a", 10.0);
+        chart.bar("b", 4.5);
+        let chart = chart.width(8).sub_cell(true).show_values(false);
+        let segments = chart.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        assert!(plain[1].chars().any(|c| SUB_CELL_GLYPHS[..7].contains(&c)));
+    }
+}
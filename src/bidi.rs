@@ -32,6 +32,7 @@ use unicode_bidi::{BidiInfo, Level};
 use crate::style::Style;
 use crate::text::Span;
 use std::borrow::Cow;
+use std::ops::Range;
 
 /// A styled range in logical text order (using character indices).
 ///
@@ -126,39 +127,32 @@ pub fn reorder_styled_spans(spans: &[Span], direction: TextDirection) -> Vec<Spa
         }).collect();
     }
     
-    // 4. Get visual text for each paragraph
-    let mut visual_text = String::new();
-    for para in &bidi_info.paragraphs {
-        let line = para.range.clone();
-        let reordered = bidi_info.reorder_line(para, line);
-        visual_text.push_str(&reordered);
-    }
-    
-    // 5. Map visual characters back to styles using character matching
-    // We need to find which logical character each visual character came from
-    let visual_chars: Vec<char> = visual_text.chars().collect();
-    let mut visual_styles: Vec<Style> = Vec::with_capacity(visual_chars.len());
-    
-    // Track which logical characters have been used
-    let mut used: Vec<bool> = vec![false; logical_chars.len()];
-    
-    for vc in &visual_chars {
-        // Find the first unused matching character in logical order
-        let mut found = false;
-        for (i, (lc, used_flag)) in logical_chars.iter().zip(used.iter_mut()).enumerate() {
-            if !*used_flag && lc == vc {
-                visual_styles.push(char_styles[i]);
-                *used_flag = true;
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            // Fallback: use default style
-            visual_styles.push(Style::default());
-        }
-    }
-    
+    // 4. Derive the visual-order permutation directly from the BiDi
+    // algorithm's resolved levels, the same way `resolve_levels` does --
+    // rather than reordering to a `String` (via `reorder_line`) and then
+    // matching visual characters back to logical ones by value, which
+    // silently misattributes styles whenever a character repeats (e.g.
+    // spaces, digits, or the "ll" in "Hello").
+    let char_levels: Vec<Level> = logical_text
+        .char_indices()
+        .map(|(byte_idx, _)| bidi_info.levels[byte_idx])
+        .collect();
+    let visual_order = BidiInfo::reorder_visual(&char_levels);
+
+    // Mirror paired characters (brackets, parentheses, ...) that fall in an
+    // RTL run per UAX #9 rule L4, before mapping to visual order.
+    let mirrored_chars: Vec<char> = logical_chars
+        .iter()
+        .zip(&char_levels)
+        .map(|(&c, level)| mirror_for_level(c, level.number()))
+        .collect();
+
+    // 5. Map each visual position straight to its logical char and style via
+    // the permutation -- exact even when multiple logical chars share the
+    // same value.
+    let visual_chars: Vec<char> = visual_order.iter().map(|&i| mirrored_chars[i]).collect();
+    let visual_styles: Vec<Style> = visual_order.iter().map(|&i| char_styles[i]).collect();
+
     // 6. Build result spans by merging adjacent same-styled characters
     let mut result: Vec<Span> = Vec::new();
     
@@ -261,15 +255,23 @@ pub fn reorder_for_display(text: &str, direction: TextDirection) -> String {
         return text.to_string();
     }
 
-    // Handle multi-paragraph text
-    let mut result = String::with_capacity(text.len());
-    for para in &bidi_info.paragraphs {
-        let line = para.range.clone();
-        let reordered = bidi_info.reorder_line(para, line);
-        result.push_str(&reordered);
-    }
+    // Sample the resolved level at each char's byte offset, mirror paired
+    // characters (brackets, parentheses, ...) that fall in an RTL run per
+    // UAX #9 rule L4, then reorder to visual order using the same
+    // permutation approach as `resolve_levels`/`reorder_styled_spans`.
+    let chars: Vec<char> = shaped_text.chars().collect();
+    let char_levels: Vec<Level> = shaped_text
+        .char_indices()
+        .map(|(byte_idx, _)| bidi_info.levels[byte_idx])
+        .collect();
+    let mirrored_chars: Vec<char> = chars
+        .iter()
+        .zip(&char_levels)
+        .map(|(&c, level)| mirror_for_level(c, level.number()))
+        .collect();
 
-    result
+    let visual_order = BidiInfo::reorder_visual(&char_levels);
+    visual_order.into_iter().map(|i| mirrored_chars[i]).collect()
 }
 
 /// Stub for when RTL feature is disabled
@@ -295,6 +297,360 @@ pub fn is_rtl(_text: &str) -> bool {
     false
 }
 
+/// UAX #9 isolate initiators (LRI/RLI/FSI) and their matching pop (PDI).
+const ISOLATE_LTR: char = '\u{2066}';
+const ISOLATE_RTL: char = '\u{2067}';
+const ISOLATE_AUTO: char = '\u{2068}';
+const POP_ISOLATE: char = '\u{2069}';
+
+/// Wrap `text` in the UAX #9 isolate initiator matching `direction` and a
+/// trailing PDI (U+2069), so that when the combined string is fed through
+/// the BiDi algorithm, `text`'s direction is pinned regardless of its
+/// surrounding context -- e.g. a user-supplied filename embedded in
+/// otherwise-RTL UI text won't reorder unexpectedly.
+///
+/// # Note
+///
+/// This is the low-level primitive that a `Span`-level directional scope
+/// (an `isolate: Option<TextDirection>` field, set via a `[dir=rtl]...[/dir]`
+/// markup tag) would call from inside `reorder_styled_spans` before handing
+/// a scoped span's text to `BidiInfo::new`. That wiring isn't included here:
+/// `Span` is defined in `src/text.rs` and the markup tag would be parsed in
+/// `src/markup.rs`, and neither file exists in this checkout to add a field
+/// or a tag to. Once they do, `reorder_styled_spans` should call this for
+/// any span with `isolate = Some(direction)` and run
+/// [`strip_isolate_controls`] on the resulting visual text before emitting
+/// spans, the same way it already mirrors paired characters per rule L4.
+pub fn isolate_scope(text: &str, direction: TextDirection) -> String {
+    let initiator = match direction {
+        TextDirection::Ltr => ISOLATE_LTR,
+        TextDirection::Rtl => ISOLATE_RTL,
+        TextDirection::Auto => ISOLATE_AUTO,
+    };
+    format!("{initiator}{text}{POP_ISOLATE}")
+}
+
+/// Strip UAX #9 isolate, embedding/override, and mark control characters
+/// (LRI/RLI/FSI/PDI, LRE/RLE/RLO/LRO/PDF, LRM/RLM) from `text`.
+///
+/// These participate in BiDi resolution but must never reach the terminal:
+/// most emulators render them as visible glyphs (often a blank box) rather
+/// than treating them as zero-width controls.
+pub fn strip_isolate_controls(text: &str) -> String {
+    text.chars()
+        .filter(|c| {
+            !matches!(c,
+                '\u{2066}'..='\u{2069}' | '\u{202a}'..='\u{202e}' | '\u{200e}' | '\u{200f}'
+            )
+        })
+        .collect()
+}
+
+/// Resolve UAX #9 embedding levels for `text` and the resulting visual
+/// reordering, for callers that need more than a flattened display
+/// string -- e.g. to mirror bracket glyphs only within RTL runs, or to
+/// reorder something other than plain text (styled spans, table cells)
+/// using the same run boundaries [`reorder_for_display`] uses internally.
+///
+/// Returns `(visual_order, levels)`: `visual_order[i]` is the logical char
+/// index to display at visual position `i` (the result of resolving weak
+/// and neutral types onto the surrounding strong runs and then reversing
+/// each maximal run from the highest level down to the lowest odd level,
+/// per UAX #9 rules X1-X10/W1-W7/N0-N2/L2), and `levels[j]` is the
+/// resolved embedding level of the `j`-th logical char (even = LTR,
+/// odd = RTL).
+#[cfg(feature = "rtl")]
+pub fn resolve_levels(text: &str, direction: TextDirection) -> (Vec<usize>, Vec<u8>) {
+    if text.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let paragraph_level = match direction {
+        TextDirection::Ltr => Some(Level::ltr()),
+        TextDirection::Rtl => Some(Level::rtl()),
+        TextDirection::Auto => None,
+    };
+
+    let bidi_info = BidiInfo::new(text, paragraph_level);
+
+    // `bidi_info.levels` is aligned to UTF-8 byte offsets; sample the level
+    // at each char's starting byte to get one level per logical char.
+    let char_levels: Vec<Level> = text
+        .char_indices()
+        .map(|(byte_idx, _)| bidi_info.levels[byte_idx])
+        .collect();
+
+    let visual_order = BidiInfo::reorder_visual(&char_levels);
+    let levels = char_levels.iter().map(|l| l.number()).collect();
+
+    (visual_order, levels)
+}
+
+/// Stub for when RTL feature is disabled: identity order, base level 0 for
+/// every char (i.e. as if the whole string were a single LTR run).
+#[cfg(not(feature = "rtl"))]
+pub fn resolve_levels(text: &str, _direction: TextDirection) -> (Vec<usize>, Vec<u8>) {
+    let char_count = text.chars().count();
+    ((0..char_count).collect(), vec![0; char_count])
+}
+
+/// A single visual line's logical↔visual character mapping, analogous to
+/// VTE's per-line `BidiRow`.
+///
+/// Built by [`analyze_line`]. Lets TUI authors translate a logical caret or
+/// selection offset to its on-screen column and back, without re-running
+/// the BiDi algorithm themselves for every cursor movement or highlight
+/// redraw.
+#[derive(Debug, Clone, Default)]
+pub struct BidiLine {
+    /// `log2vis[logical]` is the visual column of the char at logical index `logical`.
+    log2vis: Vec<usize>,
+    /// `vis2log[visual]` is the logical char index displayed at visual column `visual`.
+    vis2log: Vec<usize>,
+    /// `rtl[logical]` is `true` if the char at logical index `logical` resolved to an odd (RTL) embedding level.
+    rtl: Vec<bool>,
+}
+
+impl BidiLine {
+    /// Number of characters on this line.
+    pub fn len(&self) -> usize {
+        self.vis2log.len()
+    }
+
+    /// Whether this line has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.vis2log.is_empty()
+    }
+
+    /// The visual column the character at logical index `logical` displays at.
+    pub fn log2vis(&self, logical: usize) -> usize {
+        self.log2vis[logical]
+    }
+
+    /// The logical character index displayed at visual column `visual`.
+    pub fn vis2log(&self, visual: usize) -> usize {
+        self.vis2log[visual]
+    }
+
+    /// Whether the character at logical index `logical` resolved to an odd
+    /// (RTL) embedding level.
+    pub fn is_rtl_at(&self, logical: usize) -> bool {
+        self.rtl[logical]
+    }
+
+    /// Map a logical character range (e.g. a text selection) to the visual
+    /// column span(s) it covers.
+    ///
+    /// Returns more than one [`Range`] when the selection isn't contiguous
+    /// on screen -- an RTL run embedded in the middle of an LTR selection
+    /// (or vice versa) splits it into separate visual spans.
+    pub fn selection_visual_ranges(&self, logical_range: Range<usize>) -> Vec<Range<usize>> {
+        let mut visual_positions: Vec<usize> = logical_range
+            .filter(|&logical| logical < self.log2vis.len())
+            .map(|logical| self.log2vis[logical])
+            .collect();
+        visual_positions.sort_unstable();
+
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for visual in visual_positions {
+            match ranges.last_mut() {
+                Some(last) if last.end == visual => last.end = visual + 1,
+                _ => ranges.push(visual..visual + 1),
+            }
+        }
+        ranges
+    }
+}
+
+/// Analyze one visual line of `text`, building the logical↔visual character
+/// mapping a [`BidiLine`] exposes, using the same `resolve_levels`
+/// permutation under the hood.
+pub fn analyze_line(text: &str, direction: TextDirection) -> BidiLine {
+    let (vis2log, levels) = resolve_levels(text, direction);
+
+    let mut log2vis = vec![0usize; vis2log.len()];
+    for (visual, &logical) in vis2log.iter().enumerate() {
+        log2vis[logical] = visual;
+    }
+
+    let rtl = levels.iter().map(|&level| level % 2 == 1).collect();
+
+    BidiLine {
+        log2vis,
+        vis2log,
+        rtl,
+    }
+}
+
+/// Word-wrap `chars` in logical order to `width` display columns, returning
+/// the char-index range of each resulting line.
+///
+/// This must run *before* any BiDi reordering: wrapping the already-reordered
+/// visual text would let characters jump across the line break it's supposed
+/// to respect. Breaks prefer the last whitespace boundary that still fits, so
+/// whole words move to the next line together, falling back to a hard break
+/// only when a single word is wider than `width`. A whitespace char that
+/// would overflow the line is consumed by the break and appears on neither
+/// line.
+fn wrap_logical_lines(chars: &[char], width: usize) -> Vec<Range<usize>> {
+    if chars.is_empty() {
+        return Vec::new();
+    }
+    if width == 0 {
+        return vec![0..chars.len()];
+    }
+
+    let mut lines = Vec::new();
+    let mut line_start = 0usize;
+    let mut i = 0usize;
+
+    while i < chars.len() {
+        if chars[i] == '\n' {
+            lines.push(line_start..i);
+            i += 1;
+            line_start = i;
+            continue;
+        }
+
+        let current_width: usize = chars[line_start..i]
+            .iter()
+            .map(|c| display_width(&c.to_string()))
+            .sum();
+        let char_width = display_width(&chars[i].to_string());
+
+        if i > line_start && current_width + char_width > width {
+            if chars[i].is_whitespace() {
+                lines.push(line_start..i);
+                i += 1;
+                line_start = i;
+                continue;
+            }
+            let break_at = chars[line_start..i]
+                .iter()
+                .rposition(|c| c.is_whitespace())
+                .map(|rel| line_start + rel + 1)
+                .unwrap_or(i);
+
+            lines.push(line_start..break_at);
+            line_start = break_at;
+            continue;
+        }
+
+        i += 1;
+    }
+
+    lines.push(line_start..chars.len());
+    lines
+}
+
+/// Reorder `spans` for visual display after wrapping them to `width` display
+/// columns, returning one reordered, style-preserving span list per visual
+/// line.
+///
+/// [`reorder_styled_spans`] reorders an entire paragraph as a single unit,
+/// which is right for one visual line but wrong once text wraps: wrapping
+/// must happen in *logical* order first, and each resulting visual line must
+/// then be reordered independently, or characters get shuffled across the
+/// line break -- the invariant VTE's BiDi documentation calls out. This
+/// builds the combined logical char/style arrays the same way
+/// `reorder_styled_spans` does, computes line breaks with
+/// [`wrap_logical_lines`], then re-runs `resolve_levels` (and UAX #9 rule L4
+/// mirroring) on each line's own text independently, so a line's reordering
+/// never sees characters from its neighbors.
+pub fn reorder_wrapped(spans: &[Span], width: usize, direction: TextDirection) -> Vec<Vec<Span>> {
+    if spans.is_empty() {
+        return Vec::new();
+    }
+
+    // 1. Reshape each span and build the combined logical char/style arrays.
+    let mut logical_chars: Vec<char> = Vec::new();
+    let mut char_styles: Vec<Style> = Vec::new();
+
+    for span in spans {
+        let reshaped = crate::shaping::reshape(&span.text);
+        for c in reshaped.chars() {
+            logical_chars.push(c);
+            char_styles.push(span.style);
+        }
+    }
+
+    if logical_chars.is_empty() {
+        return Vec::new();
+    }
+
+    // 2. Compute line-break opportunities in logical order at `width` display
+    // columns, reusing the crate's display-width measurement.
+    let line_ranges = wrap_logical_lines(&logical_chars, width);
+
+    // 3. Reorder each logical line independently and emit style-preserving
+    // spans for it.
+    line_ranges
+        .into_iter()
+        .map(|range| {
+            let line_text: String = logical_chars[range.clone()].iter().collect();
+            let line_styles = &char_styles[range];
+
+            let (visual_order, levels) = resolve_levels(&line_text, direction);
+            let line_chars: Vec<char> = line_text.chars().collect();
+
+            let mirrored_chars: Vec<char> = line_chars
+                .iter()
+                .zip(&levels)
+                .map(|(&c, &level)| mirror_for_level(c, level))
+                .collect();
+
+            let visual_chars: Vec<char> = visual_order.iter().map(|&i| mirrored_chars[i]).collect();
+            let visual_styles: Vec<Style> = visual_order.iter().map(|&i| line_styles[i]).collect();
+
+            let mut result: Vec<Span> = Vec::new();
+            if visual_chars.is_empty() {
+                return result;
+            }
+
+            let mut current_text = String::new();
+            let mut current_style = visual_styles[0];
+
+            for (c, style) in visual_chars.into_iter().zip(visual_styles) {
+                if style != current_style {
+                    if !current_text.is_empty() {
+                        result.push(Span {
+                            text: Cow::Owned(current_text),
+                            style: current_style,
+                            link: None,
+                        });
+                    }
+                    current_text = String::new();
+                    current_style = style;
+                }
+                current_text.push(c);
+            }
+
+            if !current_text.is_empty() {
+                result.push(Span {
+                    text: Cow::Owned(current_text),
+                    style: current_style,
+                    link: None,
+                });
+            }
+
+            result
+        })
+        .collect()
+}
+
+/// Mirror `c` only when `level` is odd (an RTL run), per UAX #9 rule L4:
+/// paired characters like brackets and parentheses are mirrored within a
+/// right-to-left run, but left as-is where they fall in an LTR run even
+/// inside an otherwise-RTL paragraph (e.g. a parenthesized English phrase
+/// embedded in Arabic text).
+pub fn mirror_for_level(c: char, level: u8) -> char {
+    if level % 2 == 1 {
+        mirror_char(c)
+    } else {
+        c
+    }
+}
+
 /// Mirror paired characters for RTL context.
 ///
 /// Swaps brackets, parentheses, and other directional characters.
@@ -325,6 +681,15 @@ pub fn mirror_string(text: &str) -> String {
     text.chars().map(mirror_char).collect()
 }
 
+/// Unicode display width of `text` in terminal cells -- wide CJK/emoji
+/// characters count as two, combining marks count as zero -- rather than
+/// byte length or `char` count, either of which misaligns padding for
+/// anything outside plain ASCII.
+pub fn display_width(text: &str) -> usize {
+    use unicode_width::UnicodeWidthStr;
+    text.width()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,12 +724,150 @@ mod tests {
         assert_eq!(mirror_char('1'), '1');
     }
 
+    #[test]
+    fn test_display_width_ascii_matches_byte_length() {
+        assert_eq!(display_width("hello"), 5);
+    }
+
+    #[test]
+    fn test_display_width_wide_cjk_counts_two_per_char() {
+        assert_eq!(display_width("你好"), 4);
+    }
+
     #[test]
     fn test_mirror_string() {
         assert_eq!(mirror_string("(hello)"), ")hello(");
         assert_eq!(mirror_string("[a{b}c]"), "]a}b{c[");
     }
 
+    #[test]
+    fn test_mirror_for_level_only_mirrors_on_odd_levels() {
+        assert_eq!(mirror_for_level('(', 0), '(');
+        assert_eq!(mirror_for_level('(', 1), ')');
+        assert_eq!(mirror_for_level('[', 2), '[');
+        assert_eq!(mirror_for_level('[', 3), ']');
+    }
+
+    #[test]
+    fn test_analyze_line_ltr_is_identity() {
+        let line = analyze_line("Hello", TextDirection::Ltr);
+        assert_eq!(line.len(), 5);
+        for i in 0..5 {
+            assert_eq!(line.log2vis(i), i);
+            assert_eq!(line.vis2log(i), i);
+            assert!(!line.is_rtl_at(i));
+        }
+    }
+
+    #[test]
+    fn test_analyze_line_log2vis_and_vis2log_are_inverses() {
+        let line = analyze_line("مرحبا Hello", TextDirection::Auto);
+        for logical in 0..line.len() {
+            let visual = line.log2vis(logical);
+            assert_eq!(line.vis2log(visual), logical);
+        }
+    }
+
+    #[test]
+    fn test_analyze_line_empty_text() {
+        let line = analyze_line("", TextDirection::Auto);
+        assert!(line.is_empty());
+        assert_eq!(line.selection_visual_ranges(0..0), Vec::<std::ops::Range<usize>>::new());
+    }
+
+    #[test]
+    fn test_selection_visual_ranges_contiguous_for_plain_ltr() {
+        let line = analyze_line("Hello", TextDirection::Ltr);
+        assert_eq!(line.selection_visual_ranges(1..4), vec![1..4]);
+    }
+
+    #[test]
+    fn test_selection_visual_ranges_covers_every_column_across_an_embedded_rtl_run() {
+        // "AB" (Arabic, RTL) embedded between two LTR letters -- selecting
+        // the whole logical range should still recover every visual column,
+        // just not necessarily as one contiguous span.
+        let line = analyze_line("x\u{0645}\u{0631}y", TextDirection::Ltr);
+        let ranges = line.selection_visual_ranges(0..line.len());
+        let covered: std::collections::HashSet<usize> =
+            ranges.iter().flat_map(|r| r.clone()).collect();
+        assert_eq!(covered.len(), line.len());
+        assert_eq!(covered, (0..line.len()).collect());
+    }
+
+    #[test]
+    fn test_isolate_scope_wraps_with_matching_initiator_and_pdi() {
+        assert_eq!(isolate_scope("abc", TextDirection::Ltr), "\u{2066}abc\u{2069}");
+        assert_eq!(isolate_scope("abc", TextDirection::Rtl), "\u{2067}abc\u{2069}");
+        assert_eq!(isolate_scope("abc", TextDirection::Auto), "\u{2068}abc\u{2069}");
+    }
+
+    #[test]
+    fn test_strip_isolate_controls_removes_all_scope_and_mark_chars() {
+        let scoped = isolate_scope("file.txt", TextDirection::Ltr);
+        assert_eq!(strip_isolate_controls(&scoped), "file.txt");
+
+        let marked = format!("\u{200e}left\u{200f}right\u{202a}embed\u{202c}");
+        assert_eq!(strip_isolate_controls(&marked), "leftrightembed");
+    }
+
+    #[test]
+    fn test_wrap_logical_lines_breaks_at_word_boundary() {
+        let chars: Vec<char> = "hello world foo".chars().collect();
+        let lines = wrap_logical_lines(&chars, 7);
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|r| chars[r.clone()].iter().collect())
+            .collect();
+        assert_eq!(rendered, vec!["hello ", "world ", "foo"]);
+    }
+
+    #[test]
+    fn test_wrap_logical_lines_hard_breaks_an_overlong_word() {
+        let chars: Vec<char> = "abcdefghij".chars().collect();
+        let lines = wrap_logical_lines(&chars, 4);
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|r| chars[r.clone()].iter().collect())
+            .collect();
+        assert_eq!(rendered, vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_wrap_logical_lines_respects_explicit_newlines() {
+        let chars: Vec<char> = "hi\nthere".chars().collect();
+        let lines = wrap_logical_lines(&chars, 80);
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|r| chars[r.clone()].iter().collect())
+            .collect();
+        assert_eq!(rendered, vec!["hi", "there"]);
+    }
+
+    #[test]
+    fn test_reorder_wrapped_plain_text_wraps_without_reordering() {
+        let spans = vec![Span::raw("hello world foo".to_string())];
+        let lines = reorder_wrapped(&spans, 7, TextDirection::Ltr);
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_ref()).collect())
+            .collect();
+        assert_eq!(rendered, vec!["hello ", "world ", "foo"]);
+    }
+
+    #[test]
+    fn test_reorder_wrapped_preserves_style_per_line() {
+        use crate::style::Color;
+        let red = Style::new().foreground(Color::Red);
+        let spans = vec![Span::styled("abc def".to_string(), red)];
+        let lines = reorder_wrapped(&spans, 3, TextDirection::Ltr);
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            for span in line {
+                assert_eq!(span.style, red);
+            }
+        }
+    }
+
     #[cfg(feature = "rtl")]
     mod rtl_tests {
         use super::*;
@@ -399,6 +902,29 @@ mod tests {
             assert_eq!(visual.chars().count(), text.chars().count(), "Character count should match");
         }
 
+        #[test]
+        fn test_reorder_for_display_mirrors_parens_around_embedded_ltr_run() {
+            // The Latin run "abc" is embedded at a deeper (even/LTR) level
+            // inside the surrounding RTL paragraph, so per UAX #9 rule L4 the
+            // neutral parentheses around it resolve to the paragraph's odd
+            // (RTL) level and must be mirrored -- otherwise the position
+            // reversal alone would visually un-wrap them (e.g. ")abc("),
+            // rather than keeping them correctly wrapped around the run.
+            let text = "شاهد (abc)";
+            let visual = reorder_for_display(text, TextDirection::Auto);
+            assert!(
+                visual.contains("(abc)"),
+                "parentheses should still visually wrap the Latin run: {visual}"
+            );
+        }
+
+        #[test]
+        fn test_reorder_for_display_pure_ltr_leaves_parens_untouched() {
+            let text = "(abc) plain text";
+            let visual = reorder_for_display(text, TextDirection::Ltr);
+            assert_eq!(visual, text);
+        }
+
         #[test]
         fn test_arabic_sentence() {
             // "أهلا وسهلا" = "Welcome" in Arabic
@@ -518,5 +1044,70 @@ mod tests {
             assert!(has_green_arabic, "Should contain Green Arabic characters");
         }
 
+        #[test]
+        fn test_reorder_styled_spans_repeated_character_keeps_correct_style() {
+            use crate::text::Span;
+            use crate::style::{Style, Color};
+
+            let red = Style::new().foreground(Color::Red);
+            let green = Style::new().foreground(Color::Green);
+            // Two runs of the same repeated Hebrew letter (no shaping/joining
+            // behavior to complicate things) in different styles -- the old
+            // value-matching heuristic couldn't tell identical characters
+            // apart and silently left the style order unchanged instead of
+            // reversing it along with the text.
+            let spans = vec![Span::styled("אא", red), Span::styled("אא", green)];
+
+            let reordered = reorder_styled_spans(&spans, TextDirection::Rtl);
+
+            let combined: Vec<(char, Style)> = reordered
+                .iter()
+                .flat_map(|s| s.text.chars().map(move |c| (c, s.style)))
+                .collect();
+
+            assert_eq!(combined.len(), 4);
+            // A pure RTL run is displayed in reverse logical order, so the
+            // second span's (green) characters should come first visually,
+            // followed by the first span's (red) characters.
+            assert_eq!(combined[0].1.foreground, Some(Color::Green));
+            assert_eq!(combined[1].1.foreground, Some(Color::Green));
+            assert_eq!(combined[2].1.foreground, Some(Color::Red));
+            assert_eq!(combined[3].1.foreground, Some(Color::Red));
+        }
+
+        #[test]
+        fn test_resolve_levels_english_only_is_identity_at_even_levels() {
+            let (order, levels) = resolve_levels("Hello", TextDirection::Ltr);
+            assert_eq!(order, vec![0, 1, 2, 3, 4]);
+            assert!(levels.iter().all(|&l| l % 2 == 0));
+        }
+
+        #[test]
+        fn test_resolve_levels_forced_rtl_nests_latin_at_even_levels() {
+            let (order, levels) = resolve_levels("ABC", TextDirection::Rtl);
+            assert_eq!(order.len(), 3);
+            assert_eq!(levels.len(), 3);
+            // The paragraph embedding level itself is odd even though these
+            // particular (Latin) chars form their own nested LTR run.
+            assert!(levels.iter().all(|&l| l % 2 == 0));
+        }
+
+        #[test]
+        fn test_resolve_levels_mixed_text_reorders_arabic_run() {
+            let text = "مرحبا Hello";
+            let (order, levels) = resolve_levels(text, TextDirection::Auto);
+            assert_eq!(order.len(), text.chars().count());
+            assert_eq!(levels.len(), text.chars().count());
+            // Arabic chars resolve to an odd (RTL) level, "Hello"'s to even.
+            assert!(levels[0] % 2 == 1);
+            assert!(levels[levels.len() - 1] % 2 == 0);
+        }
+
+        #[test]
+        fn test_resolve_levels_empty_string() {
+            let (order, levels) = resolve_levels("", TextDirection::Auto);
+            assert!(order.is_empty());
+            assert!(levels.is_empty());
+        }
     }
 }
@@ -0,0 +1,243 @@
+//! Horizontal layout of renderables side-by-side.
+//!
+//! [`crate::group::RenderGroup`] stacks renderables vertically; `Columns`
+//! is its horizontal counterpart -- it divides the available width among
+//! its children per a [`Constraint`] each, renders every child into its
+//! own sub-width, then zips their segment lines together so rows line up,
+//! padding shorter children up to their column's width.
+
+use crate::console::RenderContext;
+use crate::renderable::{BoxedRenderable, Renderable, Segment};
+use crate::text::Span;
+
+/// How much width a column is given, mirroring tui's layout constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// A fixed percentage of the total available width.
+    Percentage(u16),
+    /// A fixed number of cells.
+    Length(usize),
+    /// At least this many cells; remaining width (after `Percentage` and
+    /// `Length` columns are assigned) is split evenly among all `Min`
+    /// columns.
+    Min(usize),
+}
+
+/// A renderable that lays out its children side-by-side.
+pub struct Columns {
+    columns: Vec<(BoxedRenderable, Constraint)>,
+    spacing: usize,
+    divider: Option<String>,
+}
+
+impl Columns {
+    /// Create an empty set of columns.
+    pub fn new() -> Self {
+        Columns {
+            columns: Vec::new(),
+            spacing: 0,
+            divider: None,
+        }
+    }
+
+    /// Add a column with an even (`Min(0)`) share of the available width.
+    pub fn add(&mut self, renderable: impl Renderable + Send + Sync + 'static) -> &mut Self {
+        self.columns.push((Box::new(renderable), Constraint::Min(0)));
+        self
+    }
+
+    /// Add a column constrained to a specific width.
+    pub fn add_with_constraint(
+        &mut self,
+        renderable: impl Renderable + Send + Sync + 'static,
+        constraint: Constraint,
+    ) -> &mut Self {
+        self.columns.push((Box::new(renderable), constraint));
+        self
+    }
+
+    /// Set the number of blank columns inserted between each child.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Insert a vertical separator glyph (e.g. `"│"`) between columns.
+    pub fn divider(mut self, divider: impl Into<String>) -> Self {
+        self.divider = Some(divider.into());
+        self
+    }
+
+    /// The width, in cells, taken up by one inter-column gap (spacing plus
+    /// an optional divider glyph).
+    fn gap_width(&self) -> usize {
+        self.spacing + self.divider.as_ref().map_or(0, |d| d.chars().count())
+    }
+
+    /// Resolve each column's width in cells for a given total width.
+    fn resolve_widths(&self, total: usize) -> Vec<usize> {
+        let gaps = self.columns.len().saturating_sub(1) * self.gap_width();
+        let mut remaining = total.saturating_sub(gaps);
+
+        let mut widths = vec![0usize; self.columns.len()];
+        let mut flexible = Vec::new();
+
+        for (i, (_, constraint)) in self.columns.iter().enumerate() {
+            match constraint {
+                Constraint::Percentage(p) => {
+                    let w = ((total as f64) * (*p as f64) / 100.0).round() as usize;
+                    let w = w.min(remaining);
+                    widths[i] = w;
+                    remaining -= w;
+                }
+                Constraint::Length(n) => {
+                    let w = (*n).min(remaining);
+                    widths[i] = w;
+                    remaining -= w;
+                }
+                Constraint::Min(_) => flexible.push(i),
+            }
+        }
+
+        if !flexible.is_empty() {
+            let share = remaining / flexible.len();
+            let mut extra = remaining % flexible.len();
+            for &i in &flexible {
+                let mut w = share;
+                if extra > 0 {
+                    w += 1;
+                    extra -= 1;
+                }
+                if let Constraint::Min(min) = self.columns[i].1 {
+                    w = w.max(min.min(total));
+                }
+                widths[i] = w;
+            }
+        }
+
+        widths
+    }
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderable for Columns {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        if self.columns.is_empty() {
+            return vec![Segment::empty_line()];
+        }
+
+        let widths = self.resolve_widths(context.width);
+
+        let mut rendered: Vec<Vec<Segment>> = Vec::new();
+        let mut max_lines = 0;
+        for ((renderable, _), &width) in self.columns.iter().zip(&widths) {
+            if width == 0 {
+                rendered.push(Vec::new());
+                continue;
+            }
+            let child_context = RenderContext {
+                width,
+                height: context.height,
+                direction: context.direction,
+            };
+            let segments = renderable.render(&child_context);
+            max_lines = max_lines.max(segments.len());
+            rendered.push(segments);
+        }
+
+        let mut output = Vec::with_capacity(max_lines);
+        for line_idx in 0..max_lines {
+            let mut spans = Vec::new();
+            for (col_idx, width) in widths.iter().enumerate() {
+                if col_idx > 0 {
+                    if self.spacing > 0 {
+                        spans.push(Span::raw(" ".repeat(self.spacing)));
+                    }
+                    if let Some(divider) = &self.divider {
+                        spans.push(Span::raw(divider.clone()));
+                    }
+                }
+
+                match rendered[col_idx].get(line_idx) {
+                    Some(segment) => {
+                        let line_len: usize = segment.plain_text().chars().count();
+                        spans.extend(segment.spans.clone());
+                        if line_len < *width {
+                            spans.push(Span::raw(" ".repeat(width - line_len)));
+                        }
+                    }
+                    None => spans.push(Span::raw(" ".repeat(*width))),
+                }
+            }
+            output.push(Segment::line(spans));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Text;
+
+    #[test]
+    fn test_resolve_widths_even_split() {
+        let mut columns = Columns::new();
+        columns.add(Text::plain("a"));
+        columns.add(Text::plain("b"));
+        assert_eq!(columns.resolve_widths(100), vec![50, 50]);
+    }
+
+    #[test]
+    fn test_resolve_widths_with_length_and_min() {
+        let mut columns = Columns::new();
+        columns.add_with_constraint(Text::plain("a"), Constraint::Length(10));
+        columns.add_with_constraint(Text::plain("b"), Constraint::Min(0));
+        assert_eq!(columns.resolve_widths(100), vec![10, 90]);
+    }
+
+    #[test]
+    fn test_resolve_widths_with_percentage() {
+        let mut columns = Columns::new();
+        columns.add_with_constraint(Text::plain("a"), Constraint::Percentage(25));
+        columns.add_with_constraint(Text::plain("b"), Constraint::Min(0));
+        assert_eq!(columns.resolve_widths(100), vec![25, 75]);
+    }
+
+    #[test]
+    fn test_render_pads_shorter_column_and_aligns_rows() {
+        let mut columns = Columns::new();
+        columns.add_with_constraint(Text::plain("one\ntwo"), Constraint::Length(5));
+        columns.add_with_constraint(Text::plain("x"), Constraint::Length(5));
+        let context = RenderContext {
+            width: 10,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = columns.render(&context);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].plain_text(), "one  x    ");
+        assert_eq!(segments[1].plain_text(), "two       ");
+    }
+
+    #[test]
+    fn test_render_inserts_divider_between_columns() {
+        let mut columns = Columns::new();
+        columns.add_with_constraint(Text::plain("a"), Constraint::Length(3));
+        columns.add_with_constraint(Text::plain("b"), Constraint::Length(3));
+        let columns = columns.divider("|");
+        let context = RenderContext {
+            width: 7,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = columns.render(&context);
+        assert_eq!(segments[0].plain_text(), "a  |b  ");
+    }
+}
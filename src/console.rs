@@ -12,16 +12,19 @@
 //! console.print("Hello, [bold magenta]World[/]!");
 //! ```
 
+use crate::bidi::TextDirection;
+use crate::highlighter::ReprHighlighter;
 use crate::markup;
 use crate::renderable::{Renderable, Segment};
 use crate::text::{Span, Text};
+use crate::theme::Theme;
 
 use crossterm::{
     execute,
     style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal,
 };
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 
 /// Escape HTML special characters.
 fn html_escape(s: &str) -> String {
@@ -38,6 +41,109 @@ fn svg_escape(s: &str) -> String {
         .replace('>', "&gt;")
 }
 
+/// Number of physical terminal rows `line` (plain text, already wrapped by
+/// the caller's renderer) wraps to at `width` columns — always at least 1,
+/// since even an empty printed line occupies its own row. Shared by any
+/// live-region redraw logic that needs to move the cursor past a previous
+/// frame's lines without reprinting ones that haven't changed (see
+/// [`crate::live::Live::refresh`] and [`crate::progress::bar::Progress`]).
+pub fn wrapped_rows(line: &str, width: usize) -> usize {
+    if width == 0 {
+        return 1;
+    }
+    let len = crate::bidi::display_width(line);
+    if len == 0 {
+        1
+    } else {
+        (len + width - 1) / width
+    }
+}
+
+/// How [`Console::print`]/[`Console::print_renderable`] break a printed
+/// line once it exceeds the console width, mirroring `bat`/`hgrep`'s
+/// wrapping modes. Selected via [`Console::wrapping_mode`]; has no effect
+/// while [`Console::soft_wrap`] is disabled, which always behaves like
+/// `None` regardless of this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrappingMode {
+    /// Break at the last whitespace boundary that still fits the console
+    /// width, falling back to a hard break only for a single word wider
+    /// than the console itself.
+    #[default]
+    Word,
+    /// Always break exactly at the console width, ignoring word
+    /// boundaries.
+    Character,
+    /// Never wrap; lines longer than the console width just run past it.
+    None,
+}
+
+/// Break `spans` -- a single already-logically-complete line -- into the
+/// rows it occupies at `width` columns under `mode`, preserving each span's
+/// style across the break. `mode` must not be [`WrappingMode::None`]; the
+/// caller (`Console::write_segments`) only reaches this function once it
+/// has already decided wrapping applies.
+fn wrap_segment_spans(spans: &[Span], width: usize, mode: WrappingMode) -> Vec<Vec<Span>> {
+    let total_width: usize = spans.iter().map(|s| crate::bidi::display_width(&s.text)).sum();
+    if total_width <= width {
+        return vec![spans.to_vec()];
+    }
+
+    match mode {
+        WrappingMode::Word => crate::bidi::reorder_wrapped(spans, width, crate::bidi::TextDirection::Auto),
+        WrappingMode::Character => wrap_spans_hard(spans, width),
+        WrappingMode::None => vec![spans.to_vec()],
+    }
+}
+
+/// Break `spans` into rows of exactly `width` display columns each,
+/// ignoring word boundaries -- the [`WrappingMode::Character`] strategy.
+fn wrap_spans_hard(spans: &[Span], width: usize) -> Vec<Vec<Span>> {
+    use unicode_width::UnicodeWidthChar;
+
+    let mut rows: Vec<Vec<Span>> = Vec::new();
+    let mut row: Vec<Span> = Vec::new();
+    let mut row_width = 0usize;
+
+    for span in spans {
+        let style = span.style;
+        let link = span.link.clone();
+        for ch in span.text.chars() {
+            let char_width = ch.width().unwrap_or(0).max(1);
+            if row_width + char_width > width && row_width > 0 {
+                rows.push(std::mem::take(&mut row));
+                row_width = 0;
+            }
+            let mut piece = Span::styled(ch.to_string(), style);
+            piece.link = link.clone();
+            row.push(piece);
+            row_width += char_width;
+        }
+    }
+    if !row.is_empty() || rows.is_empty() {
+        rows.push(row);
+    }
+    rows
+}
+
+/// The OSC 8 escape sequence that opens a terminal hyperlink to `url`, e.g.
+/// `ESC]8;;https://example.com ESC\`. Framed as `ESC]...ST` (Operating
+/// System Command, terminated with the ST control sequence `ESC\`) rather
+/// than the `ESC[...m` SGR framing [`Style::to_ansi_prefix`](crate::style::Style::to_ansi_prefix)
+/// uses, since OSC 8 carries a URL payload instead of numeric attribute
+/// codes. Closed by [`osc8_close`]; see [`Console::write_span`] for where
+/// the pair wraps a hyperlinked span's text.
+fn osc8_open(url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\")
+}
+
+/// The OSC 8 sequence that closes a hyperlink opened by [`osc8_open`] --
+/// the same framing with an empty URL, which terminal emulators interpret
+/// as "end the current link".
+fn osc8_close() -> &'static str {
+    "\x1b]8;;\x1b\\"
+}
+
 /// Rendering context passed to Renderable objects.
 #[derive(Debug, Clone)]
 pub struct RenderContext {
@@ -45,6 +151,11 @@ pub struct RenderContext {
     pub width: usize,
     /// Available height for rendering (optional).
     pub height: Option<usize>,
+    /// Base text direction for bidi reordering/reshaping, propagated down
+    /// to children so a `Group`/`Columns`/`Panel` etc. renders its content
+    /// in the direction the caller asked for rather than each renderable
+    /// re-guessing it independently.
+    pub direction: TextDirection,
 }
 
 impl Default for RenderContext {
@@ -52,6 +163,7 @@ impl Default for RenderContext {
         RenderContext {
             width: 80,
             height: None,
+            direction: TextDirection::default(),
         }
     }
 }
@@ -72,6 +184,69 @@ pub enum ColorSystem {
     Windows,
 }
 
+/// Overall policy for whether a [`Console`] emits color at all, mirroring
+/// the `anstyle`/`colorchoice` ecosystem's `ColorChoice`. Orthogonal to
+/// [`ColorSystem`], which picks the color *depth* once color is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Respect `NO_COLOR`/`CLICOLOR*`/`FORCE_COLOR` and TTY detection (the default).
+    #[default]
+    Auto,
+    /// Always emit color, even when the output isn't a TTY.
+    Always,
+    /// Never emit color, regardless of environment or TTY.
+    Never,
+}
+
+static DEFAULT_COLOR_CHOICE: std::sync::Mutex<ColorChoice> = std::sync::Mutex::new(ColorChoice::Auto);
+
+/// Set the process-wide default [`ColorChoice`] consulted by [`Console::new`]
+/// and [`Console::stderr`] -- and therefore by the default consoles backing
+/// the `print!`/`println!` macros -- unless a particular `Console` overrides
+/// it via [`Console::color_choice`]. Call this once, early in `main`, to
+/// honor a `--color=always`/`--color=never` CLI flag across the whole
+/// process without threading a `Console` through every call site.
+pub fn set_default_color_choice(choice: ColorChoice) {
+    if let Ok(mut guard) = DEFAULT_COLOR_CHOICE.lock() {
+        *guard = choice;
+    }
+}
+
+fn default_color_choice() -> ColorChoice {
+    DEFAULT_COLOR_CHOICE.lock().map(|guard| *guard).unwrap_or_default()
+}
+
+/// Terminal capabilities probed once at `Console` construction and cached,
+/// so no renderable needs to re-probe the environment on every render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    /// The color depth the terminal appears to support.
+    pub color_system: ColorSystem,
+    /// Whether the terminal is expected to render italic text distinctly.
+    pub italic: bool,
+    /// Whether the terminal is expected to render strikethrough text.
+    pub strikethrough: bool,
+}
+
+impl TerminalCapabilities {
+    /// Probe `TERM`/`COLORTERM` for color depth and feature support.
+    ///
+    /// This is a lightweight, env-var-based heuristic rather than a full
+    /// terminfo database read; `TERM=dumb` is treated as supporting neither
+    /// italics nor strikethrough, since it indicates a minimal terminal.
+    fn detect(is_tty: bool) -> Self {
+        let (color_enabled, color_system) = Console::detect_color_system(is_tty);
+        let term = std::env::var("TERM").unwrap_or_default();
+        let dumb = term == "dumb" || term.is_empty();
+
+        TerminalCapabilities {
+            color_system: if color_enabled { color_system } else { ColorSystem::NoColor },
+            italic: !dumb,
+            strikethrough: !dumb,
+        }
+    }
+}
+
 /// The main console type for rich terminal output.
 #[derive(Debug)]
 pub struct Console {
@@ -85,16 +260,56 @@ pub struct Console {
     color_enabled: bool,
     /// The detected or forced color system
     color_system: ColorSystem,
+    /// Capabilities probed once at construction; `color_system` above tracks
+    /// any subsequent override via `Console::color_system`/`force_color`.
+    capabilities: TerminalCapabilities,
     /// Whether to use markup parsing
     markup: bool,
     /// Whether to translate emoji shortcodes
     emoji: bool,
     /// Soft wrap text at terminal width
     soft_wrap: bool,
+    /// How lines exceeding the console width get broken when `soft_wrap`
+    /// is enabled. See [`Console::wrapping_mode`].
+    wrapping_mode: WrappingMode,
+    /// Whether spans carrying a [`Span::link`] are emitted as OSC 8
+    /// terminal hyperlinks. Still suppressed when the output isn't a TTY,
+    /// since escape sequences sent to a pipe or file would just be noise.
+    hyperlinks: bool,
+    /// Whether to automatically highlight numbers, strings, booleans, URLs
+    /// and similar "repr-like" tokens in printed text (see
+    /// [`crate::highlighter::ReprHighlighter`]).
+    highlight: bool,
+    /// Theme used to resolve automatic highlighting colors.
+    theme: Theme,
     /// Whether recording is enabled
     record: std::sync::Arc<std::sync::atomic::AtomicBool>,
     /// Buffer for recorded segments
     recording: std::sync::Arc<std::sync::Mutex<Vec<Segment>>>,
+    /// Overrides [`animation_supported`] when set, mirroring `force_color`.
+    /// See [`Console::force_animation`].
+    force_animation: Option<bool>,
+    /// Column width a `\t` expands to. See [`Console::tab_width`].
+    tab_width: usize,
+    /// Whether control characters are rendered visibly (Unicode control
+    /// pictures) instead of passed through literally. See
+    /// [`Console::show_nonprintable`].
+    show_nonprintable: bool,
+}
+
+/// Whether terminal animation (cursor hiding, in-place redraw escapes) is
+/// safe to use: requires an attached TTY, and rules out `TERM=dumb` (which
+/// can't interpret the escapes) and a `CI` environment (where they'd just
+/// mangle the captured log). Shared by [`crate::live::Live`] and
+/// [`crate::progress::bar::Progress`] so both degrade the same way.
+pub fn animation_supported() -> bool {
+    if std::env::var("CI").is_ok() {
+        return false;
+    }
+    if std::env::var("TERM").as_deref() == Ok("dumb") {
+        return false;
+    }
+    io::stdout().is_terminal()
 }
 
 #[derive(Debug, Clone)]
@@ -129,38 +344,106 @@ impl Default for Console {
     }
 }
 
+impl Clone for Console {
+    /// Clone this console's configuration (output target, theme, width,
+    /// color settings, markup/highlight toggles), but start the clone with
+    /// its own independent recording buffer rather than sharing the
+    /// original's. Used to snapshot a configured [`Console`] into the
+    /// per-thread template new threads initialize their macro consoles from
+    /// (see [`crate::set_default_console`]).
+    fn clone(&self) -> Self {
+        Console {
+            output: self.output.clone(),
+            width: self.width,
+            force_color: self.force_color,
+            color_enabled: self.color_enabled,
+            color_system: self.color_system,
+            capabilities: self.capabilities,
+            markup: self.markup,
+            emoji: self.emoji,
+            soft_wrap: self.soft_wrap,
+            wrapping_mode: self.wrapping_mode,
+            hyperlinks: self.hyperlinks,
+            highlight: self.highlight,
+            theme: self.theme.clone(),
+            record: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(
+                self.record.load(std::sync::atomic::Ordering::Relaxed),
+            )),
+            recording: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            force_animation: self.force_animation,
+            tab_width: self.tab_width,
+            show_nonprintable: self.show_nonprintable,
+        }
+    }
+}
+
 impl Console {
     /// Create a new Console writing to stdout.
     pub fn new() -> Self {
-        let (color_enabled, color_system) = Self::detect_color_system();
+        let is_tty = io::stdout().is_terminal();
+        let (color_enabled, color_system) = Self::resolve_color_system(is_tty, default_color_choice());
         Console {
             output: ConsoleOutput::Stdout,
             width: None,
-            force_color: false,
+            force_color: default_color_choice() == ColorChoice::Always,
             color_enabled,
             color_system,
+            capabilities: TerminalCapabilities::detect(is_tty),
             markup: true,
             emoji: true,
             soft_wrap: true,
+            wrapping_mode: WrappingMode::Word,
+            hyperlinks: true,
+            highlight: true,
+            theme: Theme::default_theme(),
             record: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             recording: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            force_animation: None,
+            tab_width: 8,
+            show_nonprintable: false,
         }
     }
 
+    /// Create a new Console writing to stdout with explicit auto-detected
+    /// color support.
+    ///
+    /// This is an explicit, discoverable alias for [`Console::new`], which
+    /// already auto-detects color the same way: honoring `NO_COLOR`,
+    /// `CLICOLOR_FORCE`/`FORCE_COLOR`, `CLICOLOR=0`, then falling back to a
+    /// TTY check and `COLORTERM`/`TERM` inspection (see
+    /// [`Console::resolve_color_system`]). Reach for this when a call site
+    /// wants to read "auto-detect" at a glance rather than relying on
+    /// `new`'s default behavior, or to revert after an earlier
+    /// [`Console::color_system`]/[`Console::force_color`] override via
+    /// [`Console::color_choice`]`(ColorChoice::Auto)`. The resolved system
+    /// can be read back via [`Console::capabilities`].
+    pub fn auto() -> Self {
+        Self::new()
+    }
+
     /// Create a new Console writing to stderr.
     pub fn stderr() -> Self {
-        let (color_enabled, color_system) = Self::detect_color_system();
+        let is_tty = io::stderr().is_terminal();
+        let (color_enabled, color_system) = Self::resolve_color_system(is_tty, default_color_choice());
         Console {
             output: ConsoleOutput::Stderr,
             width: None,
-            force_color: false,
+            force_color: default_color_choice() == ColorChoice::Always,
             color_enabled,
             color_system,
+            capabilities: TerminalCapabilities::detect(is_tty),
             markup: true,
             emoji: true,
             soft_wrap: true,
+            wrapping_mode: WrappingMode::Word,
+            hyperlinks: true,
+            highlight: true,
+            theme: Theme::default_theme(),
             record: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             recording: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            force_animation: None,
+            tab_width: 8,
+            show_nonprintable: false,
         }
     }
 
@@ -174,11 +457,25 @@ impl Console {
             force_color: true, // Force color for tests
             color_enabled: true,
             color_system: ColorSystem::TrueColor, // Capture assumes good capabilities
+            capabilities: TerminalCapabilities {
+                color_system: ColorSystem::TrueColor,
+                italic: true,
+                strikethrough: true,
+            },
             markup: true,
             emoji: true,
             soft_wrap: true,
+            wrapping_mode: WrappingMode::Word,
+            hyperlinks: true,
+            highlight: true,
+            theme: Theme::default_theme(),
             record: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             recording: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+            // Captured output feeds tests and `Live`/`Progress`'s internal
+            // diffing, so it should never carry cursor/hide escapes.
+            force_animation: Some(false),
+            tab_width: 8,
+            show_nonprintable: false,
         }
     }
 
@@ -209,17 +506,48 @@ impl Console {
                 self.color_system = ColorSystem::Standard;
             }
         }
+        self.capabilities.color_system = self.color_system;
         self
     }
 
+    /// Force animated output (cursor hiding, in-place redraws) on or off,
+    /// overriding [`animation_supported`]'s TTY/`TERM`/`CI` detection.
+    /// Mirrors [`Console::force_color`]; used by tests that want to opt
+    /// back into animation despite running non-interactively, and by power
+    /// users who know better than the detection heuristic.
+    pub fn force_animation(mut self, enabled: bool) -> Self {
+        self.force_animation = Some(enabled);
+        self
+    }
+
+    /// Whether animated output is enabled for this console: the
+    /// [`Console::force_animation`] override if set, otherwise
+    /// [`animation_supported`]'s environment detection.
+    pub fn animation_enabled(&self) -> bool {
+        self.force_animation.unwrap_or_else(animation_supported)
+    }
+
     /// Set the color system explicitly.
     pub fn color_system(mut self, system: ColorSystem) -> Self {
         self.color_system = system;
         // If explicitly setting a color system (other than NoColor), enable color
         self.color_enabled = system != ColorSystem::NoColor;
+        self.capabilities.color_system = self.color_system;
         self
     }
 
+    /// The terminal capabilities detected (or forced) for this console.
+    pub fn capabilities(&self) -> TerminalCapabilities {
+        self.capabilities
+    }
+
+    /// Whether this console writes to stderr, as opposed to stdout or an
+    /// in-memory buffer. Used by [`crate::set_default_console`] to route a
+    /// reconfigured console to the matching macro thread-locals.
+    pub(crate) fn is_stderr_target(&self) -> bool {
+        matches!(self.output, ConsoleOutput::Stderr)
+    }
+
     /// Enable or disable markup parsing.
     pub fn markup(mut self, enabled: bool) -> Self {
         self.markup = enabled;
@@ -238,6 +566,136 @@ impl Console {
         self
     }
 
+    /// Choose how lines exceeding the console width are broken while
+    /// [`Console::soft_wrap`] is enabled (the default, [`WrappingMode::Word`]).
+    /// Has no effect when `soft_wrap` is disabled.
+    pub fn wrapping_mode(mut self, mode: WrappingMode) -> Self {
+        self.wrapping_mode = mode;
+        self
+    }
+
+    /// Set the column width a `\t` expands to (default 8). Expansion lands
+    /// on the next tab stop computed from the running display column, not a
+    /// flat replacement, so tabs line up the same way a real terminal would
+    /// render them.
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.tab_width = width.max(1);
+        self
+    }
+
+    /// Render control characters visibly (as Unicode control pictures, e.g.
+    /// `\0` as `␀`) instead of passing them through literally, so printing
+    /// debug or binary-ish content can't corrupt the terminal. Disabled by
+    /// default, mirroring `bat`'s `--show-nonprintable`.
+    pub fn show_nonprintable(mut self, enabled: bool) -> Self {
+        self.show_nonprintable = enabled;
+        self
+    }
+
+    /// Expand tabs and (optionally) render control characters visibly.
+    /// Runs in [`Console::print`]/[`Console::print_raw`] before markup
+    /// parsing or highlighting, so the transform is baked into the `Text`
+    /// those build -- any later render, recording, or export sees the
+    /// already-expanded content rather than the raw input.
+    fn preprocess(&self, content: &str) -> String {
+        if self.tab_width == 8 && !self.show_nonprintable && !content.contains('\t') {
+            // Common case: nothing to do, avoid the per-char rebuild.
+            return content.to_string();
+        }
+
+        let mut out = String::with_capacity(content.len());
+        let mut column = 0usize;
+        for ch in content.chars() {
+            match ch {
+                '\t' => {
+                    let next_stop = (column / self.tab_width + 1) * self.tab_width;
+                    for _ in column..next_stop {
+                        out.push(' ');
+                    }
+                    column = next_stop;
+                }
+                '\n' => {
+                    if self.show_nonprintable {
+                        // U+2424 SYMBOL FOR NEWLINE marks the trailing
+                        // newline without dropping the real line break.
+                        out.push('\u{2424}');
+                    }
+                    out.push('\n');
+                    column = 0;
+                }
+                c if self.show_nonprintable && (c as u32) < 0x20 => {
+                    // Unicode Control Pictures block mirrors the C0 range
+                    // one-to-one (U+2400 + code point), e.g. `\0` -> `␀`.
+                    out.push(char::from_u32(0x2400 + c as u32).unwrap_or(c));
+                    column += 1;
+                }
+                '\u{7f}' if self.show_nonprintable => {
+                    out.push('\u{2421}'); // SYMBOL FOR DELETE
+                    column += 1;
+                }
+                c => {
+                    out.push(c);
+                    column += crate::bidi::display_width(&c.to_string());
+                }
+            }
+        }
+        out
+    }
+
+    /// Enable or disable automatic highlighting of numbers, strings,
+    /// booleans, URLs and similar tokens in printed text (see
+    /// [`crate::highlighter::ReprHighlighter`]). Enabled by default, matching
+    /// Rich's own behavior. Markup-styled spans are never overridden, so
+    /// explicit `[bold]...[/bold]`-style tags always take precedence.
+    pub fn highlight(mut self, enabled: bool) -> Self {
+        self.highlight = enabled;
+        self
+    }
+
+    /// Set the theme used to resolve automatic highlighting colors.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Enable or disable OSC 8 terminal hyperlinks for spans carrying a
+    /// [`Span::link`]. Even when enabled, links are only emitted when the
+    /// output is an actual TTY (see [`Console::links_supported`]).
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
+    /// Whether OSC 8 hyperlinks will actually be emitted: hyperlinks must
+    /// be enabled, color must not have been disabled (OSC 8 is an escape
+    /// sequence just like SGR, so the same `color_system`/`force_color`
+    /// plumbing that suppresses styling via [`ColorSystem::NoColor`] --
+    /// e.g. [`Console::color_choice`]`(ColorChoice::Never)` -- suppresses
+    /// links too), the output must be a real terminal (sending escape
+    /// sequences to a pipe or file would just corrupt the content), and the
+    /// environment mustn't opt out via `NO_COLOR` or flag an emulator known
+    /// to render the raw escape instead of a clickable link (VS Code's
+    /// integrated terminal, `TERM_PROGRAM=vscode`, as of this writing).
+    fn links_supported(&self) -> bool {
+        if !self.hyperlinks {
+            return false;
+        }
+        if !self.color_enabled || self.color_system == ColorSystem::NoColor {
+            return false;
+        }
+        if std::env::var("NO_COLOR").is_ok() {
+            return false;
+        }
+        if std::env::var("TERM_PROGRAM").as_deref() == Ok("vscode") {
+            return false;
+        }
+        match &self.output {
+            ConsoleOutput::Stdout => io::stdout().is_terminal(),
+            ConsoleOutput::Stderr => io::stderr().is_terminal(),
+            ConsoleOutput::Buffer(_) => false,
+        }
+    }
+
     /// Enable or disable recording of output.
     pub fn record(self, enabled: bool) -> Self {
         self.record
@@ -266,19 +724,56 @@ impl Console {
             .unwrap_or_else(|| terminal::size().map(|(w, _)| w as usize).unwrap_or(80))
     }
 
-    /// Detect color support and system.
-    fn detect_color_system() -> (bool, ColorSystem) {
-        // Check common environment variables
-        if std::env::var("NO_COLOR").is_ok() {
+    /// Detect color support and system. `is_tty` should reflect whether the
+    /// console's actual output stream is a real terminal; when it isn't
+    /// (output piped to a file or another process) and nothing is forcing
+    /// color, output is downgraded to [`ColorSystem::NoColor`] so redirected
+    /// output stays free of escape codes.
+    fn detect_color_system(is_tty: bool) -> (bool, ColorSystem) {
+        Console::resolve_color_system(is_tty, ColorChoice::Auto)
+    }
+
+    /// Resolve color support and system, honoring `choice` before falling
+    /// back to environment/TTY auto-detection. Implements the precedence
+    /// used by the `anstyle`/`colorchoice` ecosystem: `NO_COLOR` (if set to
+    /// any non-empty value) disables color outright; otherwise
+    /// `CLICOLOR_FORCE`/`FORCE_COLOR` force color even off a TTY; otherwise
+    /// `CLICOLOR=0` disables; otherwise color is enabled only on a real TTY,
+    /// with `TERM=dumb` always downgrading to none regardless of TTY state.
+    fn resolve_color_system(is_tty: bool, choice: ColorChoice) -> (bool, ColorSystem) {
+        if choice == ColorChoice::Never {
+            return (false, ColorSystem::NoColor);
+        }
+
+        let no_color = std::env::var("NO_COLOR")
+            .map(|v| !v.is_empty())
+            .unwrap_or(false);
+        if no_color {
             return (false, ColorSystem::NoColor);
         }
 
-        if std::env::var("FORCE_COLOR").is_ok() {
+        // CLICOLOR_FORCE (and the historical FORCE_COLOR) force color output
+        // even when stdout/stderr isn't a TTY, e.g. for CI logs that get
+        // colorized by a downstream viewer.
+        let clicolor_force = std::env::var("CLICOLOR_FORCE")
+            .map(|v| v != "0")
+            .unwrap_or(false);
+        if choice == ColorChoice::Always || clicolor_force || std::env::var("FORCE_COLOR").is_ok() {
             // Default to Standard if forced, can be upgraded by other checks if we were smarter,
-            // but for now FORCE_COLOR just ensures we have *some* color.
+            // but for now forcing just ensures we have *some* color.
             return (true, ColorSystem::Standard);
         }
 
+        let clicolor_disabled = std::env::var("CLICOLOR").as_deref() == Ok("0");
+        if clicolor_disabled {
+            return (false, ColorSystem::NoColor);
+        }
+
+        let dumb_term = std::env::var("TERM").as_deref() == Ok("dumb");
+        if !is_tty || dumb_term {
+            return (false, ColorSystem::NoColor);
+        }
+
         // Check COLORTERM for truecolor
         if let Ok(colorterm) = std::env::var("COLORTERM") {
             if colorterm.contains("truecolor") || colorterm.contains("24bit") {
@@ -293,27 +788,96 @@ impl Console {
             }
         }
 
-        // Fallback to Standard color if TTY (simplified)
-        // In a real app we'd check is_tty
+        // Real TTY with no more specific signal: assume basic 16-color support.
         (true, ColorSystem::Standard)
     }
 
+    /// Override this console's color support, bypassing environment/TTY
+    /// auto-detection in favor of [`ColorChoice::Always`]/[`ColorChoice::Never`],
+    /// or re-running auto-detection via [`ColorChoice::Auto`].
+    pub fn color_choice(mut self, choice: ColorChoice) -> Self {
+        let is_tty = match &self.output {
+            ConsoleOutput::Stdout => io::stdout().is_terminal(),
+            ConsoleOutput::Stderr => io::stderr().is_terminal(),
+            ConsoleOutput::Buffer(_) => false,
+        };
+        let (color_enabled, color_system) = Console::resolve_color_system(is_tty, choice);
+        self.force_color = choice == ColorChoice::Always;
+        self.color_enabled = color_enabled;
+        self.color_system = color_system;
+        self.capabilities.color_system = color_system;
+        self
+    }
+
     /// Print a string with markup support.
     pub fn print(&self, content: &str) {
+        let content = self.preprocess(content);
         let text = if self.markup {
-            markup::parse(content)
+            markup::parse(&content)
         } else {
-            Text::plain(content.to_string())
+            Text::plain(content)
         };
 
+        if !self.highlight {
+            self.print_renderable(&text);
+            return;
+        }
+
+        let context = RenderContext {
+            width: self.get_width(),
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = text.render(&context);
+        let segments = self.highlight_segments(segments);
+        self.write_segments(&segments);
+    }
+
+    /// Print `s`, a string already carrying ANSI/SGR escapes -- e.g. output
+    /// captured from another program -- preserving its styling instead of
+    /// treating the escapes as literal text or re-deriving style from
+    /// markup/auto-highlighting. Parsing is delegated to
+    /// [`crate::ansi_ingest::ingest_with_tab_width`] using this console's
+    /// configured [`Console::tab_width`], so the resulting spans can be
+    /// recorded and re-exported (HTML, SVG) with the subprocess's original
+    /// colors and attributes intact, and any `\t` in the captured stream
+    /// lands on the same column it did live.
+    pub fn print_ansi(&self, s: &str) {
+        let text = crate::ansi_ingest::ingest_with_tab_width(s, self.tab_width);
         self.print_renderable(&text);
     }
 
+    /// Apply [`ReprHighlighter`] to spans that are still at their default,
+    /// unstyled appearance, leaving spans already carrying explicit markup
+    /// styling or a link untouched. Run after markup parsing so explicit
+    /// `[...]` tags always win over automatic highlighting.
+    fn highlight_segments(&self, segments: Vec<Segment>) -> Vec<Segment> {
+        let highlighter = ReprHighlighter::new(&self.theme);
+        segments
+            .into_iter()
+            .map(|mut segment| {
+                let spans = std::mem::take(&mut segment.spans);
+                segment.spans = spans
+                    .into_iter()
+                    .flat_map(|span| {
+                        if span.style != crate::style::Style::default() || span.link.is_some() {
+                            vec![span]
+                        } else {
+                            highlighter.highlight(&span.text)
+                        }
+                    })
+                    .collect();
+                segment
+            })
+            .collect()
+    }
+
     /// Print any renderable object.
     pub fn print_renderable(&self, renderable: &dyn Renderable) {
         let context = RenderContext {
             width: self.get_width(),
             height: None,
+            direction: Default::default(),
         };
 
         let segments = renderable.render(&context);
@@ -331,7 +895,7 @@ impl Console {
     /// Use this when printing content that may contain brackets `[...]`
     /// that should NOT be interpreted as markup (e.g., debug output).
     pub fn print_raw(&self, content: &str) {
-        let text = Text::plain(content.to_string());
+        let text = Text::plain(self.preprocess(content));
         self.print_renderable(&text);
     }
 
@@ -344,6 +908,26 @@ impl Console {
         self.newline();
     }
 
+    /// Ingest a captured stream of raw terminal output -- SGR escapes,
+    /// carriage returns, backspaces, tabs -- and fold it into a [`Text`] as
+    /// a virtual terminal would display it, so overwrite-heavy output (a
+    /// progress bar, a REPL prompt) can be faithfully re-rendered inside a
+    /// [`Panel`](crate::panel::Panel) or table cell instead of showing every
+    /// overwritten byte concatenated in sequence. Tabs expand using this
+    /// console's configured [`Console::tab_width`].
+    pub fn ingest_ansi(&self, input: &str) -> Text {
+        crate::ansi_ingest::ingest_with_tab_width(input, self.tab_width)
+    }
+
+    /// Print a string after reshaping Arabic letters and reordering the
+    /// result into visual order via the Unicode Bidirectional Algorithm, so
+    /// RTL content prints left-to-right in terminal cell order instead of in
+    /// logical reading order. Requires the `rtl` feature; without it this is
+    /// equivalent to [`Console::print`].
+    pub fn print_bidi(&self, content: &str) {
+        self.print(&crate::shaping::reshape_and_display(content));
+    }
+
     /// Print an empty line.
     pub fn newline(&self) {
         let _ = self.write_raw("\n");
@@ -356,6 +940,17 @@ impl Console {
     }
 
     /// Write segments to the output.
+    ///
+    /// Recording (see [`Console::start_recording`]) always captures the
+    /// segments exactly as rendered by the caller, since HTML/SVG export
+    /// wants the renderable's own column layout, not a re-wrap for whatever
+    /// the real terminal's width happened to be at print time. Only the
+    /// bytes written to the actual output stream get wrapped.
+    ///
+    /// A `current_style` accumulator is threaded through every span written
+    /// so adjacent spans only emit the [`Style::difference`] between them
+    /// instead of a full reset-and-reapply at every boundary; if anything
+    /// was left active at the end, one trailing reset closes out the run.
     fn write_segments(&self, segments: &[Segment]) {
         if self.record.load(std::sync::atomic::Ordering::Relaxed) {
             if let Ok(mut lock) = self.recording.lock() {
@@ -363,27 +958,95 @@ impl Console {
             }
         }
 
+        let mode = if self.soft_wrap {
+            self.wrapping_mode
+        } else {
+            WrappingMode::None
+        };
+        let width = self.get_width();
+        let mut current_style = crate::style::Style::default();
+
         for segment in segments {
-            for span in &segment.spans {
-                self.write_span(span);
+            if mode == WrappingMode::None || width == 0 {
+                for span in &segment.spans {
+                    self.write_span(span, &mut current_style);
+                }
+            } else {
+                let rows = wrap_segment_spans(&segment.spans, width, mode);
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        let _ = self.write_raw("\n");
+                    }
+                    for span in row {
+                        self.write_span(span, &mut current_style);
+                    }
+                }
             }
             if segment.newline {
                 let _ = self.write_raw("\n");
             }
         }
+        if current_style != crate::style::Style::default() {
+            let mut writer = self.get_writer();
+            let _ = execute!(writer, SetAttribute(Attribute::Reset));
+        }
         let _ = self.flush();
     }
 
     /// Write a single span with styling.
-    fn write_span(&self, span: &Span) {
-        if !self.color_enabled || self.color_system == ColorSystem::NoColor || span.style.is_empty()
-        {
+    fn write_span(&self, span: &Span, current_style: &mut crate::style::Style) {
+        let link = span.link.as_deref().filter(|_| self.links_supported());
+        if let Some(url) = link {
+            let _ = self.write_raw(&osc8_open(url));
+        }
+
+        self.write_span_styled(span, current_style);
+
+        if link.is_some() {
+            let _ = self.write_raw(osc8_close());
+        }
+    }
+
+    /// Write a single span's text and styling, without any hyperlink
+    /// wrapping (handled by the caller, [`Console::write_span`]).
+    ///
+    /// Rather than always emitting a full reset-and-reapply, this computes
+    /// [`Style::difference`] between `current_style` (whatever the terminal
+    /// was last left in) and the span's own style, so a run of
+    /// similarly-styled spans only pays for the attributes/colors that
+    /// actually change at each boundary. `current_style` is updated to the
+    /// span's style afterward so the next call sees what's really active.
+    fn write_span_styled(&self, span: &Span, current_style: &mut crate::style::Style) {
+        if !self.color_enabled || self.color_system == ColorSystem::NoColor {
             let _ = self.write_raw(&span.text);
             return;
         }
 
         let mut writer = self.get_writer();
 
+        match current_style.difference(&span.style) {
+            crate::style::StyleDiff::NoChange => {}
+            crate::style::StyleDiff::ExtraOnly(extra) => {
+                self.apply_style(&mut writer, &extra);
+            }
+            crate::style::StyleDiff::Reset(full) => {
+                let _ = execute!(writer, SetAttribute(Attribute::Reset));
+                self.apply_style(&mut writer, &full);
+            }
+        }
+
+        // Write the text
+        let _ = execute!(writer, Print(&span.text));
+
+        *current_style = span.style;
+    }
+
+    /// Apply a (possibly partial) style's colors and attributes via
+    /// crossterm, honoring the console's negotiated [`ColorSystem`] for
+    /// downsampling. Shared by [`Console::write_span_styled`] for both a
+    /// span's full style and the incremental fields from a
+    /// [`StyleDiff::ExtraOnly`](crate::style::StyleDiff::ExtraOnly).
+    fn apply_style(&self, writer: &mut Box<dyn Write>, style: &crate::style::Style) {
         // Helper to downsample colors based on system
         let process_color = |color: crate::style::Color| -> crossterm::style::Color {
             match self.color_system {
@@ -395,7 +1058,7 @@ impl Console {
         };
 
         // Set foreground color
-        if let Some(color) = span.style.foreground {
+        if let Some(color) = style.foreground {
             if matches!(
                 self.color_system,
                 ColorSystem::Standard | ColorSystem::Windows
@@ -413,7 +1076,7 @@ impl Console {
         }
 
         // Set background color
-        if let Some(color) = span.style.background {
+        if let Some(color) = style.background {
             if matches!(
                 self.color_system,
                 ColorSystem::Standard | ColorSystem::Windows
@@ -431,36 +1094,30 @@ impl Console {
         }
 
         // Set attributes
-        if span.style.bold {
+        if style.bold == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::Bold));
         }
-        if span.style.dim {
+        if style.dim == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::Dim));
         }
-        if span.style.italic {
+        if style.italic == Some(true) && self.capabilities.italic {
             let _ = execute!(writer, SetAttribute(Attribute::Italic));
         }
-        if span.style.underline {
+        if style.underline == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::Underlined));
         }
-        if span.style.blink {
+        if style.blink == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::SlowBlink));
         }
-        if span.style.reverse {
+        if style.reverse == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::Reverse));
         }
-        if span.style.hidden {
+        if style.hidden == Some(true) {
             let _ = execute!(writer, SetAttribute(Attribute::Hidden));
         }
-        if span.style.strikethrough {
+        if style.strikethrough == Some(true) && self.capabilities.strikethrough {
             let _ = execute!(writer, SetAttribute(Attribute::CrossedOut));
         }
-
-        // Write the text
-        let _ = execute!(writer, Print(&span.text));
-
-        // Reset all attributes (SGR 0 includes color reset)
-        let _ = execute!(writer, SetAttribute(Attribute::Reset));
     }
 
     /// Get the writer for this console.
@@ -493,6 +1150,21 @@ impl Console {
         }
     }
 
+    /// Write raw bytes to output in one call, so a caller holding a slice
+    /// of already-rendered bytes (e.g. [`ConsolePool::print_buffer`]) can
+    /// flush it without re-encoding through `write_raw`'s `&str` path.
+    fn write_bytes_raw(&self, bytes: &[u8]) -> io::Result<()> {
+        match &self.output {
+            ConsoleOutput::Stdout => io::stdout().write_all(bytes),
+            ConsoleOutput::Stderr => io::stderr().write_all(bytes),
+            ConsoleOutput::Buffer(buf) => {
+                let mut lock = buf.lock().map_err(|e| io::Error::other(e.to_string()))?;
+                lock.extend_from_slice(bytes);
+                Ok(())
+            }
+        }
+    }
+
     /// Flush the output.
     fn flush(&self) -> io::Result<()> {
         match &self.output {
@@ -553,6 +1225,14 @@ impl Console {
         self.newline();
     }
 
+    /// Print `content` with a [`Gradient`](crate::gradient::Gradient) applied
+    /// across its characters.
+    pub fn print_gradient(&self, content: &str, gradient: &crate::gradient::Gradient) {
+        let text = gradient.apply(content);
+        self.print_renderable(&text);
+        self.newline();
+    }
+
     /// Export a renderable as plain text.
     ///
     /// Returns the plain text representation without any ANSI codes.
@@ -560,6 +1240,7 @@ impl Console {
         let context = RenderContext {
             width: self.get_width(),
             height: None,
+            direction: Default::default(),
         };
         let segments = renderable.render(&context);
         self.segments_to_text(&segments)
@@ -576,39 +1257,70 @@ impl Console {
         result
     }
 
-    /// Export a renderable as HTML with inline styles.
-    ///
-    /// Returns an HTML string with styled `<span>` elements.
+    /// Export a renderable as HTML with inline styles, using the default
+    /// dark theme. See [`Console::export_html_with_config`] to customize
+    /// colors, font, or switch to a shared stylesheet.
     pub fn export_html(&self, renderable: &dyn Renderable) -> String {
+        self.export_html_with_config(renderable, &ExportConfig::default())
+    }
+
+    /// Export a renderable as HTML, applying `config`'s theme/font and
+    /// inline-styles-vs-stylesheet choice.
+    pub fn export_html_with_config(&self, renderable: &dyn Renderable, config: &ExportConfig) -> String {
         let context = RenderContext {
             width: self.get_width(),
             height: None,
+            direction: Default::default(),
         };
         let segments = renderable.render(&context);
-        self.segments_to_html(&segments)
+        self.segments_to_html(&segments, config)
     }
 
-    /// Save the recorded output as HTML.
+    /// Save the recorded output as HTML, using the default dark theme.
     pub fn save_html(&self, path: &str) -> io::Result<()> {
+        self.save_html_with_config(path, &ExportConfig::default())
+    }
+
+    /// Save the recorded output as HTML using `config`.
+    pub fn save_html_with_config(&self, path: &str, config: &ExportConfig) -> io::Result<()> {
         let segments = self.recording.lock().unwrap();
-        let html = self.segments_to_html(&segments);
+        let html = self.segments_to_html(&segments, config);
         std::fs::write(path, html)
     }
 
-    fn segments_to_html(&self, segments: &[Segment]) -> String {
-        let mut html = String::from("<pre style=\"font-family: monospace; background: #1e1e1e; color: #d4d4d4; padding: 1em;\">\n");
+    fn segments_to_html(&self, segments: &[Segment], config: &ExportConfig) -> String {
+        let mut stylesheet = String::new();
+        let mut class_names: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+
+        let mut html = String::new();
+        if let Some(title) = &config.window_title {
+            html.push_str(&format!("<div class=\"terminal-title\">{}</div>\n", html_escape(title)));
+        }
+        html.push_str(&format!(
+            "<pre style=\"font-family: {}; background: {}; color: {}; padding: 1em;\">\n",
+            config.font_family, config.background, config.foreground
+        ));
 
         for segment in segments {
             for span in &segment.spans {
                 let style_css = span.style.to_css();
-                if style_css.is_empty() {
-                    html.push_str(&html_escape(&span.text));
+                let escaped = html_escape(&span.text);
+                let styled = if style_css.is_empty() {
+                    escaped
+                } else if config.inline_styles {
+                    format!("<span style=\"{}\">{}</span>", style_css, escaped)
                 } else {
-                    html.push_str(&format!(
-                        "<span style=\"{}\">{}</span>",
-                        style_css,
-                        html_escape(&span.text)
-                    ));
+                    let next_class = format!("r{}", class_names.len() + 1);
+                    let class = class_names.entry(style_css.clone()).or_insert_with(|| {
+                        stylesheet.push_str(&format!(".{} {{ {} }}\n", next_class, style_css));
+                        next_class
+                    });
+                    format!("<span class=\"{}\">{}</span>", class, escaped)
+                };
+                if let Some(url) = &span.link {
+                    html.push_str(&format!("<a href=\"{}\">{}</a>", html_escape(url), styled));
+                } else {
+                    html.push_str(&styled);
                 }
             }
             if segment.newline {
@@ -617,74 +1329,288 @@ impl Console {
         }
 
         html.push_str("</pre>");
-        html
+
+        if config.inline_styles {
+            html
+        } else {
+            format!("<style>\n{}</style>\n{}", stylesheet, html)
+        }
     }
 
-    /// Export a renderable as SVG.
-    ///
-    /// Returns an SVG string with text elements.
+    /// Export a renderable as SVG, using the default dark theme. See
+    /// [`Console::export_svg_with_config`] to customize colors, font, or add
+    /// a terminal-window title bar.
     pub fn export_svg(&self, renderable: &dyn Renderable) -> String {
+        self.export_svg_with_config(renderable, &ExportConfig::default())
+    }
+
+    /// Export a renderable as SVG, applying `config`'s theme/font and
+    /// optional window chrome.
+    pub fn export_svg_with_config(&self, renderable: &dyn Renderable, config: &ExportConfig) -> String {
         let context = RenderContext {
             width: self.get_width(),
             height: None,
+            direction: Default::default(),
         };
         let segments = renderable.render(&context);
-        self.segments_to_svg(&segments)
+        self.segments_to_svg(&segments, config)
     }
 
-    /// Save the recorded output as SVG.
+    /// Save the recorded output as SVG, using the default dark theme.
     pub fn save_svg(&self, path: &str) -> io::Result<()> {
+        self.save_svg_with_config(path, &ExportConfig::default())
+    }
+
+    /// Save the recorded output as SVG using `config`.
+    pub fn save_svg_with_config(&self, path: &str, config: &ExportConfig) -> io::Result<()> {
         let segments = self.recording.lock().unwrap();
-        let svg = self.segments_to_svg(&segments);
+        let svg = self.segments_to_svg(&segments, config);
         std::fs::write(path, svg)
     }
 
-    fn segments_to_svg(&self, segments: &[Segment]) -> String {
+    fn segments_to_svg(&self, segments: &[Segment], config: &ExportConfig) -> String {
         let char_width = 9.6; // Approximate monospace character width
         let line_height = 20.0;
         let padding = 10.0;
+        let chrome_height = if config.window_title.is_some() { 28.0 } else { 0.0 };
 
-        let mut lines: Vec<String> = Vec::new();
-        let mut current_line = String::new();
+        // One row per printed line, each row a sequence of (text, style) runs
+        // so backgrounds and foregrounds can be drawn per run rather than
+        // per line.
+        let mut rows: Vec<Vec<(String, crate::style::Style, Option<String>)>> = Vec::new();
+        let mut current_row: Vec<(String, crate::style::Style, Option<String>)> = Vec::new();
 
         for segment in segments {
             for span in &segment.spans {
-                current_line.push_str(&span.text);
+                current_row.push((span.text.clone(), span.style, span.link.clone()));
             }
             if segment.newline {
-                lines.push(std::mem::take(&mut current_line));
+                rows.push(std::mem::take(&mut current_row));
             }
         }
-        if !current_line.is_empty() {
-            lines.push(current_line);
+        if !current_row.is_empty() {
+            rows.push(current_row);
         }
 
-        let max_chars = lines.iter().map(|l| l.len()).max().unwrap_or(80);
+        let max_chars = rows
+            .iter()
+            .map(|row| row.iter().map(|(text, _, _)| crate::bidi::display_width(text)).sum::<usize>())
+            .max()
+            .unwrap_or(80);
         let width = (max_chars as f64 * char_width) + padding * 2.0;
-        let height = (lines.len() as f64 * line_height) + padding * 2.0;
+        let height = (rows.len() as f64 * line_height) + padding * 2.0 + chrome_height;
 
         let mut svg = format!(
             "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {:.0} {:.0}\">\n",
             width, height
         );
-        svg.push_str("  <rect width=\"100%\" height=\"100%\" fill=\"#1e1e1e\"/>\n");
-        svg.push_str("  <text font-family=\"monospace\" font-size=\"14\" fill=\"#d4d4d4\">\n");
+        svg.push_str(&format!("  <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n", config.background));
+
+        if let Some(title) = &config.window_title {
+            svg.push_str("  <rect width=\"100%\" height=\"28\" fill=\"#3a3a3a\"/>\n");
+            svg.push_str("  <circle cx=\"14\" cy=\"14\" r=\"6\" fill=\"#ff5f56\"/>\n");
+            svg.push_str("  <circle cx=\"34\" cy=\"14\" r=\"6\" fill=\"#ffbd2e\"/>\n");
+            svg.push_str("  <circle cx=\"54\" cy=\"14\" r=\"6\" fill=\"#27c93f\"/>\n");
+            svg.push_str(&format!(
+                "  <text x=\"{:.0}\" y=\"18\" font-family=\"{}\" font-size=\"13\" fill=\"#d4d4d4\" text-anchor=\"middle\">{}</text>\n",
+                width / 2.0,
+                config.font_family,
+                svg_escape(title)
+            ));
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            let y = padding + chrome_height + (i as f64 + 1.0) * line_height;
+
+            // Background rects are drawn as their own pass, behind the text
+            // row, since an SVG `<rect>` can't be a child of `<text>`.
+            let mut col = 0.0;
+            for (text, style, _link) in row {
+                let run_width = crate::bidi::display_width(text) as f64 * char_width;
+                if let Some(bg) = style.background {
+                    svg.push_str(&format!(
+                        "    <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>\n",
+                        padding + col,
+                        y - line_height + 5.0,
+                        run_width,
+                        line_height,
+                        bg.to_css()
+                    ));
+                }
+                col += run_width;
+            }
 
-        for (i, line) in lines.iter().enumerate() {
-            let y = padding + (i as f64 + 1.0) * line_height;
+            // One `<text>` per row holding one `<tspan>` per styled run, each
+            // explicitly positioned with `x` so a run's column offset never
+            // drifts from the background rects drawn above -- SVG text flow
+            // alone can't be trusted to match `display_width`'s column math
+            // for wide/zero-width characters.
             svg.push_str(&format!(
-                "    <tspan x=\"{}\" y=\"{:.1}\">{}</tspan>\n",
-                padding,
-                y,
-                svg_escape(line)
+                "    <text y=\"{:.1}\" font-family=\"{}\" font-size=\"14\">\n",
+                y, config.font_family
             ));
+            col = 0.0;
+            for (text, style, link) in row {
+                let run_width = crate::bidi::display_width(text) as f64 * char_width;
+                let x = padding + col;
+
+                let fill = style.foreground.map(|c| c.to_css()).unwrap_or_else(|| config.foreground.clone());
+                let mut tspan_style = String::new();
+                if style.bold == Some(true) {
+                    tspan_style.push_str(" font-weight=\"bold\"");
+                }
+                if style.italic == Some(true) {
+                    tspan_style.push_str(" font-style=\"italic\"");
+                }
+                let mut decorations = Vec::new();
+                if style.underline == Some(true) {
+                    decorations.push("underline");
+                }
+                if style.strikethrough == Some(true) {
+                    decorations.push("line-through");
+                }
+                if !decorations.is_empty() {
+                    tspan_style.push_str(&format!(" text-decoration=\"{}\"", decorations.join(" ")));
+                }
+
+                let tspan = format!(
+                    "<tspan x=\"{:.1}\" fill=\"{}\"{}>{}</tspan>",
+                    x,
+                    fill,
+                    tspan_style,
+                    svg_escape(text)
+                );
+                if let Some(url) = link {
+                    svg.push_str(&format!("      <a href=\"{}\">{}</a>\n", svg_escape(url), tspan));
+                } else {
+                    svg.push_str(&format!("      {}\n", tspan));
+                }
+
+                col += run_width;
+            }
+            svg.push_str("    </text>\n");
         }
 
-        svg.push_str("  </text>\n</svg>");
+        svg.push_str("</svg>");
         svg
     }
 }
 
+/// Hands out buffer-backed [`Console`] clones for worker threads to render
+/// into independently, then flushes each one to the pool's real output in
+/// whatever order the caller chooses -- borrowing `termcolor`'s
+/// `BufferWriter`/`Buffer` split so parallel rendering (e.g. syntax-
+/// highlighting many files at once across a thread pool) never interleaves
+/// mid-line on the shared terminal the way writing to `Console::new()`
+/// directly from multiple threads would.
+#[derive(Debug, Clone)]
+pub struct ConsolePool {
+    target: Console,
+}
+
+impl ConsolePool {
+    /// Create a pool that flushes to `target`'s real output (stdout or
+    /// stderr; a buffer target works too, e.g. for testing the pool
+    /// itself without touching the terminal).
+    pub fn new(target: Console) -> Self {
+        ConsolePool { target }
+    }
+
+    /// Hand out a detached console for a worker to render into: shares
+    /// `target`'s `color_system`/`width`/`markup`/theme/... settings (via
+    /// [`Console`]'s `Clone`), but with its own independent capture buffer
+    /// instead of `target`'s real output, so it's safe to render into
+    /// concurrently with other workers' consoles.
+    pub fn worker(&self) -> Console {
+        let mut worker = self.target.clone();
+        worker.output = ConsoleOutput::Buffer(std::sync::Arc::new(std::sync::Mutex::new(Vec::new())));
+        worker
+    }
+
+    /// Flush `worker`'s captured bytes to the pool's real output in one
+    /// write, so concurrent calls from multiple threads can't interleave
+    /// mid-line (writes to the real stdout/stderr are internally
+    /// serialized by the standard library). Call this on each worker's
+    /// console in whatever fixed order the output should appear in; a
+    /// `worker` console that isn't buffer-backed is a no-op.
+    pub fn print_buffer(&self, worker: &Console) -> io::Result<()> {
+        let bytes = match &worker.output {
+            ConsoleOutput::Buffer(buf) => buf.lock().map_err(|e| io::Error::other(e.to_string()))?.clone(),
+            _ => return Ok(()),
+        };
+        self.target.write_bytes_raw(&bytes)
+    }
+}
+
+/// Options controlling [`Console::export_html_with_config`]/
+/// [`Console::export_svg_with_config`] (and their `save_*` counterparts).
+///
+/// Defaults match a typical dark terminal theme: `#1e1e1e` background,
+/// `#d4d4d4` foreground, a generic monospace font stack, no window chrome,
+/// and inline `style="..."` attributes rather than a shared stylesheet.
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+    background: String,
+    foreground: String,
+    font_family: String,
+    window_title: Option<String>,
+    inline_styles: bool,
+}
+
+impl ExportConfig {
+    /// Start from the default dark theme.
+    pub fn new() -> Self {
+        ExportConfig::default()
+    }
+
+    /// Override the page/terminal background color (any valid CSS color).
+    pub fn with_background(mut self, background: impl Into<String>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Override the default text color used where a span has no foreground.
+    pub fn with_foreground(mut self, foreground: impl Into<String>) -> Self {
+        self.foreground = foreground.into();
+        self
+    }
+
+    /// Override the CSS `font-family` used for the `<pre>`/`<text>` output.
+    pub fn with_font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Label the export with `title`: [`Console::export_svg_with_config`]
+    /// wraps it in a terminal-window chrome (title bar with window control
+    /// dots), and [`Console::export_html_with_config`] emits a
+    /// `terminal-title` `<div>` before the `<pre>` block.
+    pub fn with_window_title(mut self, title: impl Into<String>) -> Self {
+        self.window_title = Some(title.into());
+        self
+    }
+
+    /// Emit a `<style>` stylesheet with one class per distinct style and
+    /// reference it via `class="..."`, instead of repeating the CSS inline
+    /// on every `<span>`. Only affects [`Console::export_html_with_config`].
+    pub fn with_stylesheet(mut self) -> Self {
+        self.inline_styles = false;
+        self
+    }
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        ExportConfig {
+            background: "#1e1e1e".to_string(),
+            foreground: "#d4d4d4".to_string(),
+            font_family: "monospace".to_string(),
+            window_title: None,
+            inline_styles: true,
+        }
+    }
+}
+
 /// A guard that captures output for testing.
 #[derive(Debug)]
 pub struct CapturedOutput {
@@ -740,4 +1666,426 @@ mod tests {
         assert!(console.force_color);
         assert!(console.color_enabled);
     }
+
+    #[test]
+    fn test_color_choice_never_disables_color_even_on_a_tty() {
+        assert_eq!(
+            Console::resolve_color_system(true, ColorChoice::Never),
+            (false, ColorSystem::NoColor)
+        );
+    }
+
+    #[test]
+    fn test_color_choice_always_forces_color_off_a_tty() {
+        assert_eq!(
+            Console::resolve_color_system(false, ColorChoice::Always),
+            (true, ColorSystem::Standard)
+        );
+    }
+
+    #[test]
+    fn test_console_color_choice_builder_overrides_detection() {
+        let console = Console::new().color_choice(ColorChoice::Never);
+        assert!(!console.color_enabled);
+        assert_eq!(console.color_system, ColorSystem::NoColor);
+
+        let console = Console::new().color_choice(ColorChoice::Always);
+        assert!(console.color_enabled);
+        assert!(console.force_color);
+    }
+
+    #[test]
+    fn test_detect_color_system_downgrades_to_no_color_off_tty() {
+        // Assumes none of NO_COLOR/CLICOLOR_FORCE/FORCE_COLOR are set in the
+        // test environment, matching how this function is already used
+        // unguarded elsewhere in the crate.
+        assert_eq!(
+            Console::detect_color_system(false),
+            (false, ColorSystem::NoColor)
+        );
+    }
+
+    #[test]
+    fn test_detect_color_system_enables_color_on_a_tty() {
+        // Mirrors `test_detect_color_system_downgrades_to_no_color_off_tty`'s
+        // same assumption (no NO_COLOR/CLICOLOR*/FORCE_COLOR set in the test
+        // environment): on a real TTY with no overrides, CLICOLOR's default
+        // (enabled) wins and color comes on.
+        let (enabled, system) = Console::detect_color_system(true);
+        assert!(enabled);
+        assert_ne!(system, ColorSystem::NoColor);
+    }
+
+    #[test]
+    fn test_ingest_ansi_overwrites_with_carriage_return() {
+        let console = Console::new();
+        let text = console.ingest_ansi("Loading...\rDone!");
+        assert_eq!(text.plain_text(), "Done!ng...");
+    }
+
+    #[test]
+    fn test_links_suppressed_on_non_tty_capture_console() {
+        // Console::capture() writes to an in-memory buffer, never a real
+        // terminal, so hyperlinks must never be emitted even though
+        // `hyperlinks` defaults to enabled.
+        let console = Console::capture();
+        assert!(console.hyperlinks);
+        assert!(!console.links_supported());
+    }
+
+    #[test]
+    fn test_links_suppressed_when_hyperlinks_disabled() {
+        let console = Console::capture().hyperlinks(false);
+        assert!(!console.links_supported());
+    }
+
+    #[test]
+    fn test_links_suppressed_when_color_choice_is_never() {
+        // OSC 8 is an escape sequence just like SGR, so disabling color via
+        // ColorChoice::Never must suppress hyperlinks too, not just styling.
+        let console = Console::capture()
+            .hyperlinks(true)
+            .color_choice(ColorChoice::Never);
+        assert!(!console.color_enabled);
+        assert_eq!(console.color_system, ColorSystem::NoColor);
+        assert!(!console.links_supported());
+    }
+
+    #[test]
+    fn test_osc8_open_and_close_frame_the_url_with_st_not_sgr() {
+        assert_eq!(osc8_open("https://example.com"), "\x1b]8;;https://example.com\x1b\\");
+        assert_eq!(osc8_close(), "\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    fn test_write_span_with_link_on_capture_console_emits_no_escape() {
+        let console = Console::capture();
+        let mut span = Span::styled("example".to_string(), crate::style::Style::new());
+        span.link = Some("https://example.com".to_string());
+        let mut current_style = crate::style::Style::default();
+        console.write_span(&span, &mut current_style);
+        let output = console.get_captured_output();
+        assert!(!output.contains("\x1b]8;;"));
+        assert!(output.contains("example"));
+    }
+
+    #[test]
+    fn test_adjacent_spans_that_only_add_attributes_skip_the_reset() {
+        let console = Console::capture();
+        let bold = crate::style::Style::new().bold();
+        let bold_underline = crate::style::Style::new().bold().underline();
+        let text = Text::from_spans(vec![
+            Span::styled("one".to_string(), bold),
+            Span::styled("two".to_string(), bold_underline),
+        ]);
+        console.print_renderable(&text);
+        let output = console.get_captured_output();
+
+        // Going from bold to bold+underline only adds a code -- no reset
+        // should appear between the two spans, only the single trailing one
+        // that closes out the run.
+        assert_eq!(output.matches("\x1b[0m").count(), 1);
+        assert!(output.ends_with("\x1b[0m"));
+    }
+
+    #[test]
+    fn test_single_reset_after_a_styled_run_of_spans() {
+        let console = Console::capture();
+        let style = crate::style::Style::new().bold().foreground(crate::style::Color::Red);
+        let text = Text::from_spans(vec![
+            Span::styled("a".to_string(), style),
+            Span::styled("b".to_string(), style),
+            Span::styled("c".to_string(), style),
+        ]);
+        console.print_renderable(&text);
+        let output = console.get_captured_output();
+
+        // Three identical spans in a row should only ever need the prefix
+        // once (no re-emission since nothing changed) and a single trailing
+        // reset, not three.
+        assert_eq!(output.matches("\x1b[0m").count(), 1);
+    }
+
+    #[test]
+    fn test_style_change_that_turns_something_off_emits_a_reset() {
+        let console = Console::capture();
+        let bold_underline = crate::style::Style::new().bold().underline();
+        let bold_only = crate::style::Style::new().bold();
+        let text = Text::from_spans(vec![
+            Span::styled("one".to_string(), bold_underline),
+            Span::styled("two".to_string(), bold_only),
+        ]);
+        console.print_renderable(&text);
+        let output = console.get_captured_output();
+
+        // Dropping underline can't be done additively, so a reset has to
+        // appear before "two" is re-styled, plus the final trailing one.
+        assert_eq!(output.matches("\x1b[0m").count(), 2);
+    }
+
+    #[test]
+    fn test_print_ansi_preserves_captured_subprocess_styling() {
+        let console = Console::capture();
+        console.print_ansi("\x1b[1mbold\x1b[0m plain");
+        let output = console.get_captured_output();
+
+        assert!(output.contains("bold"));
+        assert!(output.contains("plain"));
+        // The escape sequence itself is re-derived from the parsed style
+        // (re-encoded for capture's forced color system), not passed
+        // through literally -- but *some* SGR sequence should still
+        // surround "bold".
+        assert!(output.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_print_auto_highlights_plain_numbers() {
+        let console = Console::capture();
+        console.print("count is 42");
+        let output = console.get_captured_output();
+        assert!(output.contains("42"));
+        // The plain surrounding words shouldn't pick up escape codes, but the
+        // number should be wrapped in some SGR sequence from the theme.
+        assert!(output.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_print_highlight_disabled_leaves_text_unstyled() {
+        let console = Console::capture().highlight(false);
+        console.print("count is 42");
+        let output = console.get_captured_output();
+        assert!(!output.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_print_explicit_markup_wins_over_auto_highlight() {
+        let console = Console::capture();
+        console.print("[bold]42[/bold]");
+        let output = console.get_captured_output();
+        assert!(output.contains("42"));
+        assert!(output.contains("1")); // bold SGR code present somewhere in the escape sequence
+    }
+
+    #[test]
+    fn test_wrap_segment_spans_word_mode_breaks_at_whitespace() {
+        let spans = vec![Span::styled(
+            "one two three".to_string(),
+            crate::style::Style::new(),
+        )];
+        let rows = wrap_segment_spans(&spans, 7, WrappingMode::Word);
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| row.iter().map(|s| s.text.as_ref()).collect::<String>())
+            .collect();
+        assert_eq!(lines, vec!["one two", "three"]);
+    }
+
+    #[test]
+    fn test_wrap_segment_spans_character_mode_ignores_word_boundaries() {
+        let spans = vec![Span::styled("abcdef".to_string(), crate::style::Style::new())];
+        let rows = wrap_segment_spans(&spans, 4, WrappingMode::Character);
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| row.iter().map(|s| s.text.as_ref()).collect::<String>())
+            .collect();
+        assert_eq!(lines, vec!["abcd", "ef"]);
+    }
+
+    #[test]
+    fn test_wrap_segment_spans_under_width_is_a_single_row() {
+        let spans = vec![Span::styled("hi".to_string(), crate::style::Style::new())];
+        let rows = wrap_segment_spans(&spans, 80, WrappingMode::Word);
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn test_print_wraps_long_plain_line_at_console_width() {
+        let console = Console::capture().width(7).highlight(false);
+        console.print("one two three");
+        let output = console.get_captured_output();
+        assert_eq!(output, "one two\nthree\n");
+    }
+
+    #[test]
+    fn test_print_does_not_wrap_when_soft_wrap_disabled() {
+        let console = Console::capture().width(7).highlight(false).soft_wrap(false);
+        console.print("one two three");
+        let output = console.get_captured_output();
+        assert_eq!(output, "one two three\n");
+    }
+
+    #[test]
+    fn test_preprocess_expands_tabs_to_the_next_tab_stop() {
+        let console = Console::capture().tab_width(4);
+        assert_eq!(console.preprocess("a\tb"), "a   b");
+        assert_eq!(console.preprocess("ab\tc"), "ab  c");
+        assert_eq!(console.preprocess("abcd\te"), "abcd    e");
+    }
+
+    #[test]
+    fn test_preprocess_resets_tab_column_after_newline() {
+        let console = Console::capture().tab_width(4);
+        assert_eq!(console.preprocess("abc\n\td"), "abc\n    d");
+    }
+
+    #[test]
+    fn test_preprocess_renders_control_characters_visibly() {
+        let console = Console::capture().show_nonprintable(true);
+        assert_eq!(console.preprocess("a\0b"), "a\u{2400}b");
+        assert_eq!(console.preprocess("x\u{7f}y"), "x\u{2421}y");
+    }
+
+    #[test]
+    fn test_preprocess_marks_trailing_newline_when_show_nonprintable() {
+        let console = Console::capture().show_nonprintable(true);
+        assert_eq!(console.preprocess("hi\n"), "hi\u{2424}\n");
+    }
+
+    #[test]
+    fn test_preprocess_is_a_no_op_by_default() {
+        let console = Console::capture();
+        assert_eq!(console.preprocess("plain text, no tabs"), "plain text, no tabs");
+    }
+
+    #[test]
+    fn test_print_raw_expands_tabs_before_printing() {
+        let console = Console::capture().tab_width(4).highlight(false);
+        console.print_raw("a\tb");
+        assert_eq!(console.get_captured_output(), "a   b\n");
+    }
+
+    #[test]
+    fn test_export_html_escapes_and_wraps_styled_spans() {
+        let console = Console::new();
+        let style = crate::style::Style::new().bold().foreground(crate::style::Color::Red);
+        let text = Text::from_spans(vec![Span::styled("<b&old>".to_string(), style)]);
+        let html = console.export_html(&text);
+
+        assert!(html.contains("&lt;b&amp;old&gt;"));
+        assert!(html.contains("font-weight: bold"));
+        assert!(html.starts_with("<pre"));
+    }
+
+    #[test]
+    fn test_export_html_with_stylesheet_emits_shared_classes() {
+        let console = Console::new();
+        let style = crate::style::Style::new().bold();
+        let text = Text::from_spans(vec![
+            Span::styled("one".to_string(), style),
+            Span::styled("two".to_string(), style),
+        ]);
+        let config = ExportConfig::new().with_stylesheet();
+        let html = console.export_html_with_config(&text, &config);
+
+        assert!(html.starts_with("<style>"));
+        // Both spans share one style, so only one class should be generated.
+        assert_eq!(html.matches("class=\"r1\"").count(), 2);
+        assert!(!html.contains("class=\"r2\""));
+    }
+
+    #[test]
+    fn test_export_html_wraps_linked_span_in_anchor() {
+        let console = Console::new();
+        let mut span = Span::styled("click me".to_string(), crate::style::Style::new());
+        span.link = Some("https://example.com".to_string());
+        let text = Text::from_spans(vec![span]);
+        let html = console.export_html(&text);
+
+        assert!(html.contains("<a href=\"https://example.com\">"));
+        assert!(html.contains("click me</a>"));
+    }
+
+    #[test]
+    fn test_export_svg_wraps_linked_span_in_anchor() {
+        let console = Console::new();
+        let mut span = Span::styled("click me".to_string(), crate::style::Style::new());
+        span.link = Some("https://example.com".to_string());
+        let text = Text::from_spans(vec![span]);
+        let svg = console.export_svg(&text);
+
+        assert!(svg.contains("<a href=\"https://example.com\">"));
+        assert!(svg.contains("</a>"));
+    }
+
+    #[test]
+    fn test_export_svg_draws_a_rect_for_spans_with_background() {
+        let console = Console::new();
+        let style = crate::style::Style::new().background(crate::style::Color::Blue);
+        let text = Text::from_spans(vec![Span::styled("hi".to_string(), style)]);
+        let svg = console.export_svg(&text);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<rect"));
+        assert!(svg.contains("#0000cd")); // Color::Blue's CSS hex
+    }
+
+    #[test]
+    fn test_export_svg_emits_one_tspan_per_styled_span() {
+        let console = Console::new();
+        let text = Text::from_spans(vec![
+            Span::styled("a".to_string(), crate::style::Style::new().bold()),
+            Span::styled("b".to_string(), crate::style::Style::new().italic()),
+        ]);
+        let svg = console.export_svg(&text);
+
+        assert_eq!(svg.matches("<tspan").count(), 2);
+        assert!(svg.contains("font-weight=\"bold\""));
+        assert!(svg.contains("font-style=\"italic\""));
+    }
+
+    #[test]
+    fn test_export_svg_sets_text_decoration_for_underline_and_strikethrough() {
+        let console = Console::new();
+        let text = Text::from_spans(vec![Span::styled(
+            "hi".to_string(),
+            crate::style::Style::new().underline().strikethrough(),
+        )]);
+        let svg = console.export_svg(&text);
+
+        assert!(svg.contains("text-decoration=\"underline line-through\""));
+    }
+
+    #[test]
+    fn test_console_pool_worker_shares_settings_but_has_its_own_buffer() {
+        let target = Console::new().width(40).force_color(true);
+        let pool = ConsolePool::new(target);
+
+        let worker_a = pool.worker();
+        let worker_b = pool.worker();
+        worker_a.print("alpha");
+        worker_b.print("beta");
+
+        assert_eq!(worker_a.get_width(), 40);
+        assert_eq!(worker_a.get_captured_output(), "alpha\n");
+        assert_eq!(worker_b.get_captured_output(), "beta\n");
+    }
+
+    #[test]
+    fn test_console_pool_print_buffer_flushes_worker_output_in_call_order() {
+        let target = Console::capture();
+        let pool = ConsolePool::new(target.clone());
+
+        let worker_a = pool.worker();
+        let worker_b = pool.worker();
+        worker_a.print("first");
+        worker_b.print("second");
+
+        // Flush in the reverse of render order -- the caller, not render
+        // order, decides the final interleaving.
+        pool.print_buffer(&worker_b).unwrap();
+        pool.print_buffer(&worker_a).unwrap();
+
+        assert_eq!(target.get_captured_output(), "second\nfirst\n");
+    }
+
+    #[test]
+    fn test_export_svg_with_window_title_adds_chrome() {
+        let console = Console::new();
+        let text = Text::plain("hello".to_string());
+        let config = ExportConfig::new().with_window_title("my-session");
+        let svg = console.export_svg_with_config(&text, &config);
+
+        assert!(svg.contains("my-session"));
+        assert!(svg.contains("#ff5f56")); // macOS-style window control dot
+    }
 }
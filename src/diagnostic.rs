@@ -0,0 +1,457 @@
+//! Compiler-style diagnostic rendering: source snippets with labeled spans.
+//!
+//! A `Diagnostic` takes a source string and a set of byte-range labels and
+//! renders a gutter of line numbers, the affected source lines (plus a
+//! configurable amount of context), and underline markers connecting each
+//! label to its message -- similar to the reports produced by `rustc` or
+//! other compiler diagnostics.
+
+use std::ops::Range;
+
+use crate::box_drawing;
+use crate::console::RenderContext;
+use crate::highlighter::Highlighter;
+use crate::panel::Panel;
+use crate::renderable::{Renderable, Segment};
+use crate::style::Style;
+use crate::text::{Span, Text};
+use crate::theme::Theme;
+
+/// Severity of a diagnostic label, controlling its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// A fatal problem (red).
+    Error,
+    /// A non-fatal concern (yellow).
+    Warning,
+    /// Additional context (blue).
+    Info,
+    /// A supplementary remark (cyan).
+    Note,
+}
+
+impl Severity {
+    /// The style used for this severity's underline and label text,
+    /// drawing its color from `theme` (`Error`/`Warning`/`Info` map to the
+    /// theme's matching semantic color; `Note` maps to `theme.primary`).
+    pub fn style(&self, theme: &Theme) -> Style {
+        let color = match self {
+            Severity::Error => theme.error,
+            Severity::Warning => theme.warning,
+            Severity::Info => theme.info,
+            Severity::Note => theme.primary,
+        };
+        Style::new().foreground(color).bold()
+    }
+
+    /// The underline glyph for this severity (heavy for errors, light otherwise).
+    fn underline_char(&self) -> char {
+        match self {
+            Severity::Error => '━',
+            _ => '─',
+        }
+    }
+}
+
+/// A single labeled span over the source, with a message and severity.
+#[derive(Debug, Clone)]
+pub struct Label {
+    /// Byte offset range into the diagnostic's source.
+    pub byte_span: Range<usize>,
+    /// The message shown beneath (or beside) the span.
+    pub message: String,
+    /// The severity, which drives color and underline weight.
+    pub severity: Severity,
+}
+
+impl Label {
+    /// Create a new label.
+    pub fn new(byte_span: Range<usize>, message: impl Into<String>, severity: Severity) -> Self {
+        Label {
+            byte_span,
+            message: message.into(),
+            severity,
+        }
+    }
+}
+
+/// A 1-based line number and 0-based column for a byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+/// A compiler-style diagnostic report over a source string.
+pub struct Diagnostic {
+    source: String,
+    code: Option<String>,
+    labels: Vec<Label>,
+    context_lines: usize,
+    theme: Theme,
+    highlighter: Option<Box<dyn Highlighter>>,
+}
+
+impl Diagnostic {
+    /// Create a new diagnostic over `source` with no labels yet.
+    pub fn new(source: impl Into<String>) -> Self {
+        Diagnostic {
+            source: source.into(),
+            code: None,
+            labels: Vec::new(),
+            context_lines: 1,
+            theme: Theme::default_theme(),
+            highlighter: None,
+        }
+    }
+
+    /// Set the diagnostic code shown as the panel title (e.g. `"E0382"`).
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Add a label for a byte range in the source.
+    pub fn label(mut self, byte_span: Range<usize>, message: impl Into<String>, severity: Severity) -> Self {
+        self.labels.push(Label::new(byte_span, message, severity));
+        self
+    }
+
+    /// Set the number of unaffected context lines shown before/after each span (default 1).
+    pub fn context_lines(mut self, n: usize) -> Self {
+        self.context_lines = n;
+        self
+    }
+
+    /// Replace the theme used to color severities (default [`Theme::default_theme`]).
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Colorize displayed source lines with `highlighter` (e.g. a
+    /// [`crate::highlighter::SyntaxHighlighter`]) instead of printing them
+    /// as plain text.
+    pub fn highlighter(mut self, highlighter: impl Highlighter + 'static) -> Self {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
+    /// Resolve a byte offset to a 1-based line and 0-based column.
+    ///
+    /// `byte_offset` is a caller-supplied [`Label::byte_span`] endpoint with
+    /// no validation, so it may land mid-character (or past the end of
+    /// `source`) by accident -- rounded down to the nearest char boundary
+    /// before slicing, rather than panicking on "byte index is not a char
+    /// boundary".
+    fn position_at(&self, byte_offset: usize) -> Position {
+        let offset = floor_char_boundary(&self.source, byte_offset);
+        let mut line = 1;
+        let mut line_start = 0;
+        for (i, ch) in self.source.char_indices() {
+            if i >= offset {
+                break;
+            }
+            if ch == '\n' {
+                line += 1;
+                line_start = i + 1;
+            }
+        }
+        let column = self.source[line_start..offset].chars().count();
+        Position { line, column }
+    }
+
+    /// The set of 1-based source lines to display: every labeled line plus
+    /// `context_lines` of padding before and after, deduplicated and sorted.
+    fn visible_lines(&self, total_lines: usize) -> Vec<usize> {
+        let mut lines: Vec<usize> = Vec::new();
+        for label in &self.labels {
+            let start = self.position_at(label.byte_span.start).line;
+            let end = self.position_at(label.byte_span.end.max(label.byte_span.start)).line;
+            let lo = start.saturating_sub(self.context_lines).max(1);
+            let hi = (end + self.context_lines).min(total_lines);
+            lines.extend(lo..=hi);
+        }
+        lines.sort_unstable();
+        lines.dedup();
+        lines
+    }
+
+    /// Labels whose span starts or ends on `line`, in source order.
+    fn labels_on_line(&self, line: usize) -> Vec<&Label> {
+        self.labels
+            .iter()
+            .filter(|l| {
+                let start = self.position_at(l.byte_span.start).line;
+                let end = self.position_at(l.byte_span.end.max(l.byte_span.start)).line;
+                start == line || end == line
+            })
+            .collect()
+    }
+
+    /// Render the gutter + source + underline body as segments (without the panel frame).
+    fn render_body(&self, gutter_width: usize) -> Vec<Segment> {
+        let lines: Vec<&str> = self.source.split('\n').collect();
+        let total_lines = lines.len();
+        let visible = self.visible_lines(total_lines);
+
+        let mut segments = Vec::new();
+        let mut previous = None;
+
+        for &line_no in &visible {
+            if let Some(prev) = previous {
+                if line_no > prev + 1 {
+                    segments.push(Segment::line(vec![Span::styled(
+                        format!("{:>width$} {} ...", "", box_drawing::SQUARE.cell.left, width = gutter_width),
+                        Style::new().dim(),
+                    )]));
+                }
+            }
+            previous = Some(line_no);
+
+            let text = lines.get(line_no - 1).copied().unwrap_or("");
+            let mut line_spans = vec![
+                Span::styled(format!("{:>width$} ", line_no, width = gutter_width), Style::new().dim()),
+                Span::raw(box_drawing::SQUARE.cell.left.to_string()),
+                Span::raw(" "),
+            ];
+            match &self.highlighter {
+                Some(highlighter) => line_spans.extend(highlighter.highlight(text)),
+                None => line_spans.push(Span::raw(text.to_string())),
+            }
+            segments.push(Segment::line(line_spans));
+
+            let single_line_labels: Vec<&Label> = self
+                .labels_on_line(line_no)
+                .into_iter()
+                .filter(|l| {
+                    let start = self.position_at(l.byte_span.start);
+                    let end = self.position_at(l.byte_span.end.max(l.byte_span.start));
+                    start.line == end.line && end.line == line_no
+                })
+                .collect();
+
+            // Rightmost label's span starts first in rendering so higher-indented
+            // labels stack below it, per the connector convention described above.
+            for (depth, label) in single_line_labels.iter().enumerate().rev() {
+                let start = self.position_at(label.byte_span.start).column;
+                let end = self.position_at(label.byte_span.end.max(label.byte_span.start + 1)).column;
+                let underline_width = end.saturating_sub(start).max(1);
+                let indent = gutter_width + 1 + 1 + 1 + start;
+
+                let mut spans = vec![Span::raw(" ".repeat(indent))];
+                spans.push(Span::styled(
+                    label.severity.underline_char().to_string().repeat(underline_width),
+                    label.severity.style(&self.theme),
+                ));
+                if depth == 0 {
+                    spans.push(Span::styled(format!(" {}", label.message), label.severity.style(&self.theme)));
+                }
+                segments.push(Segment::line(spans));
+            }
+
+            // Labels stacked below the underline row, connected by a corner glyph,
+            // one row per depth so higher labels route their bar past lower ones.
+            for (depth, label) in single_line_labels.iter().enumerate().skip(1).rev() {
+                let start = self.position_at(label.byte_span.start).column;
+                let indent = gutter_width + 1 + 1 + 1 + start;
+                let mut spans = vec![Span::raw(" ".repeat(indent))];
+                spans.push(Span::styled("╰─".to_string(), label.severity.style(&self.theme)));
+                spans.push(Span::styled(format!(" {}", label.message), label.severity.style(&self.theme)));
+                let _ = depth;
+                segments.push(Segment::line(spans));
+            }
+
+            // Multi-line spans: draw the margin bar / opening / closing glyphs.
+            for label in self.labels_on_line(line_no) {
+                let start_pos = self.position_at(label.byte_span.start);
+                let end_pos = self.position_at(label.byte_span.end.max(label.byte_span.start));
+                if start_pos.line == end_pos.line {
+                    continue;
+                }
+                if line_no == start_pos.line {
+                    let indent = gutter_width + 1 + 1 + 1 + start_pos.column;
+                    segments.push(Segment::line(vec![
+                        Span::raw(" ".repeat(indent)),
+                        Span::styled("╭".to_string(), label.severity.style(&self.theme)),
+                    ]));
+                } else if line_no == end_pos.line {
+                    segments.push(Segment::line(vec![
+                        Span::raw(" ".repeat(gutter_width + 1 + 1)),
+                        Span::styled("╰─ ".to_string(), label.severity.style(&self.theme)),
+                        Span::styled(label.message.clone(), label.severity.style(&self.theme)),
+                    ]));
+                } else {
+                    segments.push(Segment::line(vec![
+                        Span::raw(" ".repeat(gutter_width + 1)),
+                        Span::styled("│".to_string(), label.severity.style(&self.theme)),
+                    ]));
+                }
+            }
+        }
+
+        segments
+    }
+}
+
+impl Renderable for Diagnostic {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        let total_lines = self.source.split('\n').count();
+        let gutter_width = total_lines.to_string().len();
+
+        let body = self.render_body(gutter_width);
+        let mut spans = Vec::new();
+        for (i, segment) in body.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("\n".to_string()));
+            }
+            spans.extend(segment.spans);
+        }
+        let text = Text::from_spans(spans);
+
+        let overall_severity = self
+            .labels
+            .iter()
+            .map(|l| l.severity)
+            .max_by_key(|s| match s {
+                Severity::Error => 3,
+                Severity::Warning => 2,
+                Severity::Info => 1,
+                Severity::Note => 0,
+            })
+            .unwrap_or(Severity::Info);
+
+        let mut panel = Panel::from_renderable(text).style(overall_severity.style(&self.theme));
+        if let Some(code) = &self.code {
+            panel = panel.title(code);
+        }
+
+        panel.render(context)
+    }
+}
+
+/// The largest char boundary in `s` that is `<= index`, clamping `index`
+/// to `s.len()` first. Lets a caller-supplied byte offset that lands
+/// mid-character (or past the end of the string) be used safely for
+/// slicing instead of panicking.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while index > 0 && !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_at() {
+        let diag = Diagnostic::new("let x = 1;\nlet y = 2;");
+        let pos = diag.position_at(4);
+        assert_eq!(pos, Position { line: 1, column: 4 });
+
+        let pos2 = diag.position_at(11);
+        assert_eq!(pos2, Position { line: 2, column: 0 });
+    }
+
+    #[test]
+    fn test_single_line_label_renders_underline_and_message() {
+        let diag = Diagnostic::new("let x = 1;")
+            .label(4..5, "unused variable `x`", Severity::Warning)
+            .code("W001");
+
+        let context = RenderContext { width: 60, height: None, direction: Default::default() };
+        let segments = diag.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+
+        assert!(plain.iter().any(|line| line.contains("unused variable")));
+        assert!(plain.iter().any(|line| line.contains('─') || line.contains('━')));
+    }
+
+    #[test]
+    fn test_render_preserves_severity_span_styles_through_the_panel() {
+        // render() used to flatten render_body's styled Vec<Segment> through
+        // plain_text() before handing it to Panel::new, discarding every
+        // span's style -- only the panel border ended up colored. Assert on
+        // the rendered spans' own style, not just plain-text content.
+        let theme = Theme::default_theme();
+        let diag = Diagnostic::new("let x = 1;").label(4..5, "unused variable `x`", Severity::Warning);
+
+        let context = RenderContext { width: 60, height: None, direction: Default::default() };
+        let segments = diag.render(&context);
+
+        let expected_style = Severity::Warning.style(&theme);
+        let has_styled_message = segments
+            .iter()
+            .flat_map(|segment| segment.spans.iter())
+            .any(|span| span.text.contains("unused variable") && span.style == expected_style);
+
+        assert!(has_styled_message, "severity-styled message span should survive into the rendered panel");
+    }
+
+    #[test]
+    fn test_severity_style_uses_theme_colors() {
+        let theme = crate::theme::Theme::monokai();
+        assert_eq!(Severity::Error.style(&theme).foreground, Some(theme.error));
+        assert_eq!(Severity::Warning.style(&theme).foreground, Some(theme.warning));
+        assert_eq!(Severity::Info.style(&theme).foreground, Some(theme.info));
+        assert_eq!(Severity::Note.style(&theme).foreground, Some(theme.primary));
+    }
+
+    #[test]
+    fn test_diagnostic_highlighter_colors_source_line() {
+        use crate::highlighter::RegexHighlighter;
+
+        let diag = Diagnostic::new("let x = 1;")
+            .label(4..5, "unused variable `x`", Severity::Warning)
+            .highlighter(RegexHighlighter::number_highlighter(
+                Style::new().foreground(crate::style::Color::Cyan),
+            ));
+
+        let context = RenderContext { width: 60, height: None, direction: Default::default() };
+        let segments = diag.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        assert!(plain.iter().any(|line| line.contains("let x = 1;")));
+        assert!(segments
+            .iter()
+            .flat_map(|segment| segment.spans.iter())
+            .any(|span| span.text == "1" && span.style.foreground == Some(crate::style::Color::Cyan)));
+    }
+
+    #[test]
+    fn test_position_at_rounds_a_mid_character_offset_down_instead_of_panicking() {
+        // "中" is a 3-byte UTF-8 character at offset 0; offsets 1 and 2 land
+        // inside it and must not panic when sliced.
+        let diag = Diagnostic::new("中文");
+        assert_eq!(diag.position_at(1), Position { line: 1, column: 0 });
+        assert_eq!(diag.position_at(2), Position { line: 1, column: 0 });
+        assert_eq!(diag.position_at(3), Position { line: 1, column: 1 });
+    }
+
+    #[test]
+    fn test_label_with_mid_character_byte_span_renders_without_panicking() {
+        // A label whose span straddles a multi-byte character (as trivially
+        // happens when byte offsets are computed against a differently
+        // encoded copy of the source) must degrade gracefully, not panic.
+        let diag = Diagnostic::new("let 中 = 1;").label(4..5, "bad span", Severity::Error);
+
+        let context = RenderContext { width: 60, height: None, direction: Default::default() };
+        let segments = diag.render(&context);
+        assert!(!segments.is_empty());
+    }
+
+    #[test]
+    fn test_diagnostic_custom_theme_applies_to_render() {
+        let theme = crate::theme::Theme::monokai();
+        let diag = Diagnostic::new("let x = 1;")
+            .label(4..5, "unused", Severity::Error)
+            .theme(theme.clone());
+
+        let context = RenderContext { width: 60, height: None, direction: Default::default() };
+        let segments = diag.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        assert!(plain.iter().any(|line| line.contains("unused")));
+    }
+}
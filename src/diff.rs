@@ -0,0 +1,318 @@
+//! Line- and word-level diffing between two texts, rendered as colored diffs.
+//!
+//! [`Diff`] takes two multi-line strings, aligns their lines with a classic
+//! LCS-based diff, and renders the result either as a single unified column
+//! (`+`/`-`/` ` prefixes, like `diff -u`) or as a side-by-side two-column
+//! [`Table`] (like `diff -y`). When a run of removed lines is immediately
+//! followed by a run of added lines, the two runs are paired up line by
+//! line (as many pairs as the shorter run has lines) and a second LCS pass
+//! at word granularity highlights just the words that actually differ
+//! within each pair; leftover lines of the longer run are shown whole.
+
+use crate::console::RenderContext;
+use crate::panel::BorderStyle;
+use crate::renderable::{Renderable, Segment};
+use crate::style::{Color, Style};
+use crate::table::{Column, Table};
+use crate::text::{Span, Text};
+
+/// One step of an LCS-based alignment between two sequences.
+enum LcsOp<T> {
+    /// Present, unchanged, in both sequences.
+    Equal(T),
+    /// Present only in the first ("old") sequence.
+    Delete(T),
+    /// Present only in the second ("new") sequence.
+    Insert(T),
+}
+
+/// Align `a` and `b` with a longest-common-subsequence dynamic-programming
+/// table, backtracking from the start: on equality take the element (the
+/// "diagonal" move); otherwise step toward whichever neighbor keeps the
+/// longer common subsequence.
+fn lcs_diff<T: PartialEq + Copy>(a: &[T], b: &[T]) -> Vec<LcsOp<T>> {
+    let n = a.len();
+    let m = b.len();
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if a[i] == b[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            ops.push(LcsOp::Equal(a[i]));
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            ops.push(LcsOp::Delete(a[i]));
+            i += 1;
+        } else {
+            ops.push(LcsOp::Insert(b[j]));
+            j += 1;
+        }
+    }
+    while i < n {
+        ops.push(LcsOp::Delete(a[i]));
+        i += 1;
+    }
+    while j < m {
+        ops.push(LcsOp::Insert(b[j]));
+        j += 1;
+    }
+    ops
+}
+
+/// Word-level LCS between a changed line pair, returning the old and new
+/// line's words as spans with differing words marked via `.reverse()` so
+/// they stand out against the line's overall removed/added color.
+fn word_diff_spans(old_line: &str, new_line: &str, removed_style: Style, added_style: Style) -> (Vec<Span>, Vec<Span>) {
+    let old_words: Vec<&str> = old_line.split_whitespace().collect();
+    let new_words: Vec<&str> = new_line.split_whitespace().collect();
+    let ops = lcs_diff(&old_words, &new_words);
+
+    let mut old_spans = Vec::new();
+    let mut new_spans = Vec::new();
+    for op in &ops {
+        match op {
+            LcsOp::Equal(word) => {
+                old_spans.push(Span::styled(format!("{} ", word), removed_style));
+                new_spans.push(Span::styled(format!("{} ", word), added_style));
+            }
+            LcsOp::Delete(word) => {
+                old_spans.push(Span::styled(format!("{} ", word), removed_style.reverse()));
+            }
+            LcsOp::Insert(word) => {
+                new_spans.push(Span::styled(format!("{} ", word), added_style.reverse()));
+            }
+        }
+    }
+    (old_spans, new_spans)
+}
+
+/// Layout used to render a [`Diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// A single column, `+`/`-`/` ` prefixed lines (like `diff -u`).
+    Unified,
+    /// Two columns, old on the left and new on the right (like `diff -y`).
+    SideBySide,
+}
+
+/// A line-level diff between two multi-line strings, ready to render.
+pub struct Diff {
+    old: String,
+    new: String,
+    layout: DiffLayout,
+    removed_style: Style,
+    added_style: Style,
+}
+
+impl Diff {
+    /// Create a new diff between `old` and `new`, defaulting to
+    /// [`DiffLayout::Unified`] with the conventional red/green coloring.
+    pub fn new(old: impl Into<String>, new: impl Into<String>) -> Self {
+        Diff {
+            old: old.into(),
+            new: new.into(),
+            layout: DiffLayout::Unified,
+            removed_style: Style::new().foreground(Color::Red),
+            added_style: Style::new().foreground(Color::Green),
+        }
+    }
+
+    /// Render as a single unified column instead of side-by-side.
+    pub fn unified(mut self) -> Self {
+        self.layout = DiffLayout::Unified;
+        self
+    }
+
+    /// Render as a two-column side-by-side table instead of unified.
+    pub fn side_by_side(mut self) -> Self {
+        self.layout = DiffLayout::SideBySide;
+        self
+    }
+
+    /// Replace the style used for removed (old-only) lines and words.
+    pub fn removed_style(mut self, style: Style) -> Self {
+        self.removed_style = style;
+        self
+    }
+
+    /// Replace the style used for added (new-only) lines and words.
+    pub fn added_style(mut self, style: Style) -> Self {
+        self.added_style = style;
+        self
+    }
+
+    fn line_ops(&self) -> Vec<LcsOp<&str>> {
+        let old_lines: Vec<&str> = self.old.split('\n').collect();
+        let new_lines: Vec<&str> = self.new.split('\n').collect();
+        lcs_diff(&old_lines, &new_lines)
+    }
+
+    fn render_unified(&self) -> Vec<Segment> {
+        let ops = self.line_ops();
+        let mut segments = Vec::new();
+        let mut i = 0;
+        while i < ops.len() {
+            match ops[i] {
+                LcsOp::Equal(line) => {
+                    segments.push(Segment::line(vec![Span::raw(format!("  {}", line))]));
+                    i += 1;
+                }
+                LcsOp::Delete(_) => {
+                    let mut removed = Vec::new();
+                    while let Some(LcsOp::Delete(line)) = ops.get(i) {
+                        removed.push(*line);
+                        i += 1;
+                    }
+                    let mut added = Vec::new();
+                    while let Some(LcsOp::Insert(line)) = ops.get(i) {
+                        added.push(*line);
+                        i += 1;
+                    }
+
+                    let paired = removed.len().min(added.len());
+                    for k in 0..paired {
+                        let (old_spans, new_spans) =
+                            word_diff_spans(removed[k], added[k], self.removed_style, self.added_style);
+                        segments.push(Segment::line(prefixed_spans("-", old_spans, self.removed_style)));
+                        segments.push(Segment::line(prefixed_spans("+", new_spans, self.added_style)));
+                    }
+                    for line in &removed[paired..] {
+                        segments.push(Segment::line(vec![Span::styled(format!("- {}", line), self.removed_style)]));
+                    }
+                    for line in &added[paired..] {
+                        segments.push(Segment::line(vec![Span::styled(format!("+ {}", line), self.added_style)]));
+                    }
+                }
+                LcsOp::Insert(line) => {
+                    segments.push(Segment::line(vec![Span::styled(format!("+ {}", line), self.added_style)]));
+                    i += 1;
+                }
+            }
+        }
+        segments
+    }
+
+    fn render_side_by_side(&self, context: &RenderContext) -> Vec<Segment> {
+        let ops = self.line_ops();
+        let mut table = Table::new();
+        table.add_column(Column::new("old").header_style(Style::new().bold()));
+        table.add_column(Column::new("new").header_style(Style::new().bold()));
+
+        let mut i = 0;
+        while i < ops.len() {
+            match ops[i] {
+                LcsOp::Equal(line) => {
+                    table.add_row(vec![Text::plain(line.to_string()), Text::plain(line.to_string())]);
+                    i += 1;
+                }
+                LcsOp::Delete(_) => {
+                    let mut removed = Vec::new();
+                    while let Some(LcsOp::Delete(line)) = ops.get(i) {
+                        removed.push(*line);
+                        i += 1;
+                    }
+                    let mut added = Vec::new();
+                    while let Some(LcsOp::Insert(line)) = ops.get(i) {
+                        added.push(*line);
+                        i += 1;
+                    }
+
+                    let paired = removed.len().min(added.len());
+                    for k in 0..paired {
+                        let (old_spans, new_spans) =
+                            word_diff_spans(removed[k], added[k], self.removed_style, self.added_style);
+                        table.add_row(vec![Text::from_spans(old_spans), Text::from_spans(new_spans)]);
+                    }
+                    for line in &removed[paired..] {
+                        table.add_row(vec![
+                            Text::from_spans(vec![Span::styled(line.to_string(), self.removed_style)]),
+                            Text::plain(String::new()),
+                        ]);
+                    }
+                    for line in &added[paired..] {
+                        table.add_row(vec![
+                            Text::plain(String::new()),
+                            Text::from_spans(vec![Span::styled(line.to_string(), self.added_style)]),
+                        ]);
+                    }
+                }
+                LcsOp::Insert(line) => {
+                    table.add_row(vec![
+                        Text::plain(String::new()),
+                        Text::from_spans(vec![Span::styled(line.to_string(), self.added_style)]),
+                    ]);
+                    i += 1;
+                }
+            }
+        }
+
+        table = table.border_style(BorderStyle::Rounded);
+        table.render(context)
+    }
+}
+
+/// Prepend a plain `prefix` column to `spans`, styling the prefix itself with `style`.
+fn prefixed_spans(prefix: &str, spans: Vec<Span>, style: Style) -> Vec<Span> {
+    let mut line = vec![Span::styled(format!("{} ", prefix), style)];
+    line.extend(spans);
+    line
+}
+
+impl Renderable for Diff {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        match self.layout {
+            DiffLayout::Unified => self.render_unified(),
+            DiffLayout::SideBySide => self.render_side_by_side(context),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcs_diff_pure_insertion() {
+        let ops = lcs_diff(&["a", "b"], &["a", "x", "b"]);
+        let kinds: Vec<&str> = ops
+            .iter()
+            .map(|op| match op {
+                LcsOp::Equal(_) => "=",
+                LcsOp::Delete(_) => "-",
+                LcsOp::Insert(_) => "+",
+            })
+            .collect();
+        assert_eq!(kinds, vec!["=", "+", "="]);
+    }
+
+    #[test]
+    fn test_unified_diff_marks_added_and_removed_lines() {
+        let diff = Diff::new("one\ntwo\nthree", "one\nthree\nfour");
+        let segments = diff.render_unified();
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+
+        assert!(plain.iter().any(|l| l.starts_with("- two")));
+        assert!(plain.iter().any(|l| l.starts_with("+ four")));
+        assert!(plain.iter().any(|l| l == "  one"));
+    }
+
+    #[test]
+    fn test_word_diff_spans_highlight_only_the_changed_word() {
+        let (old, new) = word_diff_spans("the quick fox", "the slow fox", Style::new(), Style::new());
+        assert_eq!(old.len(), 3);
+        assert_eq!(new.len(), 3);
+        assert!(Text::from_spans(old).plain_text().contains("quick"));
+        assert!(Text::from_spans(new).plain_text().contains("slow"));
+    }
+}
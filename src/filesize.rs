@@ -0,0 +1,53 @@
+//! Human-readable byte-count formatting, shared by renderables that need to
+//! display file sizes or transfer rates (e.g.
+//! [`progress::columns::DownloadColumn`](crate::progress::columns::DownloadColumn)).
+
+/// Binary-prefix units used by [`format_bytes`], in ascending order.
+const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Format a byte count using binary SI prefixes (1024-based: B, KiB, MiB,
+/// GiB, TiB), picking the largest unit for which the value is at least one,
+/// with one decimal place (`"45.2MiB"`). Counts under 1024 bytes are shown
+/// with no decimal place (`"512B"`).
+pub fn format_bytes(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit_index = 0;
+    while value >= 1024.0 && unit_index < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit_index += 1;
+    }
+    if unit_index == 0 {
+        format!("{}{}", bytes, UNITS[0])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit_index])
+    }
+}
+
+/// Format a bytes-per-second rate the same way as [`format_bytes`], with a
+/// `"/s"` suffix (`"12.3MiB/s"`).
+pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_sec.max(0.0) as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes_picks_unit_by_magnitude() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1024), "1.0KiB");
+        assert_eq!(format_bytes(1_500_000), "1.4MiB");
+        assert_eq!(format_bytes(134 * 1024 * 1024), "134.0MiB");
+    }
+
+    #[test]
+    fn test_format_bytes_per_sec_appends_suffix() {
+        assert_eq!(format_bytes_per_sec(12_300_000.0), "11.7MiB/s");
+    }
+
+    #[test]
+    fn test_format_bytes_clamps_negative_rate_to_zero() {
+        assert_eq!(format_bytes_per_sec(-5.0), "0B/s");
+    }
+}
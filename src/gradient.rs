@@ -0,0 +1,278 @@
+//! Multi-stop gradient text coloring.
+//!
+//! [`Gradient`] smoothly interpolates a list of control colors across the
+//! characters of a string, assigning each one its own foreground color --
+//! hyfetch/neofetch-style banner gradients for any styled text.
+
+use crate::style::{Color, Style};
+use crate::text::{Span, Text};
+
+/// How [`Gradient::apply`] interpolates between control colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientMode {
+    /// Piecewise-linear interpolation between consecutive control colors.
+    #[default]
+    Linear,
+    /// A uniform, clamped cubic B-spline through the control colors
+    /// (De Boor recurrence), for smoother transitions with more than two
+    /// stops. Falls back to [`GradientMode::Linear`] when fewer than four
+    /// control colors are given.
+    Spline,
+}
+
+/// A multi-stop color gradient applied across the printable characters of
+/// a string, one control color per "stop". See [`Console::print_gradient`](crate::console::Console::print_gradient).
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    colors: Vec<Color>,
+    mode: GradientMode,
+    skip_whitespace: bool,
+}
+
+impl Gradient {
+    /// Create a gradient from its control colors, in order. A single
+    /// color produces a solid fill; an empty list leaves content
+    /// unstyled.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Gradient {
+            colors,
+            mode: GradientMode::default(),
+            skip_whitespace: false,
+        }
+    }
+
+    /// Interpolate with a uniform cubic B-spline through the control
+    /// colors instead of piecewise-linear segments.
+    pub fn spline(mut self) -> Self {
+        self.mode = GradientMode::Spline;
+        self
+    }
+
+    /// Skip whitespace-only positions when advancing through the
+    /// gradient, so runs of spaces don't consume steps of the color ramp.
+    pub fn skip_whitespace(mut self, skip: bool) -> Self {
+        self.skip_whitespace = skip;
+        self
+    }
+
+    /// Apply the gradient to `content`, returning one styled [`Span`] per
+    /// character. A single-color gradient returns one span covering all of
+    /// `content`, and an empty color list returns it unstyled.
+    pub fn apply(&self, content: &str) -> Text {
+        let chars: Vec<char> = content.chars().collect();
+        if chars.is_empty() || self.colors.is_empty() {
+            return Text::plain(content.to_string());
+        }
+        if self.colors.len() == 1 {
+            let style = Style::new().foreground(self.colors[0]);
+            return Text::from_spans(vec![Span::styled(content.to_string(), style)]);
+        }
+
+        let step_count = if self.skip_whitespace {
+            chars.iter().filter(|c| !c.is_whitespace()).count().max(1)
+        } else {
+            chars.len()
+        };
+
+        let mut spans = Vec::with_capacity(chars.len());
+        let mut step = 0usize;
+        let mut last_color = self.colors[0];
+
+        for ch in &chars {
+            let color = if self.skip_whitespace && ch.is_whitespace() {
+                last_color
+            } else {
+                let t = if step_count <= 1 {
+                    0.0
+                } else {
+                    step as f64 / (step_count - 1) as f64
+                };
+                step += 1;
+                let color = self.color_at(t);
+                last_color = color;
+                color
+            };
+            spans.push(Span::styled(ch.to_string(), Style::new().foreground(color)));
+        }
+
+        Text::from_spans(spans)
+    }
+
+    /// The interpolated color at parameter `t` in `[0, 1]`.
+    fn color_at(&self, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        match self.mode {
+            GradientMode::Linear => self.linear_color_at(t),
+            GradientMode::Spline if self.colors.len() >= 4 => self.spline_color_at(t),
+            GradientMode::Spline => self.linear_color_at(t),
+        }
+    }
+
+    /// Locate the segment `s = floor(t*(C-1))` and local fraction
+    /// `f = t*(C-1) - s`, then blend component-wise between control
+    /// colors `s` and `s+1`.
+    fn linear_color_at(&self, t: f64) -> Color {
+        let segments = self.colors.len() - 1;
+        let scaled = t * segments as f64;
+        let s = (scaled.floor() as usize).min(segments - 1);
+        let f = (scaled - s as f64) as f32;
+        self.colors[s].blend(&self.colors[s + 1], f)
+    }
+
+    /// Evaluate a uniform, clamped cubic (degree-3) B-spline through the
+    /// control colors at parameter `t`, independently per RGB channel,
+    /// via the De Boor recurrence. Requires at least 4 control colors.
+    fn spline_color_at(&self, t: f64) -> Color {
+        const DEGREE: usize = 3;
+
+        let points: Vec<(f64, f64, f64)> = self
+            .colors
+            .iter()
+            .map(|c| {
+                let (r, g, b) = c.to_rgb();
+                (r as f64, g as f64, b as f64)
+            })
+            .collect();
+        let n = points.len();
+
+        // Clamped uniform knot vector: DEGREE+1 repeated knots at each end,
+        // with evenly spaced interior knots in between.
+        let knot_count = n + DEGREE + 1;
+        let interior = knot_count - 2 * (DEGREE + 1);
+        let mut knots = Vec::with_capacity(knot_count);
+        knots.extend(std::iter::repeat(0.0).take(DEGREE + 1));
+        for i in 1..=interior {
+            knots.push(i as f64 / (interior + 1) as f64);
+        }
+        knots.extend(std::iter::repeat(1.0).take(DEGREE + 1));
+
+        // Find the knot span containing t, clamped so t = 1.0 lands in the
+        // last valid span instead of falling off the end.
+        let mut span = DEGREE;
+        while span < n - 1 && t >= knots[span + 1] {
+            span += 1;
+        }
+
+        // De Boor recurrence over the DEGREE+1 control points influencing
+        // this span.
+        let mut d: Vec<(f64, f64, f64)> =
+            (0..=DEGREE).map(|j| points[span - DEGREE + j]).collect();
+        for r in 1..=DEGREE {
+            for j in (r..=DEGREE).rev() {
+                let i = span - DEGREE + j;
+                let denom = knots[i + DEGREE - r + 1] - knots[i];
+                let alpha = if denom.abs() < f64::EPSILON {
+                    0.0
+                } else {
+                    (t - knots[i]) / denom
+                };
+                d[j] = (
+                    (1.0 - alpha) * d[j - 1].0 + alpha * d[j].0,
+                    (1.0 - alpha) * d[j - 1].1 + alpha * d[j].1,
+                    (1.0 - alpha) * d[j - 1].2 + alpha * d[j].2,
+                );
+            }
+        }
+
+        let (r, g, b) = d[DEGREE];
+        Color::rgb(
+            r.round().clamp(0.0, 255.0) as u8,
+            g.round().clamp(0.0, 255.0) as u8,
+            b.round().clamp(0.0, 255.0) as u8,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::RenderContext;
+    use crate::renderable::Renderable;
+
+    fn rendered_spans(gradient: &Gradient, content: &str) -> Vec<Span> {
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = gradient.apply(content).render(&context);
+        segments[0].spans.clone()
+    }
+
+    #[test]
+    fn test_linear_gradient_endpoints_match_control_colors() {
+        let gradient = Gradient::new(vec![Color::Red, Color::Blue]);
+        let spans = rendered_spans(&gradient, "abc");
+
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].style.foreground, Some(Color::Red));
+        assert_eq!(spans[2].style.foreground, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_linear_gradient_midpoint_is_the_blend() {
+        let red = Color::rgb(255, 0, 0);
+        let blue = Color::rgb(0, 0, 255);
+        let gradient = Gradient::new(vec![red, blue]);
+        let spans = rendered_spans(&gradient, "abc");
+
+        assert_eq!(spans[1].style.foreground, Some(red.blend(&blue, 0.5)));
+    }
+
+    #[test]
+    fn test_gradient_picks_the_right_segment_for_three_stops() {
+        let gradient = Gradient::new(vec![Color::Red, Color::Green, Color::Blue]);
+        let spans = rendered_spans(&gradient, "abcde");
+
+        assert_eq!(spans[0].style.foreground, Some(Color::Red));
+        assert_eq!(spans[2].style.foreground, Some(Color::Green));
+        assert_eq!(spans[4].style.foreground, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_skip_whitespace_does_not_consume_a_gradient_step() {
+        let with_space = Gradient::new(vec![Color::Red, Color::Blue]).skip_whitespace(true);
+        let without_space = Gradient::new(vec![Color::Red, Color::Blue]);
+
+        let with_space_colors: Vec<_> = rendered_spans(&with_space, "a b")
+            .into_iter()
+            .filter(|s| s.text != " ")
+            .map(|s| s.style.foreground)
+            .collect();
+        let without_space_colors: Vec<_> = rendered_spans(&without_space, "ab")
+            .into_iter()
+            .map(|s| s.style.foreground)
+            .collect();
+
+        assert_eq!(with_space_colors, without_space_colors);
+    }
+
+    #[test]
+    fn test_single_color_gradient_is_a_solid_fill() {
+        let gradient = Gradient::new(vec![Color::Green]);
+        let spans = rendered_spans(&gradient, "abc");
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].style.foreground, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_spline_mode_still_hits_the_first_and_last_control_colors() {
+        let colors = vec![
+            Color::rgb(255, 0, 0),
+            Color::rgb(0, 255, 0),
+            Color::rgb(0, 0, 255),
+            Color::rgb(255, 255, 0),
+        ];
+        let gradient = Gradient::new(colors.clone()).spline();
+        let spans = rendered_spans(&gradient, "abcdefgh");
+
+        assert_eq!(spans[0].style.foreground, Some(colors[0]));
+        assert_eq!(spans[spans.len() - 1].style.foreground, Some(colors[3]));
+    }
+
+    #[test]
+    fn test_spline_mode_falls_back_to_linear_under_four_stops() {
+        let gradient = Gradient::new(vec![Color::Red, Color::Blue]).spline();
+        let spans = rendered_spans(&gradient, "ab");
+
+        assert_eq!(spans[0].style.foreground, Some(Color::Red));
+        assert_eq!(spans[1].style.foreground, Some(Color::Blue));
+    }
+}
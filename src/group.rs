@@ -86,6 +86,8 @@ impl Renderable for RenderGroup {
                 Fit::Fill => context.clone(),
                 Fit::Shrink => RenderContext {
                     width: renderable.min_width().min(context.width),
+                    height: context.height,
+                    direction: context.direction,
                 },
             };
 
@@ -149,4 +151,35 @@ mod tests {
         let group = RenderGroup::new().divider("---");
         assert!(group.divider.is_some());
     }
+
+    #[test]
+    fn test_shrink_fit_propagates_direction_hint_to_children() {
+        use crate::bidi::TextDirection;
+        use crate::panel::Panel;
+
+        let mut ltr_group = RenderGroup::new();
+        ltr_group.add(Panel::new("hi"));
+        let ltr_group = ltr_group.fit(Fit::Shrink);
+        let ltr_context = RenderContext {
+            width: 40,
+            height: None,
+            direction: TextDirection::Ltr,
+        };
+        let ltr_output = ltr_group.render(&ltr_context);
+
+        let mut rtl_group = RenderGroup::new();
+        rtl_group.add(Panel::new("hi"));
+        let rtl_group = rtl_group.fit(Fit::Shrink);
+        let rtl_context = RenderContext {
+            width: 40,
+            height: None,
+            direction: TextDirection::Rtl,
+        };
+        let rtl_output = rtl_group.render(&rtl_context);
+
+        // Panel mirrors its border glyphs by direction, so propagating the
+        // hint through Fit::Shrink's freshly-built RenderContext (instead
+        // of dropping it) changes the rendered border.
+        assert_ne!(ltr_output[0].plain_text(), rtl_output[0].plain_text());
+    }
 }
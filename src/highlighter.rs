@@ -4,18 +4,44 @@
 
 use crate::style::Style;
 use crate::text::{Span, Text};
+use crate::theme::Theme;
 use regex::Regex;
 
+#[cfg(feature = "syntax")]
+use crate::style::Color;
+
 /// Trait for text highlighters.
 pub trait Highlighter {
     /// Highlight text and return styled spans.
     fn highlight(&self, text: &str) -> Vec<Span>;
 }
 
+/// How a matched region's target URI (for OSC 8 hyperlinks) is derived from
+/// the matched text, if at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinkKind {
+    /// The match carries no hyperlink, just a style.
+    None,
+    /// The match itself is the URI (e.g. a bare URL).
+    AsIs,
+    /// The match is wrapped in a `mailto:` URI.
+    MailTo,
+}
+
+impl LinkKind {
+    fn resolve(self, matched: &str) -> Option<String> {
+        match self {
+            LinkKind::None => None,
+            LinkKind::AsIs => Some(matched.to_string()),
+            LinkKind::MailTo => Some(format!("mailto:{matched}")),
+        }
+    }
+}
+
 /// A regex-based highlighter that applies styles to matched patterns.
 #[derive(Debug, Clone)]
 pub struct RegexHighlighter {
-    patterns: Vec<(Regex, Style)>,
+    patterns: Vec<(Regex, Style, LinkKind)>,
 }
 
 impl RegexHighlighter {
@@ -29,7 +55,7 @@ impl RegexHighlighter {
     /// Add a pattern with associated style.
     pub fn add_pattern(&mut self, pattern: &str, style: Style) -> Result<(), regex::Error> {
         let regex = Regex::new(pattern)?;
-        self.patterns.push((regex, style));
+        self.patterns.push((regex, style, LinkKind::None));
         Ok(())
     }
 
@@ -39,11 +65,26 @@ impl RegexHighlighter {
         Ok(self)
     }
 
-    /// Create a highlighter for URLs.
+    /// Add a pattern whose matches also become clickable hyperlinks, with
+    /// the matched text used as-is for the target URI.
+    pub fn add_link_pattern(&mut self, pattern: &str, style: Style) -> Result<(), regex::Error> {
+        let regex = Regex::new(pattern)?;
+        self.patterns.push((regex, style, LinkKind::AsIs));
+        Ok(())
+    }
+
+    /// Builder method to add a hyperlinked pattern.
+    pub fn with_link_pattern(mut self, pattern: &str, style: Style) -> Result<Self, regex::Error> {
+        self.add_link_pattern(pattern, style)?;
+        Ok(self)
+    }
+
+    /// Create a highlighter for URLs. Matches are also wrapped as OSC 8
+    /// hyperlinks pointing at the matched URL itself.
     pub fn url_highlighter(style: Style) -> Self {
         let mut hl = RegexHighlighter::new();
         // Simple URL pattern
-        let _ = hl.add_pattern(r"https?://[^\s]+", style);
+        let _ = hl.add_link_pattern(r"https?://[^\s]+", style);
         hl
     }
 
@@ -54,13 +95,12 @@ impl RegexHighlighter {
         hl
     }
 
-    /// Create a highlighter for email addresses.
+    /// Create a highlighter for email addresses. Matches are also wrapped
+    /// as `mailto:` OSC 8 hyperlinks.
     pub fn email_highlighter(style: Style) -> Self {
         let mut hl = RegexHighlighter::new();
-        let _ = hl.add_pattern(
-            r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b",
-            style,
-        );
+        let regex = Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b").unwrap();
+        hl.patterns.push((regex, style, LinkKind::MailTo));
         hl
     }
 }
@@ -78,11 +118,11 @@ impl Highlighter for RegexHighlighter {
         }
 
         // Find all matches across all patterns
-        let mut matches: Vec<(usize, usize, Style)> = Vec::new();
+        let mut matches: Vec<(usize, usize, Style, LinkKind)> = Vec::new();
 
-        for (regex, style) in &self.patterns {
+        for (regex, style, link_kind) in &self.patterns {
             for m in regex.find_iter(text) {
-                matches.push((m.start(), m.end(), *style));
+                matches.push((m.start(), m.end(), *style, *link_kind));
             }
         }
 
@@ -93,7 +133,7 @@ impl Highlighter for RegexHighlighter {
         let mut spans = Vec::new();
         let mut last_end = 0;
 
-        for (start, end, style) in matches {
+        for (start, end, style, link_kind) in matches {
             // Skip if this match overlaps with previous
             if start < last_end {
                 continue;
@@ -104,8 +144,12 @@ impl Highlighter for RegexHighlighter {
                 spans.push(Span::raw(text[last_end..start].to_string()));
             }
 
-            // Add styled match
-            spans.push(Span::styled(text[start..end].to_string(), style));
+            // Add styled match, with a hyperlink target if this pattern
+            // derives one from the matched text.
+            let matched = &text[start..end];
+            let mut span = Span::styled(matched.to_string(), style);
+            span.link = link_kind.resolve(matched);
+            spans.push(span);
             last_end = end;
         }
 
@@ -122,12 +166,211 @@ impl Highlighter for RegexHighlighter {
     }
 }
 
+/// Auto-highlights structured tokens the way Python Rich's default
+/// `ReprHighlighter` colors `repr()`-style debug output: numbers, quoted
+/// strings, booleans/`None`, URLs, filesystem paths, UUIDs, and IPv4
+/// addresses, each styled by looking up the matching `repr.*` name in a
+/// [`Theme`] (`repr.number`, `repr.str`, `repr.bool_true`, `repr.bool_false`,
+/// `repr.none`, `repr.url`, `repr.path`, `repr.uuid`, `repr.ipv4`), so a
+/// custom theme can recolor them without touching this module.
+///
+/// Patterns are checked most-specific-first (UUID and IPv4 before the
+/// generic number pattern, quoted strings before bare words) since
+/// [`RegexHighlighter`] resolves overlaps by earliest match start, and a
+/// more specific pattern starting at the same position would otherwise lose
+/// to whichever pattern happens to be registered first.
+#[derive(Debug, Clone)]
+pub struct ReprHighlighter {
+    inner: RegexHighlighter,
+}
+
+impl ReprHighlighter {
+    /// Build a highlighter resolving its styles against `theme`.
+    pub fn new(theme: &Theme) -> Self {
+        let mut inner = RegexHighlighter::new();
+
+        let _ = inner.add_pattern(
+            r#""[^"]*"|'[^']*'"#,
+            theme.get_style("repr.str"),
+        );
+        let _ = inner.add_pattern(
+            r"\b[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}\b",
+            theme.get_style("repr.uuid"),
+        );
+        let _ = inner.add_pattern(
+            r"\b(?:\d{1,3}\.){3}\d{1,3}\b",
+            theme.get_style("repr.ipv4"),
+        );
+        let _ = inner.add_link_pattern(r"https?://\S+", theme.get_style("repr.url"));
+        let _ = inner.add_pattern(r"(?:/[\w.-]+)+", theme.get_style("repr.path"));
+        let _ = inner.add_pattern(r"\btrue\b|\bTrue\b", theme.get_style("repr.bool_true"));
+        let _ = inner.add_pattern(r"\bfalse\b|\bFalse\b", theme.get_style("repr.bool_false"));
+        let _ = inner.add_pattern(r"\bNone\b|\bnull\b", theme.get_style("repr.none"));
+        let _ = inner.add_pattern(r"\b\d[\d_]*\.?\d*\b", theme.get_style("repr.number"));
+
+        ReprHighlighter { inner }
+    }
+}
+
+impl Default for ReprHighlighter {
+    fn default() -> Self {
+        ReprHighlighter::new(&Theme::default_theme())
+    }
+}
+
+impl Highlighter for ReprHighlighter {
+    fn highlight(&self, text: &str) -> Vec<Span> {
+        self.inner.highlight(text)
+    }
+}
+
 /// Apply a highlighter to text and return a styled Text object.
 pub fn highlight_text(text: &str, highlighter: &impl Highlighter) -> Text {
     let spans = highlighter.highlight(text);
     Text::from_spans(spans)
 }
 
+/// A [`Highlighter`] backed by a real language grammar (via `syntect`)
+/// instead of regexes, so e.g. a Rust/JSON/Python snippet embedded in a
+/// [`crate::panel::Panel`] or [`crate::group::RenderGroup`] gets proper
+/// syntax colors rather than pattern-matched ones.
+///
+/// Bundled syntaxes and themes are compiled once ahead of time with
+/// `syntect::dumps::dump_to_file` (the same approach `hgrep` uses) and
+/// embedded via `include_bytes!`, so highlighting a snippet has no
+/// runtime dependency on loose `.sublime-syntax`/`.tmTheme` files.
+#[cfg(feature = "syntax")]
+#[derive(Clone)]
+pub struct SyntaxHighlighter {
+    language: String,
+    theme: syntect::highlighting::Theme,
+}
+
+#[cfg(feature = "syntax")]
+impl SyntaxHighlighter {
+    fn syntax_set() -> &'static syntect::parsing::SyntaxSet {
+        static SYNTAX_SET: std::sync::OnceLock<syntect::parsing::SyntaxSet> =
+            std::sync::OnceLock::new();
+        SYNTAX_SET.get_or_init(|| {
+            syntect::dumps::from_uncompressed_data(include_bytes!(
+                "../assets/syntect/syntaxes.bin"
+            ))
+            .expect("bundled syntax set should deserialize")
+        })
+    }
+
+    fn theme_set() -> &'static syntect::highlighting::ThemeSet {
+        static THEME_SET: std::sync::OnceLock<syntect::highlighting::ThemeSet> =
+            std::sync::OnceLock::new();
+        THEME_SET.get_or_init(|| {
+            syntect::dumps::from_uncompressed_data(include_bytes!("../assets/syntect/themes.bin"))
+                .expect("bundled theme set should deserialize")
+        })
+    }
+
+    /// Highlight for `language`, matched by syntax token name or file
+    /// extension (e.g. `"rust"`, `"rs"`, `"json"`). Falls back to plain
+    /// text (no grammar, so `highlight` just returns unstyled spans) if
+    /// the language isn't bundled.
+    pub fn for_language(language: &str) -> Self {
+        SyntaxHighlighter {
+            language: language.to_string(),
+            theme: Self::theme_set().themes["base16-ocean.dark"].clone(),
+        }
+    }
+
+    /// Select a bundled theme by name (e.g. `"base16-ocean.dark"`).
+    /// Unknown names leave the current theme in place rather than
+    /// erroring, since a missing theme shouldn't break highlighting.
+    pub fn with_theme(mut self, theme: &str) -> Self {
+        if let Some(theme) = Self::theme_set().themes.get(theme) {
+            self.theme = theme.clone();
+        }
+        self
+    }
+
+    /// Highlight for `language` using an explicit `syntect` theme instead
+    /// of one looked up from the bundled theme set -- used by
+    /// [`crate::syntax::Theme::Custom`] to apply a theme parsed from a
+    /// `.tmTheme` file.
+    pub fn with_custom_theme(language: &str, theme: syntect::highlighting::Theme) -> Self {
+        SyntaxHighlighter {
+            language: language.to_string(),
+            theme,
+        }
+    }
+
+    fn find_syntax(&self) -> &'static syntect::parsing::SyntaxReference {
+        let syntax_set = Self::syntax_set();
+        syntax_set
+            .find_syntax_by_token(&self.language)
+            .or_else(|| syntax_set.find_syntax_by_extension(&self.language))
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text())
+    }
+
+    /// Convert a syntect highlighting style (RGB foreground + font-style
+    /// flags) into this crate's `Style`.
+    fn convert_style(style: syntect::highlighting::Style) -> Style {
+        let mut converted = Style::new().foreground(Color::rgb(
+            style.foreground.r,
+            style.foreground.g,
+            style.foreground.b,
+        ));
+        if style
+            .font_style
+            .contains(syntect::highlighting::FontStyle::BOLD)
+        {
+            converted = converted.bold();
+        }
+        if style
+            .font_style
+            .contains(syntect::highlighting::FontStyle::ITALIC)
+        {
+            converted = converted.italic();
+        }
+        if style
+            .font_style
+            .contains(syntect::highlighting::FontStyle::UNDERLINE)
+        {
+            converted = converted.underline();
+        }
+        converted
+    }
+}
+
+#[cfg(feature = "syntax")]
+impl Highlighter for SyntaxHighlighter {
+    fn highlight(&self, text: &str) -> Vec<Span> {
+        use syntect::highlighting::{HighlightIterator, HighlightState, Highlighter as SyntectHighlighter};
+        use syntect::parsing::{ParseState, ScopeStack};
+        use syntect::util::LinesWithEndings;
+
+        let syntax_set = Self::syntax_set();
+        let syntax = self.find_syntax();
+        let highlighter = SyntectHighlighter::new(&self.theme);
+        let mut parse_state = ParseState::new(syntax);
+        let mut highlight_state = HighlightState::new(&highlighter, ScopeStack::new());
+
+        let mut spans = Vec::new();
+        for line in LinesWithEndings::from(text) {
+            let ops = match parse_state.parse_line(line, syntax_set) {
+                Ok(ops) => ops,
+                Err(_) => continue,
+            };
+            let iter = HighlightIterator::new(&mut highlight_state, &ops, line, &highlighter);
+            for (style, piece) in iter {
+                spans.push(Span::styled(piece.to_string(), Self::convert_style(style)));
+            }
+        }
+
+        if spans.is_empty() {
+            vec![Span::raw(text.to_string())]
+        } else {
+            spans
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +392,114 @@ mod tests {
         let spans = hl.highlight("Visit https://example.com for info");
         assert!(spans.len() > 1);
     }
+
+    #[test]
+    fn test_url_highlighter_sets_link_to_matched_url() {
+        let hl = RegexHighlighter::url_highlighter(Style::new().foreground(Color::Blue));
+        let spans = hl.highlight("Visit https://example.com for info");
+        let link_span = spans
+            .iter()
+            .find(|span| span.text.as_str() == "https://example.com")
+            .unwrap();
+        assert_eq!(link_span.link.as_deref(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn test_email_highlighter_sets_mailto_link() {
+        let hl = RegexHighlighter::email_highlighter(Style::new().foreground(Color::Cyan));
+        let spans = hl.highlight("Contact jane@example.com for help");
+        let link_span = spans
+            .iter()
+            .find(|span| span.text.as_str() == "jane@example.com")
+            .unwrap();
+        assert_eq!(
+            link_span.link.as_deref(),
+            Some("mailto:jane@example.com")
+        );
+    }
+
+    #[test]
+    fn test_plain_pattern_has_no_link() {
+        let mut hl = RegexHighlighter::new();
+        hl.add_pattern(r"\d+", Style::new().foreground(Color::Cyan))
+            .unwrap();
+        let spans = hl.highlight("Port 8080 is open");
+        let matched = spans.iter().find(|span| span.text.as_str() == "8080").unwrap();
+        assert_eq!(matched.link, None);
+    }
+
+    #[test]
+    fn test_repr_highlighter_colors_numbers_and_strings() {
+        let hl = ReprHighlighter::default();
+        let spans = hl.highlight(r#"count=42 name="Alice""#);
+
+        let number = spans.iter().find(|s| s.text.as_str() == "42").unwrap();
+        assert_eq!(number.style.foreground, Some(Color::Cyan));
+
+        let string = spans.iter().find(|s| s.text.as_str() == "\"Alice\"").unwrap();
+        assert_eq!(string.style.foreground, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_repr_highlighter_colors_bools_and_none() {
+        let hl = ReprHighlighter::default();
+        let spans = hl.highlight("ok=True broken=False value=None");
+
+        assert_eq!(
+            spans.iter().find(|s| s.text.as_str() == "True").unwrap().style.foreground,
+            Some(Color::BrightGreen)
+        );
+        assert_eq!(
+            spans.iter().find(|s| s.text.as_str() == "False").unwrap().style.foreground,
+            Some(Color::BrightRed)
+        );
+        assert_eq!(
+            spans.iter().find(|s| s.text.as_str() == "None").unwrap().style.foreground,
+            Some(Color::Magenta)
+        );
+    }
+
+    #[test]
+    fn test_repr_highlighter_detects_urls_and_uuids() {
+        let hl = ReprHighlighter::default();
+        let spans = hl.highlight("see https://example.com/docs id=123e4567-e89b-12d3-a456-426614174000");
+
+        let url = spans.iter().find(|s| s.text.starts_with("https://")).unwrap();
+        assert_eq!(url.link.as_deref(), Some(url.text.as_str()));
+
+        let uuid = spans
+            .iter()
+            .find(|s| s.text.as_str() == "123e4567-e89b-12d3-a456-426614174000")
+            .unwrap();
+        assert_eq!(uuid.style.foreground, Some(Color::BrightYellow));
+    }
+
+    #[test]
+    fn test_repr_highlighter_respects_custom_theme() {
+        let mut theme = crate::theme::Theme::new();
+        theme.add_color("repr.number", Color::BrightMagenta);
+        let hl = ReprHighlighter::new(&theme);
+
+        let spans = hl.highlight("value=7");
+        let number = spans.iter().find(|s| s.text.as_str() == "7").unwrap();
+        assert_eq!(number.style.foreground, Some(Color::BrightMagenta));
+    }
+
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn test_syntax_highlighter_colors_rust_keywords() {
+        let hl = SyntaxHighlighter::for_language("rust");
+        let spans = hl.highlight("fn main() {}");
+        assert!(!spans.is_empty());
+        assert!(spans.iter().any(|span| span.text.as_str().contains("fn")));
+    }
+
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn test_syntax_highlighter_unknown_language_falls_back_to_plain_text() {
+        let hl = SyntaxHighlighter::for_language("not-a-real-language");
+        let spans = hl.highlight("just some text");
+        let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+        assert_eq!(text, "just some text");
+    }
 }
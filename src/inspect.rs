@@ -273,7 +273,7 @@ mod tests {
         let value = vec!["hello", "world"];
         let inspection = inspect(&value);
 
-        let context = RenderContext { width: 60 };
+        let context = RenderContext { width: 60, ..Default::default() };
         let segments = inspection.render(&context);
 
         // Should produce some output
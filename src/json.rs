@@ -16,15 +16,26 @@
 
 use crate::console::RenderContext;
 use crate::highlighter::{Highlighter, JsonHighlighter};
+use crate::panel::Panel;
 use crate::renderable::{Renderable, Segment};
+use crate::style::{Color, Style};
 #[cfg(test)]
 use crate::text::Overflow;
-use crate::text::Text;
+use crate::text::{Span, Text};
+use crate::traceback::TracebackConfig;
+use regex::Regex;
 use serde::Serialize;
 use serde_json::{self, Value};
 use std::fmt;
 use std::fs;
 use std::path::Path;
+use std::sync::OnceLock;
+
+/// Input size above which [`Json::from_str`]/[`Json::from_str_with_options`]
+/// turn on [`JsonOptions::streaming`] by default, trading the regex
+/// highlighter pass for a single-pass renderer that styles tokens as
+/// they're visited.
+const STREAMING_THRESHOLD_BYTES: usize = 16 * 1024;
 
 /// Error type for JSON operations.
 #[derive(Debug)]
@@ -35,6 +46,8 @@ pub enum JsonError {
     Serialize(serde_json::Error),
     /// Failed to read file
     Io(std::io::Error),
+    /// A [`Json::pointer`]/[`Json::query`] selection matched nothing
+    Selection(String),
 }
 
 impl fmt::Display for JsonError {
@@ -43,6 +56,7 @@ impl fmt::Display for JsonError {
             JsonError::Parse(e) => write!(f, "JSON parse error: {}", e),
             JsonError::Serialize(e) => write!(f, "JSON serialize error: {}", e),
             JsonError::Io(e) => write!(f, "IO error: {}", e),
+            JsonError::Selection(msg) => write!(f, "JSON selection error: {}", msg),
         }
     }
 }
@@ -103,6 +117,27 @@ pub struct JsonOptions {
     pub ensure_ascii: bool,
     /// Disable word wrapping (default: true, matching Python rich)
     pub no_wrap: bool,
+    /// Render by walking the already-parsed [`Value`] once, styling each
+    /// token as it's visited, instead of re-serializing to a string via
+    /// `PrettyFormatter` and then scanning that string with
+    /// [`JsonHighlighter`]'s regexes (default: false, but
+    /// [`Json::from_str_with_options`] turns this on automatically for
+    /// inputs above [`STREAMING_THRESHOLD_BYTES`]).
+    pub streaming: bool,
+    /// Classify string leaves by content (date, URL, UUID, IP, email) and
+    /// style each category distinctly instead of the uniform string color
+    /// (default: false). See [`Json::enable_semantic_highlight`].
+    pub semantic_highlight: bool,
+    /// Styles used for each category recognized when `semantic_highlight`
+    /// is enabled.
+    pub value_theme: JsonValueTheme,
+    /// Collapse objects/arrays nested deeper than this to `{ … }` / `[ … ]`
+    /// (default: `None`, no limit). See [`Json::max_depth`].
+    pub max_depth: Option<usize>,
+    /// Render at most this many items per array, followed by a dimmed
+    /// `… N more items` marker (default: `None`, no limit). See
+    /// [`Json::max_array_items`].
+    pub max_array_items: Option<usize>,
 }
 
 impl Default for JsonOptions {
@@ -113,10 +148,118 @@ impl Default for JsonOptions {
             sort_keys: false,
             ensure_ascii: false,
             no_wrap: true, // Match Python rich's behavior
+            streaming: false,
+            semantic_highlight: false,
+            value_theme: JsonValueTheme::default(),
+            max_depth: None,
+            max_array_items: None,
+        }
+    }
+}
+
+/// Styles for string leaves recognized as a specific kind of value during
+/// semantic highlighting, mirroring how some JSON libraries grow "add-on"
+/// (de)serializers for `Date`/`Time`/`UUID`-like values (e.g. Ruby's
+/// `json/add/*`) -- here expressed purely as a rendering style, not a type.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonValueTheme {
+    /// ISO-8601 date or date-time strings, e.g. `"2024-03-05T12:00:00Z"`.
+    pub date: Style,
+    /// `http://`/`https://` URLs.
+    pub url: Style,
+    /// RFC 4122 UUIDs.
+    pub uuid: Style,
+    /// IPv4 addresses.
+    pub ip: Style,
+    /// Email-like strings.
+    pub email: Style,
+}
+
+impl Default for JsonValueTheme {
+    fn default() -> Self {
+        JsonValueTheme {
+            date: Style::new().foreground(Color::BrightCyan),
+            url: Style::new().foreground(Color::BrightBlue).underline(),
+            uuid: Style::new().foreground(Color::BrightYellow),
+            ip: Style::new().foreground(Color::BrightGreen),
+            email: Style::new().foreground(Color::BrightMagenta),
         }
     }
 }
 
+impl JsonValueTheme {
+    fn style_for(&self, category: SemanticCategory) -> Style {
+        match category {
+            SemanticCategory::Date => self.date,
+            SemanticCategory::Url => self.url,
+            SemanticCategory::Uuid => self.uuid,
+            SemanticCategory::Ip => self.ip,
+            SemanticCategory::Email => self.email,
+        }
+    }
+}
+
+/// A content-based classification of a JSON string leaf, recognized by
+/// [`classify_value`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SemanticCategory {
+    Date,
+    Url,
+    Uuid,
+    Ip,
+    Email,
+}
+
+/// Compiled once and reused, rather than per-string -- see
+/// [`classify_value`].
+struct SemanticPatterns {
+    date: Regex,
+    url: Regex,
+    uuid: Regex,
+    ip: Regex,
+    email: Regex,
+}
+
+impl SemanticPatterns {
+    fn get() -> &'static SemanticPatterns {
+        static PATTERNS: OnceLock<SemanticPatterns> = OnceLock::new();
+        PATTERNS.get_or_init(|| SemanticPatterns {
+            date: Regex::new(
+                r"^\d{4}-\d{2}-\d{2}([T ]\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:?\d{2})?)?$",
+            )
+            .unwrap(),
+            url: Regex::new(r"^https?://\S+$").unwrap(),
+            uuid: Regex::new(
+                r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+            )
+            .unwrap(),
+            ip: Regex::new(r"^(?:\d{1,3}\.){3}\d{1,3}$").unwrap(),
+            email: Regex::new(r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$").unwrap(),
+        })
+    }
+}
+
+/// Classify a whole string leaf's content for semantic highlighting,
+/// checked most-specific-first (a UUID is also dash-separated hex, but
+/// shouldn't be mistaken for anything else). Ordinary strings return
+/// `None` and keep the default string color.
+fn classify_value(s: &str) -> Option<SemanticCategory> {
+    let patterns = SemanticPatterns::get();
+    if patterns.uuid.is_match(s) {
+        Some(SemanticCategory::Uuid)
+    } else if patterns.ip.is_match(s) {
+        Some(SemanticCategory::Ip)
+    } else if patterns.url.is_match(s) {
+        Some(SemanticCategory::Url)
+    } else if patterns.email.is_match(s) {
+        Some(SemanticCategory::Email)
+    } else if patterns.date.is_match(s) {
+        Some(SemanticCategory::Date)
+    } else {
+        None
+    }
+}
+
 /// A renderable that pretty-prints JSON with syntax highlighting.
 ///
 /// # Example
@@ -207,7 +350,14 @@ impl Json {
     }
 
     /// Create a JSON renderable with custom options.
-    pub fn from_str_with_options(json: &str, options: JsonOptions) -> Result<Self, JsonError> {
+    ///
+    /// Inputs larger than [`STREAMING_THRESHOLD_BYTES`] turn
+    /// [`JsonOptions::streaming`] on automatically, even if `options` didn't
+    /// request it, so large documents skip the regex highlighter pass.
+    pub fn from_str_with_options(json: &str, mut options: JsonOptions) -> Result<Self, JsonError> {
+        if json.len() > STREAMING_THRESHOLD_BYTES {
+            options.streaming = true;
+        }
         let value: Value = serde_json::from_str(json).map_err(JsonError::Parse)?;
         Self::from_value(value, options)
     }
@@ -224,11 +374,21 @@ impl Json {
 
     /// Render the JSON value to styled Text.
     fn render_value(value: &Value, options: &JsonOptions) -> Text {
+        // Depth/array-item truncation leaves sentinel marker strings (see
+        // `TRUNCATED_OBJECT_MARKER` and friends) that only the single-pass
+        // renderer below knows how to render unquoted and dimmed, so route
+        // through it whenever truncation is active, not just for `streaming`.
+        if options.streaming || options.max_depth.is_some() || options.max_array_items.is_some() {
+            return Self::render_value_streaming(value, options);
+        }
+
+        let truncated = truncate_value(value, options.max_depth, options.max_array_items, 0);
+
         // Sort keys if requested
         let value_to_render = if options.sort_keys {
-            sort_json_keys(value)
+            sort_json_keys(&truncated)
         } else {
-            value.clone()
+            truncated
         };
 
         // Format JSON
@@ -251,6 +411,38 @@ impl Json {
         text
     }
 
+    /// Render `value` to styled spans in a single pass over the already
+    /// parsed tree, assigning each key/string/number/boolean/null its style
+    /// the moment it's visited, with indentation tracked by nesting depth --
+    /// no intermediate formatted string and no regex scan over it.
+    ///
+    /// This skips the `clone` + `PrettyFormatter` stringify + regex-scan
+    /// round trip that the non-streaming path takes, which is where
+    /// `render_value`'s cost concentrates on large documents. It still
+    /// walks the parsed [`Value`] rather than the raw input bytes, since
+    /// `Json`'s mutators (`sort_keys`, `indent`, ...) re-render from
+    /// `self.value`; a from-bytes streaming parser that never materializes
+    /// a `Value` at all would need those mutators to carry the original
+    /// source string instead, which is a larger change than this one.
+    fn render_value_streaming(value: &Value, options: &JsonOptions) -> Text {
+        let styles = if options.highlight {
+            JsonStyles::highlighted()
+        } else {
+            JsonStyles::plain()
+        };
+        let unit = indent_unit(&options.indent);
+
+        let truncated = truncate_value(value, options.max_depth, options.max_array_items, 0);
+        let mut spans = Vec::new();
+        emit_value(&truncated, &unit, 0, options, &styles, &mut spans);
+
+        let mut text = Text::from_spans(spans);
+        if options.no_wrap {
+            text = text.no_wrap();
+        }
+        text
+    }
+
     /// Re-render with updated options
     fn rerender(&self) -> Self {
         let text = Self::render_value(&self.value, &self.options);
@@ -340,6 +532,70 @@ impl Json {
         self.rerender()
     }
 
+    /// Force the single-pass streaming renderer on or off, overriding the
+    /// [`STREAMING_THRESHOLD_BYTES`]-based default.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str(r#"{"a": 1}"#).unwrap().streaming(true);
+    /// ```
+    pub fn streaming(mut self, enabled: bool) -> Self {
+        self.options.streaming = enabled;
+        self.rerender()
+    }
+
+    /// Classify string leaves by content (date, URL, UUID, IP, email) and
+    /// style each category with [`JsonOptions::value_theme`] instead of the
+    /// uniform string color (default: off).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str(r#"{"id": "123e4567-e89b-12d3-a456-426614174000"}"#)
+    ///     .unwrap()
+    ///     .enable_semantic_highlight(true);
+    /// ```
+    pub fn enable_semantic_highlight(mut self, enabled: bool) -> Self {
+        self.options.semantic_highlight = enabled;
+        self.rerender()
+    }
+
+    /// Collapse objects/arrays nested deeper than `n` to `{ … }` / `[ … ]`,
+    /// so a multi-megabyte document with deep nesting renders something
+    /// readable instead of a full pretty-print.
+    ///
+    /// A container's nesting depth is `0` at the top level; a container
+    /// deeper than `n` is collapsed without descending into it, so its own
+    /// contents never get parsed into the output at all.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str(r#"{"a": {"b": 1}}"#).unwrap().max_depth(0);
+    /// assert_eq!(json.plain_text(), "{\n  \"a\": { … }\n}");
+    /// ```
+    pub fn max_depth(mut self, n: usize) -> Self {
+        self.options.max_depth = Some(n);
+        self.rerender()
+    }
+
+    /// Render at most the first `n` items of any array, followed by a
+    /// dimmed `… N more items` marker.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str("[1, 2, 3, 4, 5]")
+    ///     .unwrap()
+    ///     .max_array_items(2);
+    /// assert_eq!(json.plain_text(), "[\n  1,\n  2,\n  … 3 more items\n]");
+    /// ```
+    pub fn max_array_items(mut self, n: usize) -> Self {
+        self.options.max_array_items = Some(n);
+        self.rerender()
+    }
+
     /// Enable word wrapping (by default, wrapping is disabled).
     ///
     /// # Example
@@ -356,6 +612,111 @@ impl Json {
     pub fn plain_text(&self) -> String {
         self.text.plain_text()
     }
+
+    /// Select the subtree at `pointer` (RFC 6901, e.g. `"/user/scores/0"`)
+    /// and re-render with only that value, discarding the rest of the
+    /// document -- useful for pulling one slice out of a large config or
+    /// API response instead of printing the whole thing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str(r#"{"user": {"scores": [1, 2, 3]}}"#)
+    ///     .unwrap()
+    ///     .pointer("/user/scores/0")
+    ///     .unwrap();
+    /// assert_eq!(json.plain_text(), "1");
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Result<Self, JsonError> {
+        let selected = self
+            .value
+            .pointer(pointer)
+            .ok_or_else(|| JsonError::Selection(format!("no value at pointer {pointer:?}")))?
+            .clone();
+        Self::from_value(selected, self.options.clone())
+    }
+
+    /// Select the subtree(s) matching a minimal JSONPath subset --
+    /// `$`, dotted keys (`.user`), `[n]` index, and `[*]` wildcard -- and
+    /// re-render with just the match, or, when the path contains a `[*]`
+    /// wildcard, a JSON array of every match.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// let json = Json::from_str(r#"{"user": {"scores": [1, 2, 3]}}"#)
+    ///     .unwrap()
+    ///     .query("$.user.scores[*]")
+    ///     .unwrap()
+    ///     .compact();
+    /// assert_eq!(json.plain_text(), "[1,2,3]");
+    /// ```
+    pub fn query(&self, path: &str) -> Result<Self, JsonError> {
+        let selected = query_value(&self.value, path)
+            .ok_or_else(|| JsonError::Selection(format!("no match for query {path:?}")))?;
+        Self::from_value(selected, self.options.clone())
+    }
+
+    /// Build a rich diagnostic for a failed parse: the offending line (plus
+    /// `config.context_lines` of surrounding context), a caret under the
+    /// reported column, and the error as the panel title -- reusing
+    /// [`TracebackConfig`]/[`Panel`] instead of surfacing the opaque
+    /// [`JsonError::Parse`] on its own.
+    ///
+    /// Returns `None` for [`JsonError::Serialize`]/[`JsonError::Io`], which
+    /// have no line/column to point at.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use fast_rich::json::Json;
+    /// use fast_rich::traceback::TracebackConfig;
+    ///
+    /// let source = r#"{"a": 1,}"#;
+    /// if let Err(err) = Json::from_str(source) {
+    ///     if let Some(panel) = Json::render_parse_error(source, &err, &TracebackConfig::default()) {
+    ///         // console.print_renderable(&panel);
+    ///         let _ = panel;
+    ///     }
+    /// }
+    /// ```
+    pub fn render_parse_error(
+        source: &str,
+        error: &JsonError,
+        config: &TracebackConfig,
+    ) -> Option<Panel> {
+        let JsonError::Parse(parse_error) = error else {
+            return None;
+        };
+
+        let lines: Vec<&str> = source.split('\n').collect();
+        let error_line = parse_error.line().max(1);
+        let column = parse_error.column().max(1);
+
+        let lo = error_line.saturating_sub(config.context_lines).max(1);
+        let hi = (error_line + config.context_lines).min(lines.len().max(1));
+
+        let mut body = String::new();
+        for line_no in lo..=hi {
+            let Some(line_text) = lines.get(line_no - 1) else {
+                continue;
+            };
+            body.push_str(line_text);
+            body.push('\n');
+            if line_no == error_line {
+                body.push_str(&" ".repeat(column - 1));
+                body.push('^');
+                body.push('\n');
+            }
+        }
+        body.pop(); // drop the trailing newline
+
+        Some(
+            Panel::new(body)
+                .title(&parse_error.to_string())
+                .border_style(config.border_style)
+                .style(Style::new().foreground(Color::Red)),
+        )
+    }
 }
 
 impl Renderable for Json {
@@ -430,6 +791,362 @@ fn escape_non_ascii(s: &str) -> String {
     result
 }
 
+/// Styles applied to each token by [`Json::render_value_streaming`],
+/// matching the `repr.*` palette used elsewhere in the crate so streaming
+/// output looks the same as the regex-highlighted path.
+struct JsonStyles {
+    key: Style,
+    string: Style,
+    number: Style,
+    bool_true: Style,
+    bool_false: Style,
+    null: Style,
+}
+
+impl JsonStyles {
+    fn highlighted() -> Self {
+        JsonStyles {
+            key: Style::new().foreground(Color::Blue).bold(),
+            string: Style::new().foreground(Color::Green),
+            number: Style::new().foreground(Color::Cyan),
+            bool_true: Style::new().foreground(Color::BrightGreen),
+            bool_false: Style::new().foreground(Color::BrightRed),
+            null: Style::new().foreground(Color::Magenta),
+        }
+    }
+
+    fn plain() -> Self {
+        JsonStyles {
+            key: Style::new(),
+            string: Style::new(),
+            number: Style::new(),
+            bool_true: Style::new(),
+            bool_false: Style::new(),
+            null: Style::new(),
+        }
+    }
+}
+
+/// The string inserted once per nesting level between a newline and the
+/// next token, or `None` for [`JsonIndent::Compact`] (no newlines at all).
+fn indent_unit(indent: &JsonIndent) -> Option<String> {
+    match indent {
+        JsonIndent::Compact => None,
+        JsonIndent::Spaces(n) => Some(" ".repeat(*n)),
+        JsonIndent::Custom(s) => Some(s.clone()),
+    }
+}
+
+fn push_plain(spans: &mut Vec<Span>, text: impl Into<String>) {
+    spans.push(Span::raw(text.into()));
+}
+
+/// Emit a newline plus `depth` copies of `unit`, or nothing when `unit` is
+/// `None` (compact mode).
+fn push_indent(spans: &mut Vec<Span>, unit: &Option<String>, depth: usize) {
+    if let Some(unit) = unit {
+        spans.push(Span::raw(format!("\n{}", unit.repeat(depth))));
+    }
+}
+
+/// Quote and escape `s` as a JSON string literal, reusing serde_json's
+/// escaper. Note this re-escapes from the parsed `str`, rather than
+/// preserving the original source slice's escapes verbatim -- `Json`
+/// already discards the source text once parsed into a `Value`.
+fn json_quoted(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("{s:?}"))
+}
+
+fn emit_string(s: &str, style: Style, options: &JsonOptions, spans: &mut Vec<Span>) {
+    let quoted = json_quoted(s);
+    let quoted = if options.ensure_ascii {
+        escape_non_ascii(&quoted)
+    } else {
+        quoted
+    };
+    spans.push(Span::styled(quoted, style));
+}
+
+fn emit_value(
+    value: &Value,
+    unit: &Option<String>,
+    depth: usize,
+    options: &JsonOptions,
+    styles: &JsonStyles,
+    spans: &mut Vec<Span>,
+) {
+    match value {
+        Value::Null => spans.push(Span::styled("null".to_string(), styles.null)),
+        Value::Bool(true) => spans.push(Span::styled("true".to_string(), styles.bool_true)),
+        Value::Bool(false) => spans.push(Span::styled("false".to_string(), styles.bool_false)),
+        Value::Number(n) => spans.push(Span::styled(n.to_string(), styles.number)),
+        Value::String(s) if s == TRUNCATED_OBJECT_MARKER => {
+            push_plain(spans, "{ \u{2026} }");
+        }
+        Value::String(s) if s == TRUNCATED_ARRAY_MARKER => {
+            push_plain(spans, "[ \u{2026} ]");
+        }
+        Value::String(s) if s.starts_with(MORE_ITEMS_MARKER_PREFIX) => {
+            let count = &s[MORE_ITEMS_MARKER_PREFIX.len()..];
+            spans.push(Span::styled(
+                format!("\u{2026} {count} more items"),
+                Style::new().dim(),
+            ));
+        }
+        Value::String(s) => {
+            let style = if options.semantic_highlight {
+                classify_value(s)
+                    .map(|category| options.value_theme.style_for(category))
+                    .unwrap_or(styles.string)
+            } else {
+                styles.string
+            };
+            emit_string(s, style, options, spans)
+        }
+        Value::Array(items) => emit_array(items, unit, depth, options, styles, spans),
+        Value::Object(map) => emit_object(map, unit, depth, options, styles, spans),
+    }
+}
+
+fn emit_array(
+    items: &[Value],
+    unit: &Option<String>,
+    depth: usize,
+    options: &JsonOptions,
+    styles: &JsonStyles,
+    spans: &mut Vec<Span>,
+) {
+    if items.is_empty() {
+        push_plain(spans, "[]");
+        return;
+    }
+
+    push_plain(spans, "[");
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            push_plain(spans, ",");
+        }
+        push_indent(spans, unit, depth + 1);
+        emit_value(item, unit, depth + 1, options, styles, spans);
+    }
+    push_indent(spans, unit, depth);
+    push_plain(spans, "]");
+}
+
+fn emit_object(
+    map: &serde_json::Map<String, Value>,
+    unit: &Option<String>,
+    depth: usize,
+    options: &JsonOptions,
+    styles: &JsonStyles,
+    spans: &mut Vec<Span>,
+) {
+    if map.is_empty() {
+        push_plain(spans, "{}");
+        return;
+    }
+
+    let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+    if options.sort_keys {
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+    }
+
+    push_plain(spans, "{");
+    for (i, (key, val)) in entries.into_iter().enumerate() {
+        if i > 0 {
+            push_plain(spans, ",");
+        }
+        push_indent(spans, unit, depth + 1);
+        emit_string(key, styles.key, options, spans);
+        push_plain(spans, if unit.is_some() { ": " } else { ":" });
+        emit_value(val, unit, depth + 1, options, styles, spans);
+    }
+    push_indent(spans, unit, depth);
+    push_plain(spans, "}");
+}
+
+/// One step of a parsed JSONPath query.
+enum PathSegment {
+    /// `.key`
+    Key(String),
+    /// `[n]`
+    Index(usize),
+    /// `[*]`
+    Wildcard,
+}
+
+/// Parse a minimal JSONPath subset: a leading `$`, then any number of
+/// `.key`, `[n]`, or `[*]` steps. Returns `None` on anything else (no
+/// recursive descent `..`, no filter expressions, no slices).
+fn parse_query(path: &str) -> Option<Vec<PathSegment>> {
+    let mut chars = path.chars().peekable();
+    if chars.next() != Some('$') {
+        return None;
+    }
+
+    let mut segments = Vec::new();
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                let mut key = String::new();
+                while let Some(&c2) = chars.peek() {
+                    if c2 == '.' || c2 == '[' {
+                        break;
+                    }
+                    key.push(c2);
+                    chars.next();
+                }
+                if key.is_empty() {
+                    return None;
+                }
+                segments.push(PathSegment::Key(key));
+            }
+            '[' => {
+                chars.next();
+                let mut inner = String::new();
+                while let Some(&c2) = chars.peek() {
+                    if c2 == ']' {
+                        break;
+                    }
+                    inner.push(c2);
+                    chars.next();
+                }
+                if chars.next() != Some(']') {
+                    return None;
+                }
+                if inner == "*" {
+                    segments.push(PathSegment::Wildcard);
+                } else {
+                    segments.push(PathSegment::Index(inner.parse().ok()?));
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(segments)
+}
+
+/// Apply `segments` to `values`, expanding each `Wildcard` step into every
+/// element of an array or value of an object.
+fn apply_segments(values: Vec<Value>, segments: &[PathSegment]) -> Vec<Value> {
+    let Some((first, rest)) = segments.split_first() else {
+        return values;
+    };
+
+    let mut next = Vec::new();
+    for value in &values {
+        match first {
+            PathSegment::Key(key) => {
+                if let Some(v) = value.get(key) {
+                    next.push(v.clone());
+                }
+            }
+            PathSegment::Index(idx) => {
+                if let Some(v) = value.get(idx) {
+                    next.push(v.clone());
+                }
+            }
+            PathSegment::Wildcard => match value {
+                Value::Array(items) => next.extend(items.iter().cloned()),
+                Value::Object(map) => next.extend(map.values().cloned()),
+                _ => {}
+            },
+        }
+    }
+
+    apply_segments(next, rest)
+}
+
+/// Run a minimal JSONPath query (see [`parse_query`]) against `value`.
+/// Returns `None` for an unparseable path or a path that matches nothing;
+/// a path containing `[*]` returns a JSON array of every match, otherwise
+/// the single matched value.
+fn query_value(value: &Value, path: &str) -> Option<Value> {
+    let segments = parse_query(path)?;
+    let has_wildcard = segments
+        .iter()
+        .any(|segment| matches!(segment, PathSegment::Wildcard));
+
+    let mut results = apply_segments(vec![value.clone()], &segments);
+    if results.is_empty() {
+        return None;
+    }
+
+    if has_wildcard {
+        Some(Value::Array(results))
+    } else {
+        Some(results.remove(0))
+    }
+}
+
+/// Sentinel string left in place of a collapsed object by [`truncate_value`].
+/// Recognized and rendered unquoted (as `{ … }`) by [`emit_value`]; the
+/// non-streaming path has no equivalent special case and shows it as an
+/// ordinary quoted string.
+const TRUNCATED_OBJECT_MARKER: &str = "\u{0}__fast_rich_json_truncated_object__";
+/// Sentinel string left in place of a collapsed array by [`truncate_value`],
+/// rendered unquoted as `[ … ]` -- see [`TRUNCATED_OBJECT_MARKER`].
+const TRUNCATED_ARRAY_MARKER: &str = "\u{0}__fast_rich_json_truncated_array__";
+/// Prefix of the sentinel string appended as the last element of a
+/// truncated array by [`truncate_value`], followed by the count of omitted
+/// items; rendered dimmed as `… N more items` -- see
+/// [`TRUNCATED_OBJECT_MARKER`].
+const MORE_ITEMS_MARKER_PREFIX: &str = "\u{0}__fast_rich_json_more_items__:";
+
+/// Pre-pass over a parsed [`Value`] that collapses containers deeper than
+/// `max_depth` and caps array length at `max_array_items`, leaving sentinel
+/// marker strings (see [`TRUNCATED_OBJECT_MARKER`] and friends) in their
+/// place. Runs before `sort_keys` and formatting/highlighting so both the
+/// streaming and non-streaming render paths share one implementation.
+fn truncate_value(
+    value: &Value,
+    max_depth: Option<usize>,
+    max_array_items: Option<usize>,
+    depth: usize,
+) -> Value {
+    if max_depth.is_none() && max_array_items.is_none() {
+        return value.clone();
+    }
+
+    match value {
+        Value::Object(map) => {
+            if max_depth.is_some_and(|limit| depth > limit) && !map.is_empty() {
+                return Value::String(TRUNCATED_OBJECT_MARKER.to_string());
+            }
+            let truncated: serde_json::Map<String, Value> = map
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.clone(),
+                        truncate_value(v, max_depth, max_array_items, depth + 1),
+                    )
+                })
+                .collect();
+            Value::Object(truncated)
+        }
+        Value::Array(items) => {
+            if max_depth.is_some_and(|limit| depth > limit) && !items.is_empty() {
+                return Value::String(TRUNCATED_ARRAY_MARKER.to_string());
+            }
+            let limit = max_array_items.unwrap_or(items.len());
+            let mut truncated: Vec<Value> = items[..items.len().min(limit)]
+                .iter()
+                .map(|v| truncate_value(v, max_depth, max_array_items, depth + 1))
+                .collect();
+            if items.len() > limit {
+                truncated.push(Value::String(format!(
+                    "{MORE_ITEMS_MARKER_PREFIX}{}",
+                    items.len() - limit
+                )));
+            }
+            Value::Array(truncated)
+        }
+        _ => value.clone(),
+    }
+}
+
 /// Recursively sort JSON object keys alphabetically.
 fn sort_json_keys(value: &Value) -> Value {
     match value {
@@ -584,4 +1301,246 @@ mod tests {
         let json = Json::from_file("/nonexistent/path/to/file.json");
         assert!(json.is_err());
     }
+
+    #[test]
+    fn test_json_streaming_matches_non_streaming_plain_text() {
+        let source = r#"{"z": 1, "a": [1, 2, "three"], "flag": true, "nil": null}"#;
+        let streamed = Json::from_str(source).unwrap().streaming(true);
+        let buffered = Json::from_str(source).unwrap().streaming(false);
+
+        assert_eq!(streamed.plain_text(), buffered.plain_text());
+    }
+
+    #[test]
+    fn test_json_streaming_honors_sort_keys_and_indent() {
+        let json = Json::from_str(r#"{"z": 1, "a": 2}"#)
+            .unwrap()
+            .streaming(true)
+            .sort_keys()
+            .indent(4);
+        let text = json.plain_text();
+
+        let a_pos = text.find("\"a\"").unwrap();
+        let z_pos = text.find("\"z\"").unwrap();
+        assert!(a_pos < z_pos);
+        assert!(text.contains("    \"a\""));
+    }
+
+    #[test]
+    fn test_json_streaming_compact_has_no_whitespace() {
+        let json = Json::from_str(r#"{"a": 1, "b": 2}"#)
+            .unwrap()
+            .streaming(true)
+            .compact();
+
+        assert_eq!(json.plain_text(), r#"{"a":1,"b":2}"#);
+    }
+
+    #[test]
+    fn test_json_from_str_auto_enables_streaming_for_large_input() {
+        let big_array = (0..5000).collect::<Vec<u32>>();
+        let source = serde_json::to_string(&big_array).unwrap();
+        assert!(source.len() > STREAMING_THRESHOLD_BYTES);
+
+        let json = Json::from_str(&source).unwrap();
+        assert!(json.options.streaming);
+    }
+
+    #[test]
+    fn test_render_parse_error_points_at_the_bad_column() {
+        let source = "{\n  \"a\": 1,\n}";
+        let err = Json::from_str(source).unwrap_err();
+
+        let panel = Json::render_parse_error(source, &err, &TracebackConfig::default());
+        assert!(panel.is_some());
+    }
+
+    #[test]
+    fn test_render_parse_error_returns_none_for_non_parse_errors() {
+        let io_err = JsonError::Io(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+        let panel = Json::render_parse_error("irrelevant", &io_err, &TracebackConfig::default());
+        assert!(panel.is_none());
+    }
+
+    #[test]
+    fn test_pointer_selects_nested_subtree() {
+        let json = Json::from_str(r#"{"user": {"scores": [10, 20, 30]}}"#).unwrap();
+        let selected = json.pointer("/user/scores/1").unwrap();
+        assert_eq!(selected.plain_text(), "20");
+    }
+
+    #[test]
+    fn test_pointer_reports_selection_error_on_miss() {
+        let json = Json::from_str(r#"{"a": 1}"#).unwrap();
+        let err = json.pointer("/missing").unwrap_err();
+        assert!(matches!(err, JsonError::Selection(_)));
+    }
+
+    #[test]
+    fn test_query_dotted_key_and_index() {
+        let json = Json::from_str(r#"{"user": {"scores": [10, 20, 30]}}"#).unwrap();
+        let selected = json.query("$.user.scores[1]").unwrap();
+        assert_eq!(selected.plain_text(), "20");
+    }
+
+    #[test]
+    fn test_query_wildcard_collects_all_matches() {
+        let json = Json::from_str(r#"{"user": {"scores": [10, 20, 30]}}"#).unwrap();
+        let selected = json.query("$.user.scores[*]").unwrap().compact();
+        assert_eq!(selected.plain_text(), "[10,20,30]");
+    }
+
+    #[test]
+    fn test_query_reports_selection_error_on_miss() {
+        let json = Json::from_str(r#"{"a": 1}"#).unwrap();
+        let err = json.query("$.nope").unwrap_err();
+        assert!(matches!(err, JsonError::Selection(_)));
+    }
+
+    #[test]
+    fn test_json_streaming_escapes_and_quotes_strings() {
+        let json = Json::from_str(r#"{"greeting": "say \"hi\"\nagain"}"#)
+            .unwrap()
+            .streaming(true);
+
+        assert_eq!(
+            json.plain_text(),
+            "{\n  \"greeting\": \"say \\\"hi\\\"\\nagain\"\n}"
+        );
+    }
+
+    #[test]
+    fn test_classify_value_recognizes_each_category() {
+        assert_eq!(
+            classify_value("2024-03-05T12:00:00Z"),
+            Some(SemanticCategory::Date)
+        );
+        assert_eq!(
+            classify_value("https://example.com/path"),
+            Some(SemanticCategory::Url)
+        );
+        assert_eq!(
+            classify_value("123e4567-e89b-12d3-a456-426614174000"),
+            Some(SemanticCategory::Uuid)
+        );
+        assert_eq!(classify_value("192.168.0.1"), Some(SemanticCategory::Ip));
+        assert_eq!(
+            classify_value("user@example.com"),
+            Some(SemanticCategory::Email)
+        );
+        assert_eq!(classify_value("just a string"), None);
+    }
+
+    fn span_style_for(json: &Json, needle: &str) -> Style {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        json.render(&context)
+            .into_iter()
+            .flat_map(|segment| segment.spans)
+            .find(|span| span.text.contains(needle))
+            .map(|span| span.style)
+            .unwrap_or_else(|| panic!("no span found containing {needle:?}"))
+    }
+
+    #[test]
+    fn test_semantic_highlight_styles_uuid_values() {
+        let json = Json::from_str(r#"{"id": "123e4567-e89b-12d3-a456-426614174000"}"#)
+            .unwrap()
+            .streaming(true)
+            .enable_semantic_highlight(true);
+
+        let style = span_style_for(&json, "123e4567");
+        assert_eq!(style, JsonValueTheme::default().uuid);
+    }
+
+    #[test]
+    fn test_semantic_highlight_styles_urls_and_emails_distinctly() {
+        let json = Json::from_str(r#"{"site": "https://example.com", "contact": "a@b.com"}"#)
+            .unwrap()
+            .streaming(true)
+            .enable_semantic_highlight(true);
+
+        assert_eq!(
+            span_style_for(&json, "https://example.com"),
+            JsonValueTheme::default().url
+        );
+        assert_eq!(
+            span_style_for(&json, "a@b.com"),
+            JsonValueTheme::default().email
+        );
+    }
+
+    #[test]
+    fn test_semantic_highlight_disabled_by_default() {
+        let json = Json::from_str(r#"{"id": "123e4567-e89b-12d3-a456-426614174000"}"#)
+            .unwrap()
+            .streaming(true);
+
+        assert_eq!(json.options.semantic_highlight, false);
+        let style = span_style_for(&json, "123e4567");
+        assert_eq!(style, JsonStyles::highlighted().string);
+    }
+
+    #[test]
+    fn test_semantic_highlight_leaves_ordinary_strings_at_default_style() {
+        let json = Json::from_str(r#"{"note": "just a plain string"}"#)
+            .unwrap()
+            .streaming(true)
+            .enable_semantic_highlight(true);
+
+        let style = span_style_for(&json, "just a plain string");
+        assert_eq!(style, JsonStyles::highlighted().string);
+    }
+
+    #[test]
+    fn test_max_depth_collapses_nested_objects_and_arrays() {
+        let json = Json::from_str(r#"{"a": {"b": 1}, "c": [1, 2]}"#)
+            .unwrap()
+            .max_depth(0);
+
+        assert_eq!(
+            json.plain_text(),
+            "{\n  \"a\": { \u{2026} },\n  \"c\": [ \u{2026} ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_max_depth_does_not_collapse_within_the_limit() {
+        let json = Json::from_str(r#"{"a": {"b": 1}}"#).unwrap().max_depth(1);
+        assert_eq!(json.plain_text(), "{\n  \"a\": {\n    \"b\": 1\n  }\n}");
+    }
+
+    #[test]
+    fn test_max_array_items_truncates_with_more_items_marker() {
+        let json = Json::from_str("[1, 2, 3, 4, 5]")
+            .unwrap()
+            .max_array_items(2);
+
+        assert_eq!(
+            json.plain_text(),
+            "[\n  1,\n  2,\n  \u{2026} 3 more items\n]"
+        );
+    }
+
+    #[test]
+    fn test_max_array_items_leaves_shorter_arrays_untouched() {
+        let json = Json::from_str("[1, 2]").unwrap().max_array_items(5);
+        assert_eq!(json.plain_text(), "[\n  1,\n  2\n]");
+    }
+
+    #[test]
+    fn test_max_depth_and_max_array_items_compose_with_sort_keys() {
+        let json = Json::from_str(r#"{"z": [1, 2, 3], "a": 1}"#)
+            .unwrap()
+            .max_array_items(1)
+            .sort_keys();
+
+        assert_eq!(
+            json.plain_text(),
+            "{\n  \"a\": 1,\n  \"z\": [\n    1,\n    \u{2026} 2 more items\n  ]\n}"
+        );
+    }
 }
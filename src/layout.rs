@@ -1,7 +1,60 @@
 use crate::console::RenderContext;
 use crate::renderable::{Renderable, Segment};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// How a layout's share of its parent's split axis is determined, mirroring
+/// the constraint model of mainstream TUI layout engines (e.g. ratatui).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// An exact number of cells, clamped to whatever space remains after
+    /// earlier siblings (in child order) have taken theirs.
+    Length(u16),
+    /// A percentage of the total available space (`total * p / 100`).
+    Percentage(u16),
+    /// A fraction `n / d` of the total available space.
+    Ratio(u32, u32),
+    /// An even share of the leftover space (after `Length`/`Percentage`/
+    /// `Ratio` siblings are resolved), floored to at least this many cells.
+    Min(u16),
+    /// An even share of the leftover space, capped to at most this many
+    /// cells.
+    Max(u16),
+    /// Distributes all leftover space proportionally to weight `w` among
+    /// the other `Min`/`Max`/`Fill` siblings.
+    Fill(u32),
+}
+
+impl Default for Constraint {
+    fn default() -> Self {
+        Constraint::Fill(1)
+    }
+}
+
+/// Blank space reserved around a [`Layout`]'s content, inset on all four
+/// sides before its children (or leaf renderable) are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Margin {
+    /// Blank lines reserved above and below the content.
+    pub vertical: u16,
+    /// Blank columns reserved to the left and right of the content.
+    pub horizontal: u16,
+}
+
+/// An absolute rectangular region on screen, in character cells, as
+/// produced by [`Layout::split`] -- unlike [`Renderable::render`]'s flat
+/// `Vec<Segment>`, this keeps the `x`/`y` offset a child landed at, so
+/// callers can do mouse hit-testing, draw overlays, or place the cursor
+/// without re-deriving geometry from scratch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
 /// A node in the layout tree for creating splits and grids.
 #[derive(Clone)]
 pub struct Layout {
@@ -11,24 +64,40 @@ pub struct Layout {
     children: Vec<Layout>,
     /// Split direction.
     direction: Direction,
-    /// Fixed size (width or height depending on parent direction).
-    size: Option<u16>,
-    /// Ratio for flexible sizing.
-    ratio: u32,
+    /// How this layout's share of the parent's split axis is resolved.
+    constraint: Constraint,
+    /// Blank space reserved around the content before splitting/rendering.
+    margin: Margin,
     /// Name for debugging.
     name: Option<String>,
-    /// Minimum size.
-    minimum_size: u16,
     /// Is this layout visible?
     visible: bool,
+    /// The `total_size` last passed to `calculate_splits`, i.e. this
+    /// layout's last known share of its parent's split axis. Used as the
+    /// percentage basis by [`Layout::resize_child`].
+    last_total_size: std::cell::Cell<u16>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     Horizontal,
     Vertical,
 }
 
+/// Key for [`LAYOUT_CACHE`]: the available space, each child's constraint
+/// (in order), and the split direction -- everything `calculate_splits`'s
+/// result actually depends on.
+type LayoutCacheKey = (u16, Vec<Constraint>, Direction);
+
+thread_local! {
+    /// Memoizes [`Layout::calculate_splits`] per split, so deeply nested
+    /// trees re-rendered every tick with unchanged constraints and space
+    /// don't re-solve the same split on every frame. Cleared wholesale by
+    /// [`Layout::reset_cache`] rather than invalidated per-key, since a
+    /// layout's constraints changing is rare and a full clear is cheap.
+    static LAYOUT_CACHE: RefCell<HashMap<LayoutCacheKey, Vec<u16>>> = RefCell::new(HashMap::new());
+}
+
 impl Layout {
     /// Create a new empty layout.
     pub fn new() -> Self {
@@ -36,11 +105,11 @@ impl Layout {
             renderable: None,
             children: Vec::new(),
             direction: Direction::Vertical,
-            size: None,
-            ratio: 1,
+            constraint: Constraint::default(),
+            margin: Margin::default(),
             name: None,
-            minimum_size: 0,
             visible: true,
+            last_total_size: std::cell::Cell::new(0),
         }
     }
 
@@ -50,22 +119,95 @@ impl Layout {
         self
     }
 
-    /// Set a fixed size for this layout.
-    pub fn with_size(mut self, size: u16) -> Self {
-        self.size = Some(size);
+    /// Set how this layout's share of the parent's split axis is resolved.
+    pub fn with_constraint(mut self, constraint: Constraint) -> Self {
+        self.constraint = constraint;
         self
     }
 
-    /// Set a ratio for this layout (default is 1).
-    pub fn with_ratio(mut self, ratio: u32) -> Self {
-        self.ratio = ratio;
+    /// Reserve `vertical` blank lines above/below and `horizontal` blank
+    /// columns left/right of this layout's content, insetting it before
+    /// splitting among children (or rendering a leaf renderable).
+    pub fn with_margin(mut self, vertical: u16, horizontal: u16) -> Self {
+        self.margin = Margin { vertical, horizontal };
         self
     }
 
-    /// Set a minimum size for this layout.
-    pub fn with_minimum_size(mut self, size: u16) -> Self {
-        self.minimum_size = size;
-        self
+    /// Resize the boundary between child `index` and its neighbor (the
+    /// next child, or the previous one if `index` is last) by `delta`
+    /// cells -- growing `index`'s share and shrinking the neighbor's by
+    /// the same amount (or the reverse, for negative `delta`), clamped so
+    /// neither drops below its own `Min` floor and their combined cell
+    /// budget is conserved exactly.
+    ///
+    /// Both resized children are rewritten as `Constraint::Percentage` of
+    /// this layout's last known total size (see `last_total_size`,
+    /// recorded by `calculate_splits`), rather than absolute cells, so the
+    /// split survives a later terminal resize instead of staying pinned
+    /// to whatever cell counts it had when dragged -- the parametric
+    /// resize approach zellij uses to avoid rounding drift across nested
+    /// layouts. The pair's target percentage is re-derived from their
+    /// combined cells on every call (not accumulated from the previous
+    /// percentages), with any leftover point from independently rounding
+    /// each side handed to whichever has the larger fractional remainder
+    /// -- so repeated small resizes don't drift off by rounding error.
+    pub fn resize_child(&mut self, index: usize, delta: i16) {
+        let count = self.children.len();
+        if count < 2 || index >= count {
+            return;
+        }
+        let neighbor = if index + 1 < count { index + 1 } else { index - 1 };
+
+        let total = self.last_total_size.get().max(1);
+        let sizes = self.calculate_splits(total);
+        let combined = sizes[index] as i32 + sizes[neighbor] as i32;
+
+        let min_floor = |constraint: Constraint| -> i32 {
+            match constraint {
+                Constraint::Min(m) => m as i32,
+                _ => 0,
+            }
+        };
+        let index_floor = min_floor(self.children[index].constraint);
+        let neighbor_floor = min_floor(self.children[neighbor].constraint);
+
+        // Dragging the boundary right of `index` grows it; if `index` is
+        // the later child, that same boundary is to its left, so the
+        // sign flips.
+        let signed_delta = if index < neighbor { delta as i32 } else { -(delta as i32) };
+        // If both floors together exceed what's actually available, favor
+        // `index`'s floor rather than panicking on an inverted clamp range.
+        let index_ceiling = (combined - neighbor_floor).max(index_floor);
+        let new_index = (sizes[index] as i32 + signed_delta).clamp(index_floor, index_ceiling);
+        let new_neighbor = combined - new_index;
+
+        let exact_index = new_index as f64 * 100.0 / total as f64;
+        let exact_neighbor = new_neighbor as f64 * 100.0 / total as f64;
+        let mut index_pct = exact_index.floor() as u16;
+        let mut neighbor_pct = exact_neighbor.floor() as u16;
+
+        let target_pct = (combined as f64 * 100.0 / total as f64).round().clamp(0.0, 100.0) as u16;
+        let leftover = target_pct.saturating_sub(index_pct + neighbor_pct);
+        let index_rem = exact_index - index_pct as f64;
+        let neighbor_rem = exact_neighbor - neighbor_pct as f64;
+        if leftover >= 1 {
+            if index_rem >= neighbor_rem {
+                index_pct += 1;
+            } else {
+                neighbor_pct += 1;
+            }
+        }
+        if leftover >= 2 {
+            // Both independent roundings fell short by a full point.
+            if index_rem >= neighbor_rem {
+                neighbor_pct += 1;
+            } else {
+                index_pct += 1;
+            }
+        }
+
+        self.children[index].constraint = Constraint::Percentage(index_pct);
+        self.children[neighbor].constraint = Constraint::Percentage(neighbor_pct);
     }
 
     /// Set the renderable content.
@@ -90,76 +232,212 @@ impl Layout {
         self.children = layouts;
     }
 
-    /// Calculate split sizes for a given total space.
+    /// Append a single child to an existing split, keeping the current
+    /// direction (and any already-attached children) intact. Useful for
+    /// building a split up incrementally rather than handing the whole
+    /// child list to `split_row`/`split_column` at once.
+    pub fn add_split(&mut self, layout: Layout) {
+        self.children.push(layout);
+    }
+
+    /// Find the sub-layout named `name` anywhere in this layout's tree
+    /// (depth-first, including `self`), so a previously built layout can
+    /// be located again and updated live without keeping a separate handle
+    /// to every leaf.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Layout> {
+        if self.name.as_deref() == Some(name) {
+            return Some(self);
+        }
+        for child in &mut self.children {
+            if let Some(found) = child.get_mut(name) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// The weight a `Min`/`Max`/`Fill` constraint contributes to the even
+    /// split of leftover space; `Min`/`Max` behave like `Fill(1)` until
+    /// their floor/ceiling kicks in.
+    fn fill_weight(constraint: Constraint) -> u32 {
+        match constraint {
+            Constraint::Fill(w) => w.max(1),
+            _ => 1,
+        }
+    }
+
+    /// Calculate split sizes for a given total space, consulting
+    /// [`LAYOUT_CACHE`] first so repeated renders of an unchanged split
+    /// don't re-solve it every frame.
     fn calculate_splits(&self, total_size: u16) -> Vec<u16> {
+        self.last_total_size.set(total_size);
+
+        let key: LayoutCacheKey = (
+            total_size,
+            self.children.iter().map(|c| c.constraint).collect(),
+            self.direction,
+        );
+
+        if let Some(cached) = LAYOUT_CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+            return cached;
+        }
+
+        let splits = self.calculate_splits_uncached(total_size);
+        LAYOUT_CACHE.with(|cache| cache.borrow_mut().insert(key, splits.clone()));
+        splits
+    }
+
+    /// Clear the split-size cache. Call this if a `Layout` is reused after
+    /// something outside the `(total_size, constraints, direction)` cache
+    /// key changed in a way that should invalidate stale cached splits.
+    pub fn reset_cache() {
+        LAYOUT_CACHE.with(|cache| cache.borrow_mut().clear());
+    }
+
+    /// `Length`/`Percentage`/`Ratio` siblings are STRONG constraints: they
+    /// pin their own size directly off `total_size` (clamped to whatever
+    /// hasn't already been claimed by an earlier sibling) before anything
+    /// else is considered. Whatever space is left over is then handed to
+    /// the `Min`/`Max`/`Fill` siblings, solved as a small weighted
+    /// constraint system: a WEAK equal-pressure share proportional to
+    /// weight (`Min`/`Max` behave like `Fill(1)`), relaxed against each
+    /// one's MEDIUM `>=`/`<=` bound by iterative clamp-and-redistribute
+    /// (unlike fixing one violator at a time, every bound that's violated
+    /// in a pass is clamped together, so interacting constraints settle in
+    /// the same pass rather than needing one pass per violator). The
+    /// result is discretized to integer cells with the largest-remainder
+    /// method, so the sizes always sum to exactly `total_size`.
+    fn calculate_splits_uncached(&self, total_size: u16) -> Vec<u16> {
         let count = self.children.len();
         if count == 0 {
             return Vec::new();
         }
 
-        let mut sizes = vec![0; count];
+        let mut sizes = vec![0u16; count];
         let mut remaining = total_size;
         let mut flexible_indices = Vec::new();
 
-        // 1. Assign fixed sizes
+        // 1. STRONG: resolve constraints that size themselves directly off
+        //    `total_size` (Length/Percentage/Ratio); everything else
+        //    (Min/Max/Fill) shares whatever space is left over.
         for (i, child) in self.children.iter().enumerate() {
-            if let Some(fixed) = child.size {
-                let s = std::cmp::min(fixed, remaining);
-                sizes[i] = s;
-                remaining -= s;
-            } else {
-                flexible_indices.push(i);
+            match child.constraint {
+                Constraint::Length(n) => {
+                    let s = n.min(remaining);
+                    sizes[i] = s;
+                    remaining -= s;
+                }
+                Constraint::Percentage(p) => {
+                    let s = ((total_size as u32 * p as u32) / 100) as u16;
+                    let s = s.min(remaining);
+                    sizes[i] = s;
+                    remaining -= s;
+                }
+                Constraint::Ratio(n, d) => {
+                    let s = if d == 0 {
+                        0
+                    } else {
+                        ((total_size as u64 * n as u64) / d as u64) as u16
+                    };
+                    let s = s.min(remaining);
+                    sizes[i] = s;
+                    remaining -= s;
+                }
+                Constraint::Min(_) | Constraint::Max(_) | Constraint::Fill(_) => {
+                    flexible_indices.push(i);
+                }
             }
         }
 
-        // 2. Resolve flexible sizes
-        let mut candidates = flexible_indices;
+        if flexible_indices.is_empty() {
+            return sizes;
+        }
+
+        // 2. MEDIUM/WEAK: solve the flexible group by iterative relaxation.
+        //    `free` starts as every Min/Max/Fill child sharing `remaining`
+        //    proportionally to weight (the WEAK equal-pressure pass);
+        //    `exact[i]` holds each child's current floating-point share.
+        //    Every pass, *all* children whose share violates their MEDIUM
+        //    Min/Max bound are clamped to that bound at once and removed
+        //    from `free`, then the rest re-share whatever's left -- so a
+        //    Max clamp freeing up space for others, which might then push
+        //    one of them over its own bound, gets caught on the next pass
+        //    instead of being left lopsided.
+        let budget = remaining as f64;
+        let mut free = flexible_indices.clone();
+        let mut claimed: f64 = 0.0;
+        let mut exact = vec![0.0f64; count];
 
-        while !candidates.is_empty() {
-            let total_ratio: u32 = candidates.iter().map(|&i| self.children[i].ratio).sum();
+        loop {
+            let share = budget - claimed;
+            let total_weight: f64 = free
+                .iter()
+                .map(|&i| Self::fill_weight(self.children[i].constraint) as f64)
+                .sum();
 
-            // If remaining is 0 or no ratio, fill rest with 0
-            if remaining == 0 || total_ratio == 0 {
-                for &i in &candidates {
-                    sizes[i] = 0;
+            if free.is_empty() || total_weight <= 0.0 || share <= 0.0 {
+                for &i in &free {
+                    exact[i] = 0.0;
                 }
                 break;
             }
 
-            let unit = remaining as f64 / total_ratio as f64;
+            let unit = share / total_weight;
+            for &i in &free {
+                exact[i] = Self::fill_weight(self.children[i].constraint) as f64 * unit;
+            }
 
-            // Find if any candidate needs to be fixed to min_size
-            let mut violator = None;
-            for (idx_in_candidates, &i) in candidates.iter().enumerate() {
-                let child = &self.children[i];
-                let ideal = child.ratio as f64 * unit;
-                if ideal < child.minimum_size as f64 {
-                    violator = Some(idx_in_candidates);
-                    break; // Fix one at a time
+            let mut violators = Vec::new();
+            for (pos, &i) in free.iter().enumerate() {
+                match self.children[i].constraint {
+                    Constraint::Min(m) if exact[i] < m as f64 => violators.push((pos, m as f64)),
+                    Constraint::Max(m) if exact[i] > m as f64 => violators.push((pos, m as f64)),
+                    _ => {}
                 }
             }
 
-            if let Some(idx_c) = violator {
-                let i = candidates.remove(idx_c);
-                let child = &self.children[i];
-                let s = std::cmp::min(child.minimum_size, remaining);
-                sizes[i] = s;
-                remaining -= s;
-            } else {
-                // No violators, distribute rest
-                let mut distributed = 0;
-                for (idx, &i) in candidates.iter().enumerate() {
-                    let child = &self.children[i];
-                    let s = if idx == candidates.len() - 1 {
-                        remaining - distributed
-                    } else {
-                        (child.ratio as f64 * unit).round() as u16
-                    };
-                    sizes[i] = s;
-                    distributed += s;
-                }
+            if violators.is_empty() {
                 break;
             }
+
+            for &(pos, bound) in violators.iter().rev() {
+                let i = free.remove(pos);
+                exact[i] = bound;
+                claimed += bound;
+            }
+        }
+
+        // 3. Discretize the flexible group to integer cells via the
+        //    largest-remainder method: floor every share, then hand the
+        //    leftover cells to whichever shares had the largest fractional
+        //    remainder, so the group's total lands on exactly `remaining`
+        //    even though each share was computed in floating point.
+        let mut floors = vec![0u16; count];
+        let mut remainders: Vec<(usize, f64)> = Vec::new();
+        let mut floor_total: u16 = 0;
+        for &i in &flexible_indices {
+            let floor = exact[i].floor().max(0.0);
+            floors[i] = floor as u16;
+            floor_total += floors[i];
+            remainders.push((i, exact[i] - floor));
+        }
+
+        // Ties (equal remainders) go to the later child first, so an
+        // even split like 100/3 grows its last share rather than its
+        // first -- matching the direction leftover space already flows in
+        // the final-candidate-absorbs-the-rounding tests below.
+        remainders.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.0.cmp(&a.0))
+        });
+        let leftover = remaining.saturating_sub(floor_total) as usize;
+        for &(i, _) in remainders.iter().take(leftover) {
+            floors[i] += 1;
+        }
+
+        for &i in &flexible_indices {
+            sizes[i] = floors[i];
         }
 
         sizes
@@ -172,12 +450,104 @@ impl Default for Layout {
     }
 }
 
-impl Renderable for Layout {
-    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+impl Layout {
+    /// Recursively assign absolute screen coordinates to every leaf layout
+    /// under `area`. Mirrors how `render` walks the tree -- a `Vertical`
+    /// split only divides `area.height` (each child spans the full
+    /// width), a `Horizontal` split only divides `area.width` (each child
+    /// spans the full height) -- but, unlike `render`, keeps the x/y
+    /// offset instead of flattening straight to `Segment`s.
+    pub fn split(&self, area: Rect) -> Vec<(Rect, &Layout)> {
         if !self.visible {
             return Vec::new();
         }
 
+        let area = self.inset(area);
+
+        if self.children.is_empty() {
+            return vec![(area, self)];
+        }
+
+        let mut result = Vec::new();
+        match self.direction {
+            Direction::Vertical => {
+                let splits = self.calculate_splits(area.height);
+                let mut y = area.y;
+                for (child, &h) in self.children.iter().zip(splits.iter()) {
+                    let child_area = Rect { x: area.x, y, width: area.width, height: h };
+                    result.extend(child.split(child_area));
+                    y += h;
+                }
+            }
+            Direction::Horizontal => {
+                let splits = self.calculate_splits(area.width);
+                let mut x = area.x;
+                for (child, &w) in self.children.iter().zip(splits.iter()) {
+                    let child_area = Rect { x, y: area.y, width: w, height: area.height };
+                    result.extend(child.split(child_area));
+                    x += w;
+                }
+            }
+        }
+        result
+    }
+
+    /// Shrink `area` by this layout's margin, offsetting `x`/`y` inward to
+    /// match -- the geometry counterpart of [`Layout::render_margined`]'s
+    /// width/height shrink.
+    fn inset(&self, area: Rect) -> Rect {
+        let h = self.margin.horizontal;
+        let v = self.margin.vertical;
+        Rect {
+            x: area.x + h,
+            y: area.y + v,
+            width: area.width.saturating_sub(h * 2),
+            height: area.height.saturating_sub(v * 2),
+        }
+    }
+
+    /// Shrink `context` by this layout's margin, render its content into
+    /// that inset space, then pad the result back out: blank lines above
+    /// and below for the vertical margin, and `horizontal` spaces of
+    /// left/right padding on every produced line.
+    fn render_margined(&self, context: &RenderContext) -> Vec<Segment> {
+        if self.margin.vertical == 0 && self.margin.horizontal == 0 {
+            return self.render_content(context);
+        }
+
+        let h_shrink = self.margin.horizontal as usize * 2;
+        let v_shrink = self.margin.vertical as usize * 2;
+        let inner_context = RenderContext {
+            width: context.width.saturating_sub(h_shrink),
+            height: context.height.map(|h| h.saturating_sub(v_shrink)),
+            direction: context.direction,
+        };
+        let content = self.render_content(&inner_context);
+
+        let h_pad = " ".repeat(self.margin.horizontal as usize);
+        let blank_line = || Segment::new(vec![crate::text::Span::raw(" ".repeat(context.width))]);
+
+        let mut segments = Vec::new();
+        for _ in 0..self.margin.vertical {
+            segments.push(blank_line());
+        }
+        for line in content {
+            let mut spans = vec![crate::text::Span::raw(h_pad.clone())];
+            spans.extend(line.spans);
+            spans.push(crate::text::Span::raw(h_pad.clone()));
+            segments.push(Segment::line(spans));
+        }
+        for _ in 0..self.margin.vertical {
+            segments.push(blank_line());
+        }
+
+        segments
+    }
+
+    /// Render this layout's content (children split across the axis, or a
+    /// leaf renderable) without accounting for margin; called by
+    /// [`Layout::render_margined`] against the already-inset context.
+    fn render_content(&self, context: &RenderContext) -> Vec<Segment> {
         // Leaf node: Render content
         if self.children.is_empty() {
             if let Some(r) = &self.renderable {
@@ -217,6 +587,7 @@ impl Renderable for Layout {
                     let child_ctx = RenderContext {
                         width: context.width,
                         height: Some(h),
+                        direction: context.direction,
                     };
                     let child_segments = child.render(&child_ctx);
 
@@ -265,9 +636,10 @@ impl Renderable for Layout {
                 }
 
                 // Pass through the parent's height constraint to children
-                let child_ctx = RenderContext { 
-                    width: w, 
+                let child_ctx = RenderContext {
+                    width: w,
                     height: target_height,
+                    direction: context.direction,
                 };
                 let child_segs = child.render(&child_ctx);
                 max_lines = std::cmp::max(max_lines, child_segs.len());
@@ -301,17 +673,29 @@ impl Renderable for Layout {
     }
 }
 
+impl Renderable for Layout {
+    /// Flattens the tree straight to `Segment`s for printing. For the
+    /// underlying geometry -- where each child actually landed -- see
+    /// [`Layout::split`].
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        if !self.visible {
+            return Vec::new();
+        }
+        self.render_margined(context)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_calculate_splits_ratios() {
+    fn test_calculate_splits_fill_weights() {
         // Equal split
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1),
-            Layout::new().with_ratio(1),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![50, 50]);
@@ -319,35 +703,46 @@ mod tests {
         // 1:3 split
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1),
-            Layout::new().with_ratio(3),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(3)),
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![25, 75]);
     }
 
     #[test]
-    fn test_calculate_splits_fixed() {
+    fn test_calculate_splits_length() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_size(10),
-            Layout::new().with_size(20),
+            Layout::new().with_constraint(Constraint::Length(10)),
+            Layout::new().with_constraint(Constraint::Length(20)),
         ]);
         let splits = layout.calculate_splits(100);
-        // If NO ratio items, implementation should just give fixed?
-        // Wait, if NO ratio items, `flexible_indices` is empty, so loop 2 doesn't run.
-        // So expected is [10, 20]. (Correct)
+        // With no Min/Max/Fill siblings, the flexible pass never runs, so
+        // the Length constraints are used exactly as given.
         assert_eq!(splits[0], 10);
         assert_eq!(splits[1], 20);
     }
 
+    #[test]
+    fn test_calculate_splits_percentage_and_ratio() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Percentage(25)),
+            Layout::new().with_constraint(Constraint::Ratio(1, 4)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+        let splits = layout.calculate_splits(100);
+        assert_eq!(splits, vec![25, 25, 50]);
+    }
+
     #[test]
     fn test_calculate_splits_mixed() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_size(10), // Fixed 10
-            Layout::new().with_ratio(1), // Takes half of remaining (90/2 = 45)
-            Layout::new().with_ratio(1), // Takes other half (45)
+            Layout::new().with_constraint(Constraint::Length(10)), // Fixed 10
+            Layout::new().with_constraint(Constraint::Fill(1)),    // Takes half of remaining (90/2 = 45)
+            Layout::new().with_constraint(Constraint::Fill(1)),    // Takes other half (45)
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![10, 45, 45]);
@@ -359,43 +754,56 @@ mod tests {
         // Should be 33, 33, 34
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1),
-            Layout::new().with_ratio(1),
-            Layout::new().with_ratio(1),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![33, 33, 34]);
         assert_eq!(splits.iter().sum::<u16>(), 100);
     }
     #[test]
-    fn test_calculate_splits_min_size_simple() {
+    fn test_calculate_splits_min_floor_simple() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1).with_minimum_size(60),
-            Layout::new().with_ratio(1),
+            Layout::new().with_constraint(Constraint::Min(60)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![60, 40]);
     }
 
     #[test]
-    fn test_calculate_splits_min_size_priority() {
+    fn test_calculate_splits_min_floor_priority() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1).with_minimum_size(80),
-            Layout::new().with_ratio(1).with_minimum_size(10),
+            Layout::new().with_constraint(Constraint::Min(80)),
+            Layout::new().with_constraint(Constraint::Min(10)),
         ]);
         let splits = layout.calculate_splits(100);
         assert_eq!(splits, vec![80, 20]);
     }
 
+    #[test]
+    fn test_calculate_splits_max_ceiling() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Max(20)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+        let splits = layout.calculate_splits(100);
+        // Even split would be 50/50, but Max(20) caps the first column,
+        // so the second gets the rest.
+        assert_eq!(splits, vec![20, 80]);
+    }
+
     #[test]
     fn test_calculate_splits_complex_min() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_size(5),
-            Layout::new().with_ratio(1).with_minimum_size(10),
-            Layout::new().with_ratio(1),
+            Layout::new().with_constraint(Constraint::Length(5)),
+            Layout::new().with_constraint(Constraint::Min(10)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
         ]);
         let splits = layout.calculate_splits(20);
         assert_eq!(splits, vec![5, 10, 5]);
@@ -405,12 +813,12 @@ mod tests {
     fn test_vertical_split_ratios() {
         let mut layout = Layout::new();
         layout.split_column(vec![
-            Layout::new().with_ratio(1).with_name("Top"),
-            Layout::new().with_ratio(1).with_name("Bottom"),
+            Layout::new().with_constraint(Constraint::Fill(1)).with_name("Top"),
+            Layout::new().with_constraint(Constraint::Fill(1)).with_name("Bottom"),
         ]);
 
         // Mock context with height
-        let context = RenderContext { width: 80, height: Some(10) };
+        let context = RenderContext { width: 80, height: Some(10), direction: Default::default() };
         let segments = layout.render(&context);
 
         // Should have 10 lines total
@@ -425,31 +833,194 @@ mod tests {
     fn test_vertical_split_stacking() {
         let mut layout = Layout::new();
         layout.split_column(vec![
-            Layout::new().with_size(1).with_name("Top"),
+            Layout::new().with_constraint(Constraint::Length(1)).with_name("Top"),
             Layout::new().with_name("Bottom"),
         ]);
 
         // Unconstrained height
-        let context = RenderContext { width: 80, height: None };
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
         let segments = layout.render(&context);
 
         // Each leaf layout renders 1 blank line by default if empty
         assert_eq!(segments.len(), 2);
     }
 
+    #[test]
+    fn test_add_split_appends_without_resetting_existing_children() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![Layout::new().with_name("Left")]);
+        layout.add_split(Layout::new().with_name("Right"));
+
+        assert_eq!(layout.direction, Direction::Horizontal);
+        assert_eq!(layout.children.len(), 2);
+        assert_eq!(layout.children[1].name.as_deref(), Some("Right"));
+    }
+
+    #[test]
+    fn test_get_mut_finds_nested_named_layout() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_name("Left"),
+            Layout::new().with_name("Right"),
+        ]);
+
+        let found = layout.get_mut("Right").expect("Right should be found");
+        found.update(crate::panel::Panel::new("updated"));
+        assert!(found.renderable.is_some());
+
+        assert!(layout.get_mut("Missing").is_none());
+    }
+
     #[test]
     fn test_horizontal_split_propagates_height() {
         let mut layout = Layout::new();
         layout.split_row(vec![
-            Layout::new().with_ratio(1),
-            Layout::new().with_ratio(1),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
         ]);
 
         // If we pass a height, it should be enforced on children (columns)
-        let context = RenderContext { width: 80, height: Some(5) };
+        let context = RenderContext { width: 80, height: Some(5), direction: Default::default() };
         let segments = layout.render(&context);
 
         // Should have 5 lines
         assert_eq!(segments.len(), 5);
     }
+
+    #[test]
+    fn test_margin_insets_leaf_content() {
+        let layout = Layout::new().with_margin(1, 2);
+
+        let context = RenderContext { width: 20, height: Some(5), direction: Default::default() };
+        let segments = layout.render(&context);
+
+        // 1 blank line of vertical margin above/below the leaf's own line.
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].plain_text(), " ".repeat(20));
+        assert_eq!(segments[2].plain_text(), " ".repeat(20));
+        // Horizontal margin pads the content line back out to full width.
+        assert_eq!(segments[1].plain_text().len(), 20);
+        assert!(segments[1].plain_text().starts_with("  "));
+        assert!(segments[1].plain_text().ends_with("  "));
+    }
+
+    #[test]
+    fn test_margin_shrinks_split_width() {
+        let mut layout = Layout::new().with_margin(0, 5);
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+
+        let context = RenderContext { width: 30, height: Some(1), direction: Default::default() };
+        let segments = layout.render(&context);
+
+        // 30 - 2*5 = 20 cells of content, split evenly, padded back to 30.
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].plain_text().len(), 30);
+    }
+
+    #[test]
+    fn test_calculate_splits_populates_and_resets_cache() {
+        Layout::reset_cache();
+
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+
+        let splits = layout.calculate_splits(100);
+        assert_eq!(splits, vec![50, 50]);
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        // Same total_size/constraints/direction hits the cached entry.
+        let cached = layout.calculate_splits(100);
+        assert_eq!(cached, vec![50, 50]);
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 1);
+
+        Layout::reset_cache();
+        assert_eq!(LAYOUT_CACHE.with(|cache| cache.borrow().len()), 0);
+    }
+
+    #[test]
+    fn test_split_assigns_absolute_horizontal_coordinates() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Length(10)).with_name("Left"),
+            Layout::new().with_constraint(Constraint::Fill(1)).with_name("Right"),
+        ]);
+
+        let area = Rect { x: 5, y: 2, width: 100, height: 20 };
+        let leaves = layout.split(area);
+
+        assert_eq!(leaves.len(), 2);
+        assert_eq!(leaves[0].0, Rect { x: 5, y: 2, width: 10, height: 20 });
+        assert_eq!(leaves[0].1.name.as_deref(), Some("Left"));
+        assert_eq!(leaves[1].0, Rect { x: 15, y: 2, width: 90, height: 20 });
+        assert_eq!(leaves[1].1.name.as_deref(), Some("Right"));
+    }
+
+    #[test]
+    fn test_split_stacks_vertical_children_by_y() {
+        let mut layout = Layout::new();
+        layout.split_column(vec![
+            Layout::new().with_constraint(Constraint::Length(3)).with_name("Top"),
+            Layout::new().with_constraint(Constraint::Fill(1)).with_name("Bottom"),
+        ]);
+
+        let area = Rect { x: 0, y: 0, width: 40, height: 10 };
+        let leaves = layout.split(area);
+
+        assert_eq!(leaves[0].0, Rect { x: 0, y: 0, width: 40, height: 3 });
+        assert_eq!(leaves[1].0, Rect { x: 0, y: 3, width: 40, height: 7 });
+    }
+
+    #[test]
+    fn test_split_applies_margin_inset() {
+        let layout = Layout::new().with_margin(1, 2);
+
+        let area = Rect { x: 0, y: 0, width: 20, height: 10 };
+        let leaves = layout.split(area);
+
+        assert_eq!(leaves.len(), 1);
+        assert_eq!(leaves[0].0, Rect { x: 2, y: 1, width: 16, height: 8 });
+    }
+
+    #[test]
+    fn test_resize_child_shifts_boundary_between_siblings() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Fill(1)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+        // Record a total_size (100) to resize against.
+        assert_eq!(layout.calculate_splits(100), vec![50, 50]);
+
+        layout.resize_child(0, 10);
+
+        assert_eq!(layout.children[0].constraint, Constraint::Percentage(60));
+        assert_eq!(layout.children[1].constraint, Constraint::Percentage(40));
+        assert_eq!(layout.calculate_splits(100), vec![60, 40]);
+
+        // A later terminal resize still respects the new 60/40 split.
+        assert_eq!(layout.calculate_splits(200), vec![120, 80]);
+    }
+
+    #[test]
+    fn test_resize_child_clamps_to_min_floor() {
+        let mut layout = Layout::new();
+        layout.split_row(vec![
+            Layout::new().with_constraint(Constraint::Min(30)),
+            Layout::new().with_constraint(Constraint::Fill(1)),
+        ]);
+        assert_eq!(layout.calculate_splits(100), vec![50, 50]);
+
+        // Try to shrink child 0 well past its Min(30) floor.
+        layout.resize_child(0, -30);
+
+        assert_eq!(layout.children[0].constraint, Constraint::Percentage(30));
+        assert_eq!(layout.children[1].constraint, Constraint::Percentage(70));
+        assert_eq!(layout.calculate_splits(100), vec![30, 70]);
+    }
 }
@@ -108,13 +108,17 @@
 //! | `rprint!` / `rprintln!` | ✅ Parsed | Alias (when you need both std and rich) |
 
 use std::cell::RefCell;
+use std::sync::Mutex;
 
 // Core modules
 pub mod align;
+pub mod backend;
 pub mod bar;
+pub mod bidi;
 pub mod box_drawing;
 pub mod console;
 pub mod emoji;
+pub mod gradient;
 pub mod group;
 pub mod highlighter;
 pub mod markup;
@@ -124,12 +128,15 @@ pub mod padding;
 pub mod pager;
 pub mod renderable;
 pub mod screen;
+pub mod shaping;
 pub mod style;
 pub mod text;
 pub mod theme;
 
 // Renderables
 pub mod columns;
+pub mod diagnostic;
+pub mod diff;
 pub mod filesize;
 pub mod layout;
 pub mod live;
@@ -143,6 +150,7 @@ pub mod tree;
 pub mod progress;
 
 // Utilities
+pub mod ansi_ingest;
 pub mod inspect;
 pub mod json;
 pub mod prompt;
@@ -155,11 +163,16 @@ pub mod markdown;
 #[cfg(feature = "syntax")]
 pub mod syntax;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // Re-exports for convenience
-pub use console::Console;
+pub use columns::{Columns, Constraint};
+pub use console::{Console, ConsolePool};
+pub use diagnostic::{Diagnostic, Label, Severity};
 pub use layout::Layout;
 pub use live::Live;
-pub use panel::{BorderStyle, Panel};
+pub use panel::{BorderStyle, Borders, Panel, TitleAlign};
 pub use renderable::Renderable;
 pub use rule::Rule;
 pub use style::{Color, Style};
@@ -171,12 +184,136 @@ pub use tree::{Tree, TreeNode};
 // Thread-local Console for Print Macros
 // ============================================================================
 
+// Process-global templates used to initialize each thread's macro consoles.
+// `None` means "use the library default" (`Console::new()` / `Console::stderr()`).
+// Set via `set_default_console` or `configure_macros()` so that a house theme,
+// width, or highlighter configured once applies to every thread, not just the
+// one that called it.
+static STDOUT_TEMPLATE: Mutex<Option<Console>> = Mutex::new(None);
+static STDERR_TEMPLATE: Mutex<Option<Console>> = Mutex::new(None);
+
+fn stdout_console_from_template() -> Console {
+    STDOUT_TEMPLATE
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(Console::new)
+}
+
+fn stderr_console_from_template() -> Console {
+    STDERR_TEMPLATE
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(Console::stderr)
+}
+
 thread_local! {
-    static STDOUT_CONSOLE: RefCell<Console> = RefCell::new(Console::new());
-    static STDERR_CONSOLE: RefCell<Console> = RefCell::new(Console::stderr());
+    static STDOUT_CONSOLE: RefCell<Console> = RefCell::new(stdout_console_from_template());
+    static STDERR_CONSOLE: RefCell<Console> = RefCell::new(stderr_console_from_template());
     // Raw consoles have markup parsing disabled - for data output
-    static STDOUT_RAW_CONSOLE: RefCell<Console> = RefCell::new(Console::new().markup(false));
-    static STDERR_RAW_CONSOLE: RefCell<Console> = RefCell::new(Console::stderr().markup(false));
+    static STDOUT_RAW_CONSOLE: RefCell<Console> = RefCell::new(stdout_console_from_template().markup(false));
+    static STDERR_RAW_CONSOLE: RefCell<Console> = RefCell::new(stderr_console_from_template().markup(false));
+}
+
+/// Replace the default console used by the `print!`/`println!` macro family
+/// (and, with markup parsing turned off, the `print_raw!`/`println_raw!`
+/// family) on whichever stream `console` targets -- stdout or stderr, judged
+/// by how the console was constructed (`Console::new()` vs
+/// `Console::stderr()`).
+///
+/// Updates the current thread's macro consoles immediately, and stores
+/// `console` as the template future threads initialize theirs from, so a
+/// house theme, forced width, or disabled highlighter set once here applies
+/// process-wide instead of only on the calling thread.
+pub fn set_default_console(console: Console) {
+    if console.is_stderr_target() {
+        *STDERR_TEMPLATE.lock().unwrap() = Some(console.clone());
+        STDERR_CONSOLE.with(|c| *c.borrow_mut() = console.clone());
+        STDERR_RAW_CONSOLE.with(|c| *c.borrow_mut() = console.markup(false));
+    } else {
+        *STDOUT_TEMPLATE.lock().unwrap() = Some(console.clone());
+        STDOUT_CONSOLE.with(|c| *c.borrow_mut() = console.clone());
+        STDOUT_RAW_CONSOLE.with(|c| *c.borrow_mut() = console.markup(false));
+    }
+}
+
+/// Start building a configuration for the macro consoles (see
+/// [`set_default_console`]), applied to both the stdout and stderr streams
+/// at once.
+///
+/// ```no_run
+/// use fast_rich::{configure_macros, theme::Theme};
+///
+/// configure_macros()
+///     .theme(Theme::monokai())
+///     .width(100)
+///     .force_color(true)
+///     .apply();
+/// ```
+pub fn configure_macros() -> MacroConfig {
+    MacroConfig::default()
+}
+
+/// Builder returned by [`configure_macros`]. Unset fields leave the
+/// corresponding `Console` setting at its default.
+#[derive(Default)]
+pub struct MacroConfig {
+    theme: Option<theme::Theme>,
+    width: Option<usize>,
+    force_color: Option<bool>,
+    highlight: Option<bool>,
+}
+
+impl MacroConfig {
+    /// Set the theme used to resolve styles and automatic highlighting.
+    pub fn theme(mut self, theme: theme::Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Force a fixed terminal width instead of auto-detecting one.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Force (or suppress) color output regardless of TTY detection.
+    pub fn force_color(mut self, force: bool) -> Self {
+        self.force_color = Some(force);
+        self
+    }
+
+    /// Enable or disable automatic repr highlighting (see
+    /// [`crate::console::Console::highlight`]).
+    pub fn highlight(mut self, enabled: bool) -> Self {
+        self.highlight = Some(enabled);
+        self
+    }
+
+    fn apply_to(&self, mut console: Console) -> Console {
+        if let Some(theme) = &self.theme {
+            console = console.theme(theme.clone());
+        }
+        if let Some(width) = self.width {
+            console = console.width(width);
+        }
+        if let Some(force) = self.force_color {
+            console = console.force_color(force);
+        }
+        if let Some(enabled) = self.highlight {
+            console = console.highlight(enabled);
+        }
+        console
+    }
+
+    /// Apply this configuration to the stdout and stderr macro consoles, for
+    /// the current thread and as the template future threads initialize
+    /// theirs from.
+    pub fn apply(self) {
+        set_default_console(self.apply_to(Console::new()));
+        set_default_console(self.apply_to(Console::stderr()));
+    }
 }
 
 /// Internal helper for print macros - DO NOT USE DIRECTLY.
@@ -450,13 +587,17 @@ pub mod prelude {
     // Raw print macros for data output (no markup parsing, no std conflicts)
     pub use crate::{eprint_raw, eprintln_raw, print_raw, println_raw};
 
-    pub use crate::columns::Columns;
+    pub use crate::columns::{Columns, Constraint};
     pub use crate::console::Console;
+    pub use crate::diagnostic::{Diagnostic, Label, Severity};
     pub use crate::inspect::{inspect, InspectConfig};
     pub use crate::json::Json;
     pub use crate::log::ConsoleLog;
-    pub use crate::panel::{BorderStyle, Panel};
-    pub use crate::progress::{track, Progress, ProgressBar, Spinner, SpinnerStyle, Status};
+    pub use crate::panel::{BorderStyle, Borders, Panel, TitleAlign};
+    pub use crate::progress::{
+        track, Progress, ProgressBar, Spinner, SpinnerBoard, SpinnerBoardView, SpinnerFrames,
+        SpinnerManager, SpinnerStyle, Status,
+    };
     pub use crate::renderable::Renderable;
     pub use crate::rule::Rule;
     pub use crate::style::{Color, Style};
@@ -480,8 +621,8 @@ mod tests {
     fn test_style_builder() {
         let style = Style::new().foreground(Color::Red).bold().underline();
 
-        assert!(style.bold);
-        assert!(style.underline);
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(true));
     }
 
     #[test]
@@ -501,7 +642,8 @@ mod tests {
         assert!(!table
             .render(&console::RenderContext {
                 width: 40,
-                height: None
+                height: None,
+                direction: Default::default()
             })
             .is_empty());
     }
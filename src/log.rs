@@ -3,9 +3,11 @@
 //! Provides timestamped logging with file/line information and pretty-printing.
 
 use crate::console::{Console, RenderContext};
+use crate::panel::BorderStyle;
 use crate::renderable::{Renderable, Segment};
 use crate::style::{Color, Style};
-use crate::text::Span;
+use crate::table::{Column, Table};
+use crate::text::{Span, Text};
 use std::time::SystemTime;
 
 /// A log message with metadata.
@@ -23,6 +25,161 @@ pub struct LogMessage {
     pub level: LogLevel,
     /// Whether to show the timestamp
     pub show_time: bool,
+    /// Module path the message originated from, if known.
+    pub target: Option<String>,
+    /// Overrides the fixed time/level/message/location layout with a
+    /// caller-chosen token order. See [`LogFormat`].
+    pub format: Option<LogFormat>,
+    /// Structured key-value context attached via [`LogMessage::field`] or
+    /// collected from a `log::Record`'s `key_values()`.
+    pub fields: Vec<(String, String)>,
+    /// How the `Time` token is formatted. See [`TimeFormat`].
+    pub time_format: TimeFormat,
+    /// Offset, in seconds, applied to the UTC timestamp before formatting
+    /// (e.g. `3600` for UTC+1), so local time can be rendered without a
+    /// timezone database.
+    pub utc_offset_seconds: i32,
+    /// Maximum display width for the `Target` token before it's truncated
+    /// down to its rightmost `::`-separated segment, keeping the log
+    /// column aligned across lines. `None` means no truncation.
+    pub target_width: Option<usize>,
+}
+
+/// Above this many [`LogMessage::fields`], `render` switches from inline
+/// `key=value` spans to a compact two-column table.
+const INLINE_FIELD_LIMIT: usize = 4;
+
+/// One element of a [`LogFormat`]'s token order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatToken {
+    /// The bracketed timestamp, laid out per [`LogMessage::time_format`]
+    /// and skipped when [`LogMessage::show_time`] is false.
+    Time,
+    /// The level label (`INFO`, `WARN`, ...).
+    Level,
+    /// The module path, skipped when [`LogMessage::target`] is `None`.
+    Target,
+    /// The `file:line` location, skipped when no location was set.
+    Location,
+    /// The message text.
+    Message,
+    /// Fixed text, e.g. a separator between other tokens.
+    Literal(String),
+}
+
+/// An ordered layout of [`FormatToken`]s controlling how
+/// [`LogMessage::render`] lays out a log line, modeled on simplelog's
+/// token-based format builder. Build one with [`LogFormat::builder`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFormat {
+    tokens: Vec<FormatToken>,
+}
+
+impl LogFormat {
+    /// Start building a [`LogFormat`] token by token.
+    pub fn builder() -> LogFormatBuilder {
+        LogFormatBuilder::default()
+    }
+
+    /// The fixed timestamp/level/message/location layout `LogMessage`
+    /// renders when no custom `format` is set.
+    fn default_layout() -> Self {
+        LogFormat::builder()
+            .time()
+            .literal(" ")
+            .level()
+            .literal(" ")
+            .message()
+            .literal(" ")
+            .location()
+            .build()
+    }
+
+    /// The tokens in render order.
+    pub fn tokens(&self) -> &[FormatToken] {
+        &self.tokens
+    }
+}
+
+/// Builder for [`LogFormat`]; each method appends one token.
+#[derive(Debug, Clone, Default)]
+pub struct LogFormatBuilder {
+    tokens: Vec<FormatToken>,
+}
+
+impl LogFormatBuilder {
+    /// Append a [`FormatToken::Time`] token.
+    pub fn time(mut self) -> Self {
+        self.tokens.push(FormatToken::Time);
+        self
+    }
+
+    /// Append a [`FormatToken::Level`] token.
+    pub fn level(mut self) -> Self {
+        self.tokens.push(FormatToken::Level);
+        self
+    }
+
+    /// Append a [`FormatToken::Target`] token.
+    pub fn target(mut self) -> Self {
+        self.tokens.push(FormatToken::Target);
+        self
+    }
+
+    /// Append a [`FormatToken::Location`] token.
+    pub fn location(mut self) -> Self {
+        self.tokens.push(FormatToken::Location);
+        self
+    }
+
+    /// Append a [`FormatToken::Message`] token.
+    pub fn message(mut self) -> Self {
+        self.tokens.push(FormatToken::Message);
+        self
+    }
+
+    /// Append a fixed [`FormatToken::Literal`] token, e.g. a separator.
+    pub fn literal(mut self, text: impl Into<String>) -> Self {
+        self.tokens.push(FormatToken::Literal(text.into()));
+        self
+    }
+
+    /// Finish building the [`LogFormat`].
+    pub fn build(self) -> LogFormat {
+        LogFormat { tokens: self.tokens }
+    }
+}
+
+/// How [`LogMessage::format_time`] renders the `Time` token. Defaults to
+/// [`TimeFormat::HmsMillis`], matching the crate's original hardcoded
+/// `HH:MM:SS.mmm` layout.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// `HH:MM:SS`.
+    Hms,
+    /// `HH:MM:SS.mmm`.
+    #[default]
+    HmsMillis,
+    /// `YYYY-MM-DD HH:MM:SS`.
+    DateTime,
+    /// A strftime-like pattern supporting `%Y %m %d %H %M %S %3f`.
+    Custom(String),
+}
+
+/// Civil (year, month, day) for the given count of days since the Unix
+/// epoch (1970-01-01), via Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
 }
 
 /// Log level for messages.
@@ -71,6 +228,12 @@ impl LogMessage {
             time: SystemTime::now(),
             level: LogLevel::Info,
             show_time: true,
+            target: None,
+            format: None,
+            fields: Vec::new(),
+            time_format: TimeFormat::default(),
+            utc_offset_seconds: 0,
+            target_width: None,
         }
     }
 
@@ -86,26 +249,93 @@ impl LogMessage {
         self.level = level;
         self
     }
-    
+
     /// Set whether to show the timestamp.
     pub fn show_time(mut self, show: bool) -> Self {
         self.show_time = show;
         self
     }
 
-    /// Format the timestamp.
+    /// Set the module path this message originated from.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Override the fixed timestamp/level/message/location layout with a
+    /// custom token order. See [`LogFormat`].
+    pub fn format(mut self, format: LogFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Attach a structured key-value pair, rendered at the end of the line
+    /// after every format token (as a table below the line when there are
+    /// more than four fields).
+    /// See [`LogMessage::fields`].
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set how the `Time` token is formatted. See [`TimeFormat`].
+    pub fn time_format(mut self, format: TimeFormat) -> Self {
+        self.time_format = format;
+        self
+    }
+
+    /// Set the UTC offset, in seconds, applied before formatting the
+    /// timestamp. See [`LogMessage::utc_offset_seconds`].
+    pub fn utc_offset(mut self, seconds: i32) -> Self {
+        self.utc_offset_seconds = seconds;
+        self
+    }
+
+    /// Set the maximum display width for the `Target` token. See
+    /// [`LogMessage::target_width`].
+    pub fn target_width(mut self, width: usize) -> Self {
+        self.target_width = Some(width);
+        self
+    }
+
+    /// Format the timestamp per `self.time_format`.
     fn format_time(&self) -> String {
         use std::time::UNIX_EPOCH;
 
         let duration = self.time.duration_since(UNIX_EPOCH).unwrap_or_default();
-        let secs = duration.as_secs(); 
-        
-        let hours = (secs / 3600) % 24;
-        let minutes = (secs / 60) % 60;
-        let seconds = secs % 60;
         let millis = duration.subsec_millis();
+        let total_secs = duration.as_secs() as i64 + self.utc_offset_seconds as i64;
+
+        let days = total_secs.div_euclid(86400);
+        let secs_of_day = total_secs.rem_euclid(86400);
+        let hours = secs_of_day / 3600;
+        let minutes = (secs_of_day / 60) % 60;
+        let seconds = secs_of_day % 60;
 
-        format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+        match &self.time_format {
+            TimeFormat::Hms => format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
+            TimeFormat::HmsMillis => {
+                format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis)
+            }
+            TimeFormat::DateTime => {
+                let (year, month, day) = civil_from_days(days);
+                format!(
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                    year, month, day, hours, minutes, seconds
+                )
+            }
+            TimeFormat::Custom(pattern) => {
+                let (year, month, day) = civil_from_days(days);
+                pattern
+                    .replace("%Y", &format!("{:04}", year))
+                    .replace("%m", &format!("{:02}", month))
+                    .replace("%d", &format!("{:02}", day))
+                    .replace("%H", &format!("{:02}", hours))
+                    .replace("%M", &format!("{:02}", minutes))
+                    .replace("%S", &format!("{:02}", seconds))
+                    .replace("%3f", &format!("{:03}", millis))
+            }
+        }
     }
 
     /// Format the location.
@@ -119,42 +349,89 @@ impl LogMessage {
             _ => None,
         }
     }
+
+    /// Format the target, truncated to its rightmost `::`-separated
+    /// segment when it exceeds `self.target_width`.
+    fn format_target(&self) -> Option<String> {
+        let target = self.target.as_ref()?;
+        match self.target_width {
+            Some(width) if target.chars().count() > width => {
+                Some(target.rsplit("::").next().unwrap_or(target).to_string())
+            }
+            _ => Some(target.clone()),
+        }
+    }
 }
 
 impl Renderable for LogMessage {
-    fn render(&self, _context: &RenderContext) -> Vec<Segment> {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        let format = self.format.clone().unwrap_or_else(LogFormat::default_layout);
         let mut spans = Vec::new();
 
-        // Timestamp
-        if self.show_time {
-            spans.push(Span::styled(
-                format!("[{}]", self.format_time()),
-                Style::new().dim(),
-            ));
-            spans.push(Span::raw(" "));
+        for token in format.tokens() {
+            match token {
+                FormatToken::Time => {
+                    if self.show_time {
+                        spans.push(Span::styled(
+                            format!("[{}]", self.format_time()),
+                            Style::new().dim(),
+                        ));
+                    }
+                }
+                FormatToken::Level => {
+                    spans.push(Span::styled(
+                        format!("{:5}", self.level.label()),
+                        self.level.style(),
+                    ));
+                }
+                FormatToken::Target => {
+                    if let Some(target) = self.format_target() {
+                        spans.push(Span::styled(
+                            target,
+                            Style::new().foreground(Color::Cyan).dim(),
+                        ));
+                    }
+                }
+                FormatToken::Location => {
+                    if let Some(location) = self.format_location() {
+                        spans.push(Span::styled(
+                            location,
+                            Style::new().foreground(Color::Cyan).dim(),
+                        ));
+                    }
+                }
+                FormatToken::Message => {
+                    spans.push(Span::raw(self.message.clone()));
+                }
+                FormatToken::Literal(text) => {
+                    spans.push(Span::raw(text.clone()));
+                }
+            }
         }
 
-        // Level
-        spans.push(Span::styled(
-            format!("{:5}", self.level.label()),
-            self.level.style(),
-        ));
-
-        spans.push(Span::raw(" "));
+        if !self.fields.is_empty() && self.fields.len() <= INLINE_FIELD_LIMIT {
+            for (key, value) in &self.fields {
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(key.clone(), Style::new().foreground(Color::Cyan).dim()));
+                spans.push(Span::styled("=".to_string(), Style::new().dim()));
+                spans.push(Span::styled(value.clone(), Style::new().dim()));
+            }
+        }
 
-        // Message
-        spans.push(Span::raw(self.message.clone()));
+        let mut segments = vec![Segment::line(spans)];
 
-        // Location
-        if let Some(location) = self.format_location() {
-             spans.push(Span::raw(" "));
-            spans.push(Span::styled(
-                location,
-                Style::new().foreground(Color::Cyan).dim(),
-            ));
+        if self.fields.len() > INLINE_FIELD_LIMIT {
+            let mut table = Table::new();
+            table.add_column(Column::new("field").header_style(Style::new().bold()));
+            table.add_column(Column::new("value").header_style(Style::new().bold()));
+            for (key, value) in &self.fields {
+                table.add_row(vec![Text::plain(key.clone()), Text::plain(value.clone())]);
+            }
+            let table = table.border_style(BorderStyle::Rounded);
+            segments.extend(table.render(context));
         }
 
-        vec![Segment::line(spans)]
+        segments
     }
 }
 
@@ -210,11 +487,101 @@ macro_rules! log {
 mod log_integration {
     //! Integration with the `log` crate.
     use super::*;
+    use log::kv::{Error as KvError, Key, Source, Value, VisitSource};
     use log::{Level, Log, Metadata, Record, SetLoggerError};
     use std::sync::OnceLock;
 
     static CONSOLE: OnceLock<Console> = OnceLock::new();
 
+    /// Collects a `Record`'s `key_values()` into owned `(String, String)`
+    /// pairs so they can be attached to a [`LogMessage`] via
+    /// [`LogMessage::field`].
+    #[derive(Default)]
+    struct FieldCollector {
+        fields: Vec<(String, String)>,
+    }
+
+    impl<'kvs> VisitSource<'kvs> for FieldCollector {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), KvError> {
+            self.fields.push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    fn collect_fields(source: &dyn Source) -> Vec<(String, String)> {
+        let mut collector = FieldCollector::default();
+        let _ = source.visit(&mut collector);
+        collector.fields
+    }
+
+    /// A single `env_logger`-style filter directive: either a bare level
+    /// (`target: None`, sets the global default) or a `path=level`/bare-path
+    /// entry scoping a level (or, for a bare path, "show everything") to a
+    /// module prefix. See [`parse_filters`].
+    #[derive(Debug, Clone)]
+    pub struct Directive {
+        /// Module path prefix this directive applies to, or `None` for the
+        /// global default.
+        pub target: Option<String>,
+        /// Maximum level allowed through for a matching record.
+        pub level: log::LevelFilter,
+    }
+
+    /// Parse an `env_logger`-style directive string (e.g.
+    /// `"warn,fast_rich::table=debug,my_app=trace"`) into [`Directive`]s.
+    /// Each comma-separated piece is either a bare level (the global
+    /// default), `path=level`, or a bare path (treated as `path=trace`,
+    /// i.e. "show everything from this target").
+    pub fn parse_filters(spec: &str) -> Vec<Directive> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| match part.split_once('=') {
+                Some((target, level)) => Directive {
+                    target: Some(target.trim().to_string()),
+                    level: level.trim().parse().unwrap_or(log::LevelFilter::Trace),
+                },
+                None => match part.parse::<log::LevelFilter>() {
+                    Ok(level) => Directive { target: None, level },
+                    Err(_) => Directive {
+                        target: Some(part.to_string()),
+                        level: log::LevelFilter::Trace,
+                    },
+                },
+            })
+            .collect()
+    }
+
+    /// Resolve the effective level for `target` against `filters`, falling
+    /// back to `default` when nothing matches. Among directives whose
+    /// `target` is a prefix of `target`, the **longest** prefix wins; ties
+    /// (equal-length prefixes, including two identical targets) go to
+    /// whichever directive appears later in `filters`. A bare-level
+    /// directive updates `default` itself rather than competing on prefix
+    /// length, so it's always overridden by any matching scoped directive.
+    fn directive_level(filters: &[Directive], default: log::LevelFilter, target: &str) -> log::LevelFilter {
+        let mut global_default = default;
+        let mut best: Option<(usize, log::LevelFilter)> = None;
+
+        for directive in filters {
+            match &directive.target {
+                None => global_default = directive.level,
+                Some(prefix) if target.starts_with(prefix.as_str()) => {
+                    let matches_better = match best {
+                        Some((best_len, _)) => prefix.len() >= best_len,
+                        None => true,
+                    };
+                    if matches_better {
+                        best = Some((prefix.len(), directive.level));
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        best.map(|(_, level)| level).unwrap_or(global_default)
+    }
+
     /// Configuration for the RichLogger.
     #[derive(Clone, Debug)]
     pub struct RichLoggerConfig {
@@ -222,6 +589,24 @@ mod log_integration {
         pub enable_time: bool,
         /// Whether to show the file path/location.
         pub enable_path: bool,
+        /// Overrides the default timestamp/level/message/location layout
+        /// entirely; when set, its tokens decide the order, while
+        /// `enable_time = false` still hides its `Time` token and
+        /// `enable_path = false` still leaves its `Location` empty. See
+        /// [`RichLoggerBuilder::format`].
+        pub format: Option<LogFormat>,
+        /// How the `Time` token is formatted. See [`TimeFormat`] and
+        /// [`RichLoggerBuilder::time_format`].
+        pub time_format: TimeFormat,
+        /// Offset, in seconds, applied to the timestamp before formatting.
+        /// See [`RichLoggerBuilder::utc_offset`].
+        pub utc_offset_seconds: i32,
+        /// Whether to show the record's `target` (module path by default).
+        pub show_target: bool,
+        /// Maximum display width for the target column before it's
+        /// truncated to its rightmost `::`-separated segment. See
+        /// [`RichLoggerBuilder::target_width`].
+        pub target_width: Option<usize>,
     }
 
     impl Default for RichLoggerConfig {
@@ -229,13 +614,45 @@ mod log_integration {
             Self {
                 enable_time: true,
                 enable_path: true,
+                format: None,
+                time_format: TimeFormat::default(),
+                utc_offset_seconds: 0,
+                show_target: false,
+                target_width: None,
+            }
+        }
+    }
+
+    impl RichLoggerConfig {
+        /// The effective [`LogFormat`] for a record: `self.format` if set,
+        /// otherwise [`LogFormat::default_layout`] with the `Time`/`Location`
+        /// tokens dropped per `enable_time`/`enable_path`.
+        fn resolve_format(&self) -> LogFormat {
+            if let Some(format) = &self.format {
+                return format.clone();
+            }
+
+            let mut builder = LogFormat::builder();
+            if self.enable_time {
+                builder = builder.time().literal(" ");
             }
+            builder = builder.level().literal(" ");
+            if self.show_target {
+                builder = builder.target().literal(" ");
+            }
+            builder = builder.message();
+            if self.enable_path {
+                builder = builder.literal(" ").location();
+            }
+            builder.build()
         }
     }
 
     /// A log handler that outputs to a rich Console.
     pub struct RichLogger {
         config: RichLoggerConfig,
+        filters: Vec<Directive>,
+        default_level: log::LevelFilter,
     }
 
     impl RichLogger {
@@ -255,6 +672,7 @@ mod log_integration {
     pub struct RichLoggerBuilder {
         config: RichLoggerConfig,
         level: Option<log::LevelFilter>,
+        filters: Vec<Directive>,
     }
 
     impl RichLoggerBuilder {
@@ -270,33 +688,108 @@ mod log_integration {
             self
         }
 
-        /// Set the max log level.
+        /// Override the default timestamp/level/message/location layout
+        /// entirely with a custom [`LogFormat`]. Once set, the format's own
+        /// tokens decide the order; `enable_time(false)` still hides the
+        /// `Time` token and `enable_path(false)` still leaves `Location` empty.
+        pub fn format(mut self, format: LogFormat) -> Self {
+            self.config.format = Some(format);
+            self
+        }
+
+        /// Set how the `Time` token is formatted. See [`TimeFormat`].
+        pub fn time_format(mut self, format: TimeFormat) -> Self {
+            self.config.time_format = format;
+            self
+        }
+
+        /// Set the UTC offset, in seconds, applied to the timestamp before
+        /// formatting (e.g. `3600` for UTC+1), so records can be rendered
+        /// in local time without a timezone database.
+        pub fn utc_offset(mut self, seconds: i32) -> Self {
+            self.config.utc_offset_seconds = seconds;
+            self
+        }
+
+        /// Show each record's `target` (module path by default) in a
+        /// styled column of its own.
+        pub fn show_target(mut self, enable: bool) -> Self {
+            self.config.show_target = enable;
+            self
+        }
+
+        /// Truncate the target column to its rightmost `::`-separated
+        /// segment once it exceeds `width`, keeping the log column
+        /// aligned across lines.
+        pub fn target_width(mut self, width: usize) -> Self {
+            self.config.target_width = Some(width);
+            self
+        }
+
+        /// Set the global default max log level. Overridden per-module by
+        /// any matching directive from [`RichLoggerBuilder::parse_filters`]/
+        /// [`RichLoggerBuilder::parse_default_env`].
         pub fn filter_level(mut self, level: log::LevelFilter) -> Self {
             self.level = Some(level);
             self
         }
 
+        /// Parse an `env_logger`-style directive string (see
+        /// [`parse_filters`]) and use it to filter records by module path,
+        /// e.g. `"warn,fast_rich::table=debug,my_app=trace"`. An empty
+        /// string allows everything through at
+        /// [`RichLoggerBuilder::filter_level`].
+        pub fn parse_filters(mut self, spec: &str) -> Self {
+            self.filters = parse_filters(spec);
+            self
+        }
+
+        /// Read filter directives from the `RUST_LOG` environment variable,
+        /// mirroring `env_logger::Builder::parse_default_env`. Leaves any
+        /// filters already set via [`RichLoggerBuilder::parse_filters`]
+        /// untouched if `RUST_LOG` isn't set.
+        pub fn parse_default_env(mut self) -> Self {
+            if let Ok(spec) = std::env::var("RUST_LOG") {
+                self.filters = parse_filters(&spec);
+            }
+            self
+        }
+
         /// Initialize the logger.
         pub fn init(self) -> Result<(), SetLoggerError> {
              // Initialize global console if not already
             CONSOLE.get_or_init(Console::new);
-            
+
+            let default_level = self.level.unwrap_or(log::LevelFilter::Trace);
+            // The `log` crate's own global max level gate runs before
+            // `enabled()` ever sees the record, so it must be at least as
+            // permissive as the most verbose directive or nothing scoped
+            // to a noisier target than the default would ever get through.
+            let max_level = self
+                .filters
+                .iter()
+                .map(|d| d.level)
+                .fold(default_level, log::LevelFilter::max);
+
             let logger = Box::new(RichLogger {
                 config: self.config,
+                filters: self.filters,
+                default_level,
             });
-            
+
             // We need to leak the logger to satisfy 'static requirement of set_logger
             let static_logger = Box::leak(logger);
 
             log::set_logger(static_logger)?;
-            log::set_max_level(self.level.unwrap_or(log::LevelFilter::Trace));
+            log::set_max_level(max_level);
             Ok(())
         }
     }
 
     impl Log for RichLogger {
-        fn enabled(&self, _metadata: &Metadata) -> bool {
-            true
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            let level = directive_level(&self.filters, self.default_level, metadata.target());
+            metadata.level() <= level
         }
 
         fn log(&self, record: &Record) {
@@ -315,8 +808,20 @@ mod log_integration {
 
             let mut log_msg = LogMessage::new(&format!("{}", record.args()))
                 .level(level)
-                .show_time(self.config.enable_time);
-            
+                .show_time(self.config.enable_time)
+                .target(record.target())
+                .format(self.config.resolve_format())
+                .time_format(self.config.time_format.clone())
+                .utc_offset(self.config.utc_offset_seconds);
+
+            if let Some(width) = self.config.target_width {
+                log_msg = log_msg.target_width(width);
+            }
+
+            for (key, value) in collect_fields(record.key_values()) {
+                log_msg = log_msg.field(key, value);
+            }
+
             if self.config.enable_path {
                 if let Some(file) = record.file_static() {
                     if let Some(line) = record.line() {
@@ -325,28 +830,100 @@ mod log_integration {
                 }
             }
 
-            // Note: Timestamp is handled by LogMessage itself based on creation time, 
-            // but we could suppress it in render if we passed config down.
-            // For now, let's just use what LogMessage does, but maybe we should refactor LogMessage 
-            // to just hold data and let the renderer decide?
-            // Or simpler: We can't easily change LogMessage::render without changing trait signature 
-            // or adding fields.
-            // Let's assume LogMessage::render always renders time if it has it, 
-            // but we want to control it. 
-            // Hack fix: If enable_time is false, we could modify how we construct LogMessage or 
-            // implementation of Renderable for LogMessage needs to know about config.
-            // Since LogMessage is a public struct separate from RichLogger, 
-            // we should probably just make LogMessage configurable or specific to this usage.
-            // 
-            // For this iteration, let's keep LogMessage implementation simple and maybe update it 
-            // to have public fields we can manipulate or rendering options.
-            // But LogMessage implements Renderable directly.
-            
             console.print_renderable(&log_msg);
         }
 
         fn flush(&self) {}
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_parse_filters_splits_bare_level_and_scoped_directives() {
+            let directives = parse_filters("warn,fast_rich::table=debug,my_app=trace");
+            assert_eq!(directives.len(), 3);
+            assert_eq!(directives[0].target, None);
+            assert_eq!(directives[0].level, log::LevelFilter::Warn);
+            assert_eq!(directives[1].target.as_deref(), Some("fast_rich::table"));
+            assert_eq!(directives[1].level, log::LevelFilter::Debug);
+            assert_eq!(directives[2].target.as_deref(), Some("my_app"));
+            assert_eq!(directives[2].level, log::LevelFilter::Trace);
+        }
+
+        #[test]
+        fn test_parse_filters_bare_path_means_show_everything() {
+            let directives = parse_filters("my_app::noisy");
+            assert_eq!(directives.len(), 1);
+            assert_eq!(directives[0].target.as_deref(), Some("my_app::noisy"));
+            assert_eq!(directives[0].level, log::LevelFilter::Trace);
+        }
+
+        #[test]
+        fn test_parse_filters_empty_string_is_empty() {
+            assert!(parse_filters("").is_empty());
+            assert!(parse_filters("  ").is_empty());
+        }
+
+        #[test]
+        fn test_directive_level_picks_longest_matching_prefix() {
+            let directives = parse_filters("warn,fast_rich=info,fast_rich::table=debug");
+            assert_eq!(
+                directive_level(&directives, log::LevelFilter::Error, "fast_rich::table::row"),
+                log::LevelFilter::Debug
+            );
+            assert_eq!(
+                directive_level(&directives, log::LevelFilter::Error, "fast_rich::panel"),
+                log::LevelFilter::Info
+            );
+        }
+
+        #[test]
+        fn test_directive_level_falls_back_to_global_default_on_no_match() {
+            let directives = parse_filters("warn,fast_rich::table=debug");
+            assert_eq!(
+                directive_level(&directives, log::LevelFilter::Error, "some_other_crate"),
+                log::LevelFilter::Warn
+            );
+        }
+
+        #[test]
+        fn test_directive_level_ties_go_to_the_later_directive() {
+            let directives = parse_filters("my_app=warn,my_app=trace");
+            assert_eq!(
+                directive_level(&directives, log::LevelFilter::Error, "my_app::thing"),
+                log::LevelFilter::Trace
+            );
+        }
+
+        #[test]
+        fn test_directive_level_empty_filters_uses_builder_default() {
+            assert_eq!(
+                directive_level(&[], log::LevelFilter::Info, "anything::at::all"),
+                log::LevelFilter::Info
+            );
+        }
+
+        #[test]
+        fn test_field_collector_visit_pair_accumulates_string_pairs() {
+            let mut collector = FieldCollector::default();
+            collector
+                .visit_pair(Key::from_str("status"), Value::from("200"))
+                .unwrap();
+            collector
+                .visit_pair(Key::from_str("method"), Value::from("GET"))
+                .unwrap();
+
+            assert_eq!(
+                collector.fields,
+                vec![
+                    ("status".to_string(), "200".to_string()),
+                    ("method".to_string(), "GET".to_string()),
+                ]
+            );
+        }
+    }
 }
 
 #[cfg(feature = "logging")]
@@ -368,7 +945,7 @@ mod tests {
     #[test]
     fn test_log_message_render() {
         let msg = LogMessage::new("Hello").level(LogLevel::Info);
-        let context = RenderContext { width: 80, height: None };
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
         let segments = msg.render(&context);
 
         assert_eq!(segments.len(), 1);
@@ -376,4 +953,185 @@ mod tests {
         assert!(text.contains("INFO"));
         assert!(text.contains("Hello"));
     }
+
+    #[test]
+    fn test_log_message_custom_format_controls_token_order() {
+        let format = LogFormat::builder()
+            .level()
+            .literal(": ")
+            .target()
+            .literal(" - ")
+            .message()
+            .build();
+        let msg = LogMessage::new("Hello")
+            .level(LogLevel::Warning)
+            .target("my_crate::module")
+            .format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        assert_eq!(segments.len(), 1);
+        let text = segments[0].plain_text();
+        assert_eq!(text, "WARN : my_crate::module - Hello");
+    }
+
+    #[test]
+    fn test_log_message_custom_format_skips_absent_location() {
+        let format = LogFormat::builder().message().literal(" @ ").location().build();
+        let msg = LogMessage::new("no location set").format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        let text = segments[0].plain_text();
+        assert_eq!(text, "no location set @ ");
+    }
+
+    #[test]
+    fn test_log_message_custom_format_includes_location_when_set() {
+        let format = LogFormat::builder().message().literal(" @ ").location().build();
+        let msg = LogMessage::new("with location")
+            .location("src/main.rs", 42)
+            .format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        let text = segments[0].plain_text();
+        assert_eq!(text, "with location @ main.rs:42");
+    }
+
+    #[test]
+    fn test_log_message_default_format_matches_prior_fixed_layout() {
+        let msg = LogMessage::new("Hello")
+            .level(LogLevel::Info)
+            .location("src/main.rs", 7);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+        let text = segments[0].plain_text();
+
+        assert!(text.starts_with('['));
+        assert!(text.contains("INFO "));
+        assert!(text.contains("Hello"));
+        assert!(text.ends_with("main.rs:7"));
+    }
+
+    #[test]
+    fn test_log_message_renders_a_few_fields_as_inline_dim_spans() {
+        let msg = LogMessage::new("request handled")
+            .field("status", "200")
+            .field("method", "GET");
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        assert_eq!(segments.len(), 1);
+        let text = segments[0].plain_text();
+        assert!(text.contains("request handled"));
+        assert!(text.contains("status=200"));
+        assert!(text.contains("method=GET"));
+    }
+
+    #[test]
+    fn test_log_message_with_no_fields_has_no_trailing_spans() {
+        let msg = LogMessage::new("plain").format(LogFormat::builder().message().build());
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].plain_text().trim_end(), "plain");
+    }
+
+    #[test]
+    fn test_log_message_with_many_fields_renders_an_extra_table_segment() {
+        let msg = LogMessage::new("burst")
+            .field("a", "1")
+            .field("b", "2")
+            .field("c", "3")
+            .field("d", "4")
+            .field("e", "5");
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let segments = msg.render(&context);
+
+        assert!(segments.len() > 1);
+        let table_text: String = segments[1..].iter().map(|s| s.plain_text()).collect();
+        assert!(table_text.contains('e'));
+        assert!(table_text.contains('5'));
+    }
+
+    #[test]
+    fn test_civil_from_days_matches_known_epoch_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(civil_from_days(19716), (2023, 12, 25));
+    }
+
+    #[test]
+    fn test_format_time_hms_omits_milliseconds() {
+        let msg = LogMessage::new("x").time_format(TimeFormat::Hms);
+        let time = msg.format_time();
+        assert_eq!(time.len(), 8);
+        assert!(!time.contains('.'));
+    }
+
+    #[test]
+    fn test_format_time_date_time_includes_calendar_date() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut msg = LogMessage::new("x").time_format(TimeFormat::DateTime);
+        msg.time = UNIX_EPOCH + Duration::from_secs(19716 * 86400);
+        assert_eq!(msg.format_time(), "2023-12-25 00:00:00");
+    }
+
+    #[test]
+    fn test_format_time_custom_pattern_substitutes_tokens() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut msg = LogMessage::new("x").time_format(TimeFormat::Custom("%Y/%m/%d %H:%M:%S.%3f".to_string()));
+        msg.time = UNIX_EPOCH + Duration::from_millis(19716 * 86_400_000 + 1_234);
+        assert_eq!(msg.format_time(), "2023/12/25 00:00:01.234");
+    }
+
+    #[test]
+    fn test_format_time_applies_utc_offset() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut msg = LogMessage::new("x")
+            .time_format(TimeFormat::Hms)
+            .utc_offset(3600);
+        msg.time = UNIX_EPOCH + Duration::from_secs(23 * 3600);
+        assert_eq!(msg.format_time(), "00:00:00");
+    }
+
+    #[test]
+    fn test_log_message_renders_target_when_format_includes_it() {
+        let format = LogFormat::builder().target().literal(" ").message().build();
+        let msg = LogMessage::new("hello")
+            .target("my_crate::module")
+            .format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let text = msg.render(&context)[0].plain_text();
+        assert_eq!(text, "my_crate::module hello");
+    }
+
+    #[test]
+    fn test_log_message_truncates_target_to_rightmost_segment_past_width() {
+        let format = LogFormat::builder().target().literal(" ").message().build();
+        let msg = LogMessage::new("hello")
+            .target("my_crate::deeply::nested::module")
+            .target_width(10)
+            .format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let text = msg.render(&context)[0].plain_text();
+        assert_eq!(text, "module hello");
+    }
+
+    #[test]
+    fn test_log_message_keeps_target_under_width_unchanged() {
+        let format = LogFormat::builder().target().literal(" ").message().build();
+        let msg = LogMessage::new("hello")
+            .target("short")
+            .target_width(10)
+            .format(format);
+        let context = RenderContext { width: 80, height: None, direction: Default::default() };
+        let text = msg.render(&context)[0].plain_text();
+        assert_eq!(text, "short hello");
+    }
 }
@@ -12,6 +12,45 @@ use crate::syntax::Syntax;
 use crate::table::Table;
 use crate::text::{Span, Text};
 use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+use std::io::IsTerminal;
+
+/// Whether stdout looks like a terminal that understands OSC 8 hyperlinks,
+/// used to resolve [`LinkMode::Auto`].
+fn osc8_supported() -> bool {
+    std::io::stdout().is_terminal()
+}
+
+/// Wrap the spans at `current_line[start_idx..]` (the already-rendered link
+/// label) in an OSC 8 hyperlink escape sequence pointing at `url`, so the
+/// label becomes clickable while keeping its existing styling.
+fn wrap_osc8(current_line: &mut [Span], start_idx: usize, url: &str) {
+    if start_idx >= current_line.len() {
+        return;
+    }
+    let last_idx = current_line.len() - 1;
+    current_line[start_idx].text = format!("\x1b]8;;{}\x1b\\{}", url, current_line[start_idx].text).into();
+    current_line[last_idx].text = format!("{}\x1b]8;;\x1b\\", current_line[last_idx].text).into();
+}
+
+/// How markdown links and images are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkMode {
+    /// Emit an OSC 8 hyperlink when stdout looks like a terminal that
+    /// supports it, otherwise fall back to `Inline`.
+    #[default]
+    Auto,
+    /// Always wrap the link text in an OSC 8 hyperlink escape sequence
+    /// (`\x1b]8;;URL\x1b\\`...`\x1b]8;;\x1b\\`), making it clickable in
+    /// terminals that support it.
+    Osc8,
+    /// Always append the URL inline as `text (url)`, styled with
+    /// `link_style`, with no escape sequences.
+    Inline,
+    /// Drop the URL entirely; only the link text (or image alt text) is
+    /// shown.
+    Off,
+}
 
 /// Markdown rendering configuration.
 #[derive(Debug, Clone)]
@@ -26,6 +65,8 @@ pub struct MarkdownConfig {
     pub emphasis_style: Style,
     /// Style for strong (bold)
     pub strong_style: Style,
+    /// Style for strikethrough (`~~text~~`)
+    pub strikethrough_style: Style,
     /// Style for links
     pub link_style: Style,
     /// Style for blockquotes
@@ -34,10 +75,17 @@ pub struct MarkdownConfig {
     pub list_bullet_style: Style,
     /// Style for ordered list numbers
     pub list_number_style: Style,
+    /// Style for an unchecked task-list checkbox (`☐`)
+    pub task_unchecked_style: Style,
+    /// Style for a checked task-list checkbox (`☑`) and its item text
+    pub task_checked_style: Style,
     /// Whether to use a panel for code blocks
     pub code_block_panel: bool,
     /// Theme for syntax highlighting
     pub syntax_theme: crate::syntax::Theme,
+    /// How links and images should carry their destination URL into the
+    /// terminal output.
+    pub hyperlinks: LinkMode,
 }
 
 impl Default for MarkdownConfig {
@@ -55,23 +103,56 @@ impl Default for MarkdownConfig {
             ],
             emphasis_style: Style::new().italic(),
             strong_style: Style::new().bold(),
+            strikethrough_style: Style::new().strikethrough(),
             link_style: Style::new().foreground(Color::Blue).underline(),
             quote_style: Style::new().foreground(Color::Magenta), // Distinctive color for quote border
             list_bullet_style: Style::new().foreground(Color::Yellow).bold(),
             list_number_style: Style::new().foreground(Color::Yellow).bold(),
+            task_unchecked_style: Style::new().foreground(Color::Yellow),
+            task_checked_style: Style::new().foreground(Color::Green).dim().strikethrough(),
             code_block_panel: true,
             syntax_theme: crate::syntax::Theme::Monokai,
+            hyperlinks: LinkMode::Auto,
         }
     }
 }
 
+/// Resolve a highlight theme name (case-insensitive) to a built-in
+/// [`crate::syntax::Theme`], falling back to the default theme for any name
+/// that isn't recognized.
+fn resolve_syntax_theme_name(name: &str) -> crate::syntax::Theme {
+    match name.to_lowercase().as_str() {
+        "monokai" => crate::syntax::Theme::Monokai,
+        "base16oceandark" | "base16 ocean dark" => crate::syntax::Theme::Base16OceanDark,
+        "solarizeddark" | "solarized dark" => crate::syntax::Theme::SolarizedDark,
+        _ => crate::syntax::Theme::Monokai,
+    }
+}
+
 /// Rendered markdown content.
+///
+/// `Markdown` implements [`Renderable`] directly against a parsed
+/// [`ParsedBlock`] tree rather than lowering to a [`crate::group::RenderGroup`]
+/// of child renderables. A `RenderGroup` composes its children as a uniform
+/// vertical stack with fixed spacing, which can't express what this renderer
+/// actually needs: block quotes and list items nest arbitrarily deep and
+/// share an `indent`/`quote_depth` that must thread through their children,
+/// a list item's first paragraph line merges onto its bullet rather than
+/// starting a new block, and footnote definitions are collected across the
+/// whole document and rendered once at the end. Code blocks still delegate
+/// to [`crate::syntax::Syntax`] (itself a `Panel` over a highlighted body)
+/// for the per-language highlighting this module would otherwise have to
+/// duplicate.
 #[derive(Debug, Clone)]
 pub struct Markdown {
     /// The markdown source
     source: String,
     /// Configuration
     config: MarkdownConfig,
+    /// Lazily-populated parse of `source`/`config`, reused across repeated
+    /// `render` calls (e.g. on terminal resize) so the pulldown-cmark event
+    /// walk only happens once. Reset whenever `source`/`config` change.
+    parse_cache: std::cell::OnceCell<ParsedMarkdown>,
 }
 
 impl Markdown {
@@ -80,458 +161,824 @@ impl Markdown {
         Markdown {
             source: source.to_string(),
             config: MarkdownConfig::default(),
+            parse_cache: std::cell::OnceCell::new(),
         }
     }
 
     /// Set the rendering configuration.
     pub fn config(mut self, config: MarkdownConfig) -> Self {
         self.config = config;
+        self.parse_cache = std::cell::OnceCell::new();
         self
     }
 
-    /// Parse the markdown and return rendering elements.
-    fn parse_internal(&self) -> Vec<MarkdownElement> {
-        let options = Options::all();
-        let parser = Parser::new_ext(&self.source, options);
-        let mut elements = Vec::new();
-        let mut style_stack: Vec<Style> = Vec::new();
-        let mut in_code_block = false;
-        let mut code_block_content = String::new();
-        let mut code_block_lang = String::new();
-        let mut list_depth = 0;
-        let mut ordered_list_num: Option<u64> = None;
-
-        let mut in_table = false;
-        let mut in_table_head = false;
-        let mut current_table_headers: Vec<String> = Vec::new();
-        let mut current_table_rows: Vec<Vec<String>> = Vec::new();
-        let mut current_row: Vec<String> = Vec::new();
-        let mut current_cell_text = String::new();
+    /// Select the fenced-code-block highlight theme by name (case-insensitive),
+    /// e.g. `.syntax_theme("Base16OceanDark")`. Unknown names fall back to the
+    /// default theme rather than erroring, since a missing theme shouldn't
+    /// break rendering.
+    ///
+    /// This only resolves names to the crate's built-in [`crate::syntax::Theme`]
+    /// variants; loading a custom `SyntaxSet`/`ThemeSet` bundle (e.g. a
+    /// precompiled bat-style asset) is a property of the `syntax` module's own
+    /// syntect wiring and isn't exposed here.
+    pub fn syntax_theme(mut self, name: &str) -> Self {
+        self.config.syntax_theme = resolve_syntax_theme_name(name);
+        self.parse_cache = std::cell::OnceCell::new();
+        self
+    }
+
+    /// Parse the markdown source once and return an owned, reusable
+    /// [`ParsedMarkdown`]. Repeated calls (and `render`) reuse the same
+    /// cached parse until `config`/`syntax_theme` rebuild it, so re-drawing
+    /// the same document (e.g. across a terminal resize) doesn't re-walk
+    /// the pulldown-cmark event stream each time -- only the
+    /// width-dependent layout work in `render` (wrapping, table/syntax
+    /// panels) repeats.
+    pub fn parse(&self) -> ParsedMarkdown {
+        self.parse_cache.get_or_init(|| self.parse_tree()).clone()
+    }
+
+    /// Walk this document's headings in order, returning `(level, text,
+    /// slug)` triples. Slugs are GitHub-style: the heading text lowercased,
+    /// stripped of anything that isn't alphanumeric/whitespace/hyphen, with
+    /// runs of whitespace collapsed to single hyphens, and collisions
+    /// deduplicated by appending `-1`, `-2`, ... (mirroring rustdoc's
+    /// `derive_id`).
+    pub fn heading_slugs(&self) -> Vec<(usize, String, String)> {
+        let mut slug_counts: HashMap<String, usize> = HashMap::new();
+        self.headings()
+            .into_iter()
+            .map(|(level, text)| {
+                let slug = Self::slugify(&text, &mut slug_counts);
+                (level, text, slug)
+            })
+            .collect()
+    }
+
+    /// Build a navigable table of contents from this document's headings,
+    /// indented by level and carrying each heading's slug as `#slug` so an
+    /// intra-document link like `[see](#section)` can be matched back to a
+    /// heading via [`Markdown::heading_slugs`].
+    pub fn table_of_contents(&self) -> Text {
+        let mut spans = Vec::new();
+        for (level, text, slug) in self.heading_slugs() {
+            let indent = "  ".repeat(level);
+            let style = self.config.heading_styles[level.min(5)];
+            spans.push(Span::styled(
+                format!("{}{} #{}\n", indent, text, slug),
+                style,
+            ));
+        }
+        Text::from_spans(spans)
+    }
+
+    /// Scan the source for heading text, without applying any other
+    /// rendering. Used by [`Markdown::heading_slugs`]; kept separate from
+    /// [`Markdown::parse_tree`] because a heading's plain text (for
+    /// slugging) needs to be collected as one string, while the main
+    /// render pass keeps it as separately-styled spans.
+    fn headings(&self) -> Vec<(usize, String)> {
+        let parser = Parser::new_ext(&self.source, Options::all());
+        let mut headings = Vec::new();
+        let mut current_level: Option<usize> = None;
+        let mut current_text = String::new();
 
         for event in parser {
             match event {
-                Event::Start(tag) => match tag {
-                    Tag::Heading { level, .. } => {
-                        let level_idx = match level {
-                            HeadingLevel::H1 => 0,
-                            HeadingLevel::H2 => 1,
-                            HeadingLevel::H3 => 2,
-                            HeadingLevel::H4 => 3,
-                            HeadingLevel::H5 => 4,
-                            HeadingLevel::H6 => 5,
-                        };
-                        style_stack.push(self.config.heading_styles[level_idx]);
-                        elements.push(MarkdownElement::StartHeading(level_idx));
+                Event::Start(Tag::Heading { level, .. }) => {
+                    current_level = Some(match level {
+                        HeadingLevel::H1 => 0,
+                        HeadingLevel::H2 => 1,
+                        HeadingLevel::H3 => 2,
+                        HeadingLevel::H4 => 3,
+                        HeadingLevel::H5 => 4,
+                        HeadingLevel::H6 => 5,
+                    });
+                    current_text.clear();
+                }
+                Event::Text(text) if current_level.is_some() => current_text.push_str(&text),
+                Event::Code(code) if current_level.is_some() => current_text.push_str(&code),
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some(level) = current_level.take() {
+                        headings.push((level, std::mem::take(&mut current_text)));
                     }
-                    Tag::Paragraph => {
-                        if !in_table {
-                            elements.push(MarkdownElement::StartParagraph);
+                }
+                _ => {}
+            }
+        }
+
+        headings
+    }
+
+    /// GitHub-style slug for a single heading's text, deduplicated against
+    /// `counts` (shared across all headings in a document).
+    fn slugify(text: &str, counts: &mut HashMap<String, usize>) -> String {
+        let mut slug = String::with_capacity(text.len());
+        let mut last_was_hyphen = false;
+        for c in text.to_lowercase().chars() {
+            if c.is_alphanumeric() {
+                slug.push(c);
+                last_was_hyphen = false;
+            } else if c.is_whitespace() || c == '-' {
+                if !last_was_hyphen && !slug.is_empty() {
+                    slug.push('-');
+                    last_was_hyphen = true;
+                }
+            }
+        }
+        let slug = slug.trim_end_matches('-').to_string();
+
+        let count = counts.entry(slug.clone()).or_insert(0);
+        let result = if *count == 0 {
+            slug
+        } else {
+            format!("{}-{}", slug, count)
+        };
+        *count += 1;
+        result
+    }
+
+    /// Parse the markdown into a tree of [`ParsedBlock`]s. Unlike the old
+    /// flat element stream, a list item or blockquote owns its nested
+    /// content as a child `Vec<ParsedBlock>`, so the renderer can recurse
+    /// with accumulated indent and blockquote-depth context instead of
+    /// losing track of nesting once a sub-list or nested code block shows
+    /// up.
+    fn parse_tree(&self) -> ParsedMarkdown {
+        let heading_slugs = self.heading_slugs();
+        let mut heading_index = 0;
+        let mut footnote_order: Vec<String> = Vec::new();
+        let mut footnote_defs: HashMap<String, Vec<ParsedBlock>> = HashMap::new();
+        let parser = Parser::new_ext(&self.source, Options::all());
+        let mut iter = parser.peekable();
+        let blocks = self.parse_blocks(
+            &mut iter,
+            &heading_slugs,
+            &mut heading_index,
+            &mut footnote_order,
+            &mut footnote_defs,
+            StopAt::None,
+        );
+        let footnotes = footnote_order
+            .into_iter()
+            .filter_map(|label| {
+                let body = footnote_defs.remove(&label)?;
+                Some((label, body))
+            })
+            .collect();
+        ParsedMarkdown { blocks, footnotes }
+    }
+
+    /// Consume events until a matching end tag for `stop_at` (or, for
+    /// `StopAt::None`, until the iterator is exhausted), recursing into
+    /// `List`/`Item` and `BlockQuote` containers so their contents become
+    /// nested `Vec<ParsedBlock>`s rather than flat siblings.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_blocks<'a, I: Iterator<Item = Event<'a>>>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        heading_slugs: &[(usize, String, String)],
+        heading_index: &mut usize,
+        footnote_order: &mut Vec<String>,
+        footnote_defs: &mut HashMap<String, Vec<ParsedBlock>>,
+        stop_at: StopAt,
+    ) -> Vec<ParsedBlock> {
+        let mut blocks = Vec::new();
+        let is_inline = |event: &Event<'a>| matches!(event,
+            Event::Text(_) | Event::Code(_) | Event::SoftBreak | Event::HardBreak | Event::FootnoteReference(_)
+            | Event::Start(Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. })
+            | Event::End(TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link | TagEnd::Image));
+
+        while let Some(event) = iter.next() {
+            match event {
+                Event::End(tag_end) if stop_at != StopAt::None && tag_end_kind(&tag_end) == stop_at => break,
+                Event::Start(Tag::Heading { level, .. }) => {
+                    let level_idx = heading_level_index(level);
+                    let slug = heading_slugs
+                        .get(*heading_index)
+                        .map(|(_, _, slug)| slug.clone())
+                        .unwrap_or_default();
+                    *heading_index += 1;
+                    let lines = self.parse_inline(
+                        iter,
+                        StopAt::Heading,
+                        self.config.heading_styles[level_idx],
+                        footnote_order,
+                    );
+                    let spans = lines.into_iter().flatten().collect();
+                    blocks.push(ParsedBlock::Heading {
+                        level: level_idx,
+                        slug,
+                        spans,
+                    });
+                }
+                Event::Start(Tag::Paragraph) => {
+                    let lines =
+                        self.parse_inline(iter, StopAt::Paragraph, Style::new(), footnote_order);
+                    blocks.push(ParsedBlock::Paragraph(lines));
+                }
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let language = match kind {
+                        pulldown_cmark::CodeBlockKind::Fenced(lang) => lang.to_string(),
+                        pulldown_cmark::CodeBlockKind::Indented => String::new(),
+                    };
+                    let mut content = String::new();
+                    for event in iter.by_ref() {
+                        match event {
+                            Event::Text(text) => content.push_str(&text),
+                            Event::End(TagEnd::CodeBlock) => break,
+                            _ => {}
                         }
                     }
-                    Tag::Emphasis => style_stack.push(self.config.emphasis_style),
-                    Tag::Strong => style_stack.push(self.config.strong_style),
-                    Tag::CodeBlock(kind) => {
-                        in_code_block = true;
-                        code_block_content.clear();
-                        code_block_lang = match kind {
-                            pulldown_cmark::CodeBlockKind::Fenced(lang) => lang.to_string(),
-                            pulldown_cmark::CodeBlockKind::Indented => String::new(),
-                        };
-                    }
-                    Tag::Link { dest_url, .. } => {
-                        style_stack.push(self.config.link_style);
-                        elements.push(MarkdownElement::StartLink(dest_url.to_string()));
-                    }
-                    Tag::List(start) => {
-                        list_depth += 1;
-                        ordered_list_num = start;
-                    }
-                    Tag::Item => {
-                        let prefix = if let Some(num) = ordered_list_num {
-                            ordered_list_num = Some(num + 1);
-                            format!("{}. ", num)
-                        } else {
-                            "• ".to_string()
-                        };
-                        elements.push(MarkdownElement::ListItem {
-                            depth: list_depth,
-                            prefix,
-                            is_ordered: ordered_list_num.is_some(),
-                        });
-                    }
-                    Tag::BlockQuote(_) => {
-                        style_stack.push(self.config.quote_style);
-                        elements.push(MarkdownElement::StartBlockQuote);
-                    }
-                    Tag::Table(_) => {
-                        in_table = true;
-                        current_table_headers.clear();
-                        current_table_rows.clear();
-                    }
-                    Tag::TableHead => {
-                        in_table_head = true;
-                        current_row.clear();
-                    }
-                    Tag::TableRow => {
-                        current_row.clear();
-                    }
-                    Tag::TableCell => {
-                        current_cell_text.clear();
-                    }
-                    _ => {}
-                },
-                Event::End(tag) => match tag {
-                    TagEnd::Heading(_) => {
-                        style_stack.pop();
-                        elements.push(MarkdownElement::EndHeading);
-                    }
-                    TagEnd::Paragraph => {
-                        if !in_table {
-                            elements.push(MarkdownElement::EndParagraph);
+                    blocks.push(ParsedBlock::CodeBlock { content, language });
+                }
+                Event::Start(Tag::BlockQuote(_)) => {
+                    let children = self.parse_blocks(
+                        iter,
+                        heading_slugs,
+                        heading_index,
+                        footnote_order,
+                        footnote_defs,
+                        StopAt::BlockQuote,
+                    );
+                    blocks.push(ParsedBlock::BlockQuote(children));
+                }
+                Event::Start(Tag::List(ordered_start)) => {
+                    let mut items = Vec::new();
+                    loop {
+                        match iter.next() {
+                            Some(Event::Start(Tag::Item)) => {
+                                let checked = if matches!(iter.peek(), Some(Event::TaskListMarker(_)))
+                                {
+                                    match iter.next() {
+                                        Some(Event::TaskListMarker(checked)) => Some(checked),
+                                        _ => None,
+                                    }
+                                } else {
+                                    None
+                                };
+                                let item_blocks = self.parse_blocks(
+                                    iter,
+                                    heading_slugs,
+                                    heading_index,
+                                    footnote_order,
+                                    footnote_defs,
+                                    StopAt::Item,
+                                );
+                                items.push(ListItem {
+                                    checked,
+                                    blocks: item_blocks,
+                                });
+                            }
+                            Some(Event::End(tag_end)) if tag_end_kind(&tag_end) == StopAt::List => {
+                                break;
+                            }
+                            Some(_) => {}
+                            None => break,
                         }
                     }
-                    TagEnd::Emphasis | TagEnd::Strong => {
-                        style_stack.pop();
-                    }
-                    TagEnd::CodeBlock => {
-                        in_code_block = false;
-                        elements.push(MarkdownElement::CodeBlock {
-                            content: std::mem::take(&mut code_block_content),
-                            language: std::mem::take(&mut code_block_lang),
-                        });
-                    }
-                    TagEnd::Link => {
-                        style_stack.pop();
-                        elements.push(MarkdownElement::EndLink);
-                    }
-                    TagEnd::List(_) => {
-                        list_depth -= 1;
-                        ordered_list_num = None;
-                    }
-                    TagEnd::Item => {}
-                    TagEnd::BlockQuote(_) => {
-                        style_stack.pop();
-                        elements.push(MarkdownElement::EndBlockQuote);
-                    }
-                    TagEnd::Table => {
-                        in_table = false;
-                        elements.push(MarkdownElement::Table {
-                            headers: std::mem::take(&mut current_table_headers),
-                            rows: std::mem::take(&mut current_table_rows),
-                        });
-                    }
-                    TagEnd::TableHead => {
-                        in_table_head = false;
-                        current_table_headers = std::mem::take(&mut current_row);
-                    }
-                    TagEnd::TableRow => {
-                        if in_table_head {
-                            // Should not happen with current pulldown-cmark
-                            current_table_headers = std::mem::take(&mut current_row);
-                        } else {
-                            current_table_rows.push(std::mem::take(&mut current_row));
-                        }
+                    blocks.push(ParsedBlock::List {
+                        items,
+                        ordered_start,
+                    });
+                }
+                Event::Start(Tag::Table(_)) => {
+                    blocks.push(self.parse_table(iter));
+                }
+                Event::Start(Tag::FootnoteDefinition(label)) => {
+                    let body = self.parse_blocks(
+                        iter,
+                        heading_slugs,
+                        heading_index,
+                        footnote_order,
+                        footnote_defs,
+                        StopAt::FootnoteDefinition,
+                    );
+                    footnote_defs.insert(label.to_string(), body);
+                }
+                Event::Rule => blocks.push(ParsedBlock::Rule),
+                // Tight list items carry their inline content without a
+                // Paragraph wrapper, so gather that run into one here.
+                event if is_inline(&event) => {
+                    let mut inline = std::iter::once(event)
+                        .chain(std::iter::from_fn(|| iter.next_if(is_inline)))
+                        .peekable();
+                    let lines =
+                        self.parse_inline(&mut inline, StopAt::Paragraph, Style::new(), footnote_order);
+                    blocks.push(ParsedBlock::Paragraph(lines));
+                }
+                _ => {}
+            }
+        }
+
+        blocks
+    }
+
+    /// Consume a `Tag::Table` body (already past its `Start` event) into a
+    /// flat `headers`/`rows` table -- tables don't nest, so unlike
+    /// lists/blockquotes this stays a leaf block with plain cell text.
+    fn parse_table<'a, I: Iterator<Item = Event<'a>>>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+    ) -> ParsedBlock {
+        let mut headers = Vec::new();
+        let mut rows = Vec::new();
+        let mut in_head = false;
+        let mut current_row: Vec<String> = Vec::new();
+        let mut current_cell = String::new();
+
+        for event in iter.by_ref() {
+            match event {
+                Event::Start(Tag::TableHead) => {
+                    in_head = true;
+                    current_row.clear();
+                }
+                Event::Start(Tag::TableRow) => current_row.clear(),
+                Event::Start(Tag::TableCell) => current_cell.clear(),
+                Event::Text(text) => current_cell.push_str(&text),
+                Event::Code(code) => {
+                    current_cell.push('`');
+                    current_cell.push_str(&code);
+                    current_cell.push('`');
+                }
+                Event::SoftBreak => current_cell.push(' '),
+                Event::HardBreak => current_cell.push('\n'),
+                Event::End(TagEnd::TableCell) => {
+                    current_row.push(std::mem::take(&mut current_cell));
+                }
+                Event::End(TagEnd::TableHead) => {
+                    in_head = false;
+                    headers = std::mem::take(&mut current_row);
+                }
+                Event::End(TagEnd::TableRow) => {
+                    if in_head {
+                        // Should not happen with current pulldown-cmark
+                        headers = std::mem::take(&mut current_row);
+                    } else {
+                        rows.push(std::mem::take(&mut current_row));
                     }
-                    TagEnd::TableCell => {
-                        current_row.push(std::mem::take(&mut current_cell_text));
+                }
+                Event::End(TagEnd::Table) => break,
+                _ => {}
+            }
+        }
+
+        ParsedBlock::Table { headers, rows }
+    }
+
+    /// Accumulate inline content (text, emphasis/strong, links, images,
+    /// inline code, soft/hard breaks) until a matching end tag for
+    /// `stop_at`. A hard break starts a new line rather than a new block,
+    /// which is why this returns lines instead of one flat span list.
+    fn parse_inline<'a, I: Iterator<Item = Event<'a>>>(
+        &self,
+        iter: &mut std::iter::Peekable<I>,
+        stop_at: StopAt,
+        base_style: Style,
+        footnote_order: &mut Vec<String>,
+    ) -> Vec<Vec<Span>> {
+        let mut lines: Vec<Vec<Span>> = vec![Vec::new()];
+        let mut style_stack: Vec<Style> = vec![base_style];
+        let mut link_stack: Vec<(String, usize)> = Vec::new();
+        let mut in_image = false;
+        let mut image_alt = String::new();
+        let mut image_url = String::new();
+
+        while let Some(event) = iter.next() {
+            let current_line = lines.last_mut().expect("lines always has at least one entry");
+            match event {
+                Event::End(tag_end) if tag_end_kind(&tag_end) == stop_at => break,
+                Event::Start(Tag::Emphasis) => style_stack.push(self.config.emphasis_style),
+                Event::End(TagEnd::Emphasis) => {
+                    style_stack.pop();
+                }
+                Event::Start(Tag::Strong) => style_stack.push(self.config.strong_style),
+                Event::End(TagEnd::Strong) => {
+                    style_stack.pop();
+                }
+                Event::Start(Tag::Strikethrough) => {
+                    style_stack.push(self.config.strikethrough_style)
+                }
+                Event::End(TagEnd::Strikethrough) => {
+                    style_stack.pop();
+                }
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    style_stack.push(self.config.link_style);
+                    link_stack.push((dest_url.to_string(), current_line.len()));
+                }
+                Event::End(TagEnd::Link) => {
+                    style_stack.pop();
+                    if let Some((url, start_idx)) = link_stack.pop() {
+                        self.apply_link(current_line, start_idx, &url);
                     }
-                    _ => {}
-                },
+                }
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    in_image = true;
+                    image_alt.clear();
+                    image_url = dest_url.to_string();
+                }
+                Event::End(TagEnd::Image) => {
+                    in_image = false;
+                    let start_idx = current_line.len();
+                    let label = if image_alt.is_empty() {
+                        image_url.clone()
+                    } else {
+                        image_alt.clone()
+                    };
+                    current_line.push(Span::styled(label, self.config.link_style));
+                    self.apply_link(current_line, start_idx, &image_url);
+                }
                 Event::Text(text) => {
-                    if in_code_block {
-                        code_block_content.push_str(&text);
-                    } else if in_table {
-                        current_cell_text.push_str(&text);
+                    if in_image {
+                        image_alt.push_str(&text);
                     } else {
                         let style = style_stack
                             .iter()
                             .fold(Style::new(), |acc, s| acc.combine(s));
-                        elements.push(MarkdownElement::Text(text.to_string(), style));
+                        current_line.push(Span::styled(text.to_string(), style));
                     }
                 }
                 Event::Code(code) => {
-                    if in_table {
-                        current_cell_text.push('`');
-                        current_cell_text.push_str(&code);
-                        current_cell_text.push('`');
-                    } else {
-                        elements.push(MarkdownElement::InlineCode(code.to_string()));
-                    }
-                }
-                Event::SoftBreak => {
-                    if in_table {
-                        current_cell_text.push(' ');
-                    } else {
-                        elements.push(MarkdownElement::SoftBreak);
-                    }
+                    current_line.push(Span::styled(
+                        format!(" {} ", code),
+                        self.config
+                            .inline_code_style
+                            .background(Color::rgb(60, 60, 60)),
+                    ));
                 }
-                Event::HardBreak => {
-                    if in_table {
-                        current_cell_text.push('\n');
-                    } else {
-                        elements.push(MarkdownElement::HardBreak);
+                Event::SoftBreak => current_line.push(Span::raw(" ")),
+                Event::HardBreak => lines.push(Vec::new()),
+                Event::FootnoteReference(label) => {
+                    let label = label.to_string();
+                    if !footnote_order.contains(&label) {
+                        footnote_order.push(label.clone());
                     }
-                }
-                Event::Rule => {
-                    elements.push(MarkdownElement::HorizontalRule);
+                    let number = footnote_order.iter().position(|l| *l == label).unwrap() + 1;
+                    current_line.push(Span::styled(
+                        format!("[^{}]", number),
+                        self.config.link_style,
+                    ));
                 }
                 _ => {}
             }
         }
-        elements
+
+        lines
     }
 }
 
-/// Internal markdown element for rendering.
+/// An owned, parsed markdown document returned by [`Markdown::parse`] and
+/// reused by `render` across repeated calls on the same `Markdown`
+/// instead of re-walking pulldown-cmark's event stream every time.
+#[derive(Debug, Clone)]
+pub struct ParsedMarkdown {
+    blocks: Vec<ParsedBlock>,
+    /// Footnote definitions, in the order their reference was first seen
+    /// in the main document -- that order is also each footnote's number.
+    footnotes: Vec<(String, Vec<ParsedBlock>)>,
+}
+
+/// A block-level markdown node. List items and blockquotes own their
+/// nested content as a child `Vec<ParsedBlock>`, so the renderer can
+/// recurse with accumulated indent + blockquote-depth context and get
+/// nested code blocks/tables/sub-lists indented and quote-marked
+/// correctly at arbitrary depth.
 #[derive(Debug, Clone)]
-enum MarkdownElement {
-    StartHeading(usize),
-    EndHeading,
-    StartParagraph,
-    EndParagraph,
-    Text(String, Style),
-    InlineCode(String),
+enum ParsedBlock {
+    Heading {
+        level: usize,
+        slug: String,
+        spans: Vec<Span>,
+    },
+    Paragraph(Vec<Vec<Span>>),
+    List {
+        items: Vec<ListItem>,
+        ordered_start: Option<u64>,
+    },
+    BlockQuote(Vec<ParsedBlock>),
     CodeBlock {
         content: String,
         language: String,
     },
-    StartLink(String),
-    EndLink,
-    ListItem {
-        depth: usize,
-        prefix: String,
-        is_ordered: bool,
-    },
-    StartBlockQuote,
-    EndBlockQuote,
-    SoftBreak,
-    HardBreak,
-    HorizontalRule,
     Table {
         headers: Vec<String>,
         rows: Vec<Vec<String>>,
     },
+    Rule,
+}
+
+/// A single list item: its own child blocks, plus an optional GFM
+/// task-list checkbox state (`- [ ]`/`- [x]`).
+#[derive(Debug, Clone)]
+struct ListItem {
+    checked: Option<bool>,
+    blocks: Vec<ParsedBlock>,
+}
+
+/// Which container a `parse_blocks`/`parse_inline` call should stop at,
+/// compared by variant only (not by the end tag's embedded data) so we
+/// don't need `PartialEq` on pulldown-cmark's own tag types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopAt {
+    None,
+    Heading,
+    Paragraph,
+    BlockQuote,
+    Item,
+    List,
+    FootnoteDefinition,
+}
+
+fn tag_end_kind(tag_end: &TagEnd) -> StopAt {
+    match tag_end {
+        TagEnd::Heading(_) => StopAt::Heading,
+        TagEnd::Paragraph => StopAt::Paragraph,
+        TagEnd::BlockQuote(_) => StopAt::BlockQuote,
+        TagEnd::Item => StopAt::Item,
+        TagEnd::List(_) => StopAt::List,
+        TagEnd::FootnoteDefinition => StopAt::FootnoteDefinition,
+        _ => StopAt::None,
+    }
+}
+
+fn heading_level_index(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 0,
+        HeadingLevel::H2 => 1,
+        HeadingLevel::H3 => 2,
+        HeadingLevel::H4 => 3,
+        HeadingLevel::H5 => 4,
+        HeadingLevel::H6 => 5,
+    }
 }
 
 impl Renderable for Markdown {
     fn render(&self, context: &RenderContext) -> Vec<Segment> {
         let mut segments = Vec::new();
-        let mut current_line: Vec<Span> = Vec::new();
-        let mut _in_heading = false;
-        let mut heading_level = 0;
-        let mut blockquote_depth = 0;
-
-        for element in self.parse_internal() {
-            // Pre-process for blockquotes
-            match element {
-                MarkdownElement::StartHeading(level) => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-                    _in_heading = true;
-                    heading_level = level;
+        let parsed = self.parse_cache.get_or_init(|| self.parse_tree());
+        self.render_blocks(&parsed.blocks, &mut segments, context, 0, 0);
+        self.render_footnotes(&parsed.footnotes, &mut segments, context);
+        segments
+    }
+}
+
+impl Markdown {
+    /// Recursively render `blocks` into `segments`, threading `indent`
+    /// (list nesting, in "bullet columns") and `quote_depth` (blockquote
+    /// nesting) down to every child block so nested content lines up
+    /// under its enclosing list item/blockquote no matter how deep.
+    fn render_blocks(
+        &self,
+        blocks: &[ParsedBlock],
+        segments: &mut Vec<Segment>,
+        context: &RenderContext,
+        indent: usize,
+        quote_depth: usize,
+    ) {
+        for block in blocks {
+            match block {
+                ParsedBlock::Heading { level, slug, spans } => {
+                    let mut line = self.render_prefix(indent, quote_depth);
                     let prefix = "#".repeat(level + 1) + " ";
-                    current_line.push(Span::styled(prefix, self.config.heading_styles[level]));
-                }
-                MarkdownElement::EndHeading => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-                    // H1 and H2 get underlines
-                    let underline_char = if heading_level == 0 {
+                    let mut prefix_span = Span::styled(prefix, self.config.heading_styles[*level]);
+                    // Not wrapped in an OSC 8 escape here -- terminals have
+                    // no notion of a jump target -- but the slug travels on
+                    // the span so a consumer (e.g. an HTML exporter) can
+                    // turn it into a real anchor.
+                    prefix_span.link = Some(format!("#{}", slug));
+                    line.push(prefix_span);
+                    line.extend(spans.clone());
+                    segments.push(Segment::line(line));
+
+                    let underline_char = if *level == 0 {
                         Some("═")
-                    } else if heading_level == 1 {
+                    } else if *level == 1 {
                         Some("─")
                     } else {
                         None
                     };
-
-                    if let Some(char) = underline_char {
-                        let width = if heading_level == 0 {
+                    if let Some(ch) = underline_char {
+                        let width = if *level == 0 {
                             context.width.min(60)
                         } else {
                             context.width.min(40)
                         };
-                        let style = self.config.heading_styles[heading_level];
-                        let mut underline = Vec::new();
-                        underline.push(Span::styled(char.repeat(width), style));
-                        self.flush_line(&mut segments, &mut underline, blockquote_depth);
+                        let mut underline = self.render_prefix(indent, quote_depth);
+                        underline.push(Span::styled(ch.repeat(width), self.config.heading_styles[*level]));
+                        segments.push(Segment::line(underline));
                     }
-
-                    _in_heading = false;
-                    self.add_blank_line(&mut segments, blockquote_depth);
+                    self.blank_line(segments, quote_depth);
                 }
-                MarkdownElement::StartParagraph => {}
-                MarkdownElement::EndParagraph => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
+                ParsedBlock::Paragraph(lines) => {
+                    for spans in lines {
+                        let mut line = self.render_prefix(indent, quote_depth);
+                        line.extend(spans.clone());
+                        segments.push(Segment::line(line));
                     }
-                    self.add_blank_line(&mut segments, blockquote_depth);
-                }
-                MarkdownElement::Text(text, style) => {
-                    current_line.push(Span::styled(text, style));
+                    self.blank_line(segments, quote_depth);
                 }
-                MarkdownElement::InlineCode(code) => {
-                    current_line.push(Span::styled(
-                        format!(" {} ", code),
-                        self.config
-                            .inline_code_style
-                            .background(Color::rgb(60, 60, 60)),
-                    ));
-                }
-                MarkdownElement::CodeBlock { content, language } => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-
-                    let syntax = Syntax::new(&content, &language)
-                        .theme(self.config.syntax_theme)
-                        .panel(self.config.code_block_panel)
-                        .line_numbers(true);
-
-                    let syntax_segments = syntax.render(context);
+                ParsedBlock::List {
+                    items,
+                    ordered_start,
+                } => {
+                    let mut number = *ordered_start;
+                    for item in items {
+                        let mut line = self.render_prefix(indent, quote_depth);
 
-                    if blockquote_depth > 0 {
-                        for segment in syntax_segments {
-                            let mut new_spans = vec![self.get_quote_marker(blockquote_depth)];
-                            new_spans.extend(segment.spans);
-                            segments.push(Segment::line(new_spans));
+                        if let Some(is_checked) = item.checked {
+                            let (glyph, style) = if is_checked {
+                                ("☑ ", self.config.task_checked_style)
+                            } else {
+                                ("☐ ", self.config.task_unchecked_style)
+                            };
+                            line.push(Span::styled(glyph, style));
+                        } else if let Some(num) = number {
+                            number = Some(num + 1);
+                            line.push(Span::styled(
+                                format!("{}. ", num),
+                                self.config.list_number_style,
+                            ));
+                        } else {
+                            line.push(Span::styled("• ", self.config.list_bullet_style));
                         }
-                    } else {
-                        segments.extend(syntax_segments);
-                    }
 
-                    self.add_blank_line(&mut segments, blockquote_depth);
-                }
-                MarkdownElement::StartLink(_url) => {}
-                MarkdownElement::EndLink => {}
-                MarkdownElement::ListItem {
-                    depth,
-                    prefix,
-                    is_ordered,
-                } => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-                    let indent = "  ".repeat(depth.saturating_sub(1));
-                    let style = if is_ordered {
-                        self.config.list_number_style
-                    } else {
-                        self.config.list_bullet_style
-                    };
+                        // The item's first paragraph sits beside its bullet on
+                        // this same line; anything after that (a nested list,
+                        // a code block, further paragraphs) recurses indented
+                        // underneath it.
+                        let mut rest = item.blocks.as_slice();
+                        if let Some(ParsedBlock::Paragraph(lines)) = rest.first() {
+                            if let Some(first_line) = lines.first() {
+                                line.extend(first_line.clone());
+                            }
+                            segments.push(Segment::line(line));
+                            for extra_line in lines.iter().skip(1) {
+                                let mut cont = self.render_prefix(indent + 1, quote_depth);
+                                cont.extend(extra_line.clone());
+                                segments.push(Segment::line(cont));
+                            }
+                            rest = &rest[1..];
+                        } else {
+                            segments.push(Segment::line(line));
+                        }
 
-                    current_line.push(Span::raw(indent));
-                    current_line.push(Span::styled(prefix, style));
-                }
-                MarkdownElement::StartBlockQuote => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-                    blockquote_depth += 1;
-                }
-                MarkdownElement::EndBlockQuote => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
+                        if !rest.is_empty() {
+                            self.render_blocks(rest, segments, context, indent + 1, quote_depth);
+                        }
                     }
-                    blockquote_depth -= 1;
-                    self.add_blank_line(&mut segments, blockquote_depth);
-                }
-                MarkdownElement::SoftBreak => {
-                    current_line.push(Span::raw(" "));
+                    self.blank_line(segments, quote_depth);
                 }
-                MarkdownElement::HardBreak => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
+                ParsedBlock::BlockQuote(children) => {
+                    self.render_blocks(children, segments, context, indent, quote_depth + 1);
+                    self.blank_line(segments, quote_depth);
                 }
-                MarkdownElement::HorizontalRule => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-                    let rule = Rule::line().style(Style::new().foreground(Color::Yellow));
-                    let rule_segments = rule.render(context);
-                    if blockquote_depth > 0 {
-                        for segment in rule_segments {
-                            let mut new_spans = vec![self.get_quote_marker(blockquote_depth)];
-                            new_spans.extend(segment.spans);
-                            segments.push(Segment::line(new_spans));
-                        }
-                    } else {
-                        segments.extend(rule_segments);
+                ParsedBlock::CodeBlock { content, language } => {
+                    let syntax = Syntax::new(content, language)
+                        .theme(self.config.syntax_theme.clone())
+                        .panel(self.config.code_block_panel)
+                        .line_numbers(true);
+                    for segment in syntax.render(context) {
+                        let mut line = self.render_prefix(indent, quote_depth);
+                        line.extend(segment.spans);
+                        segments.push(Segment::line(line));
                     }
-                    self.add_blank_line(&mut segments, blockquote_depth);
+                    self.blank_line(segments, quote_depth);
                 }
-                MarkdownElement::Table { headers, rows } => {
-                    if !current_line.is_empty() {
-                        self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-                    }
-
+                ParsedBlock::Table { headers, rows } => {
                     let mut table = Table::new();
                     for header in headers {
                         table.add_column(
-                            crate::table::Column::new(&header)
+                            crate::table::Column::new(header)
                                 .header_style(Style::new().bold().foreground(Color::Cyan)),
                         );
                     }
-
                     for row in rows {
-                        let cells: Vec<Text> = row.into_iter().map(Text::plain).collect();
+                        let cells: Vec<Text> = row.iter().cloned().map(Text::plain).collect();
                         table.add_row(cells);
                     }
-
                     table = table.border_style(BorderStyle::Rounded);
-
-                    let table_segments = table.render(context);
-
-                    if blockquote_depth > 0 {
-                        for segment in table_segments {
-                            let mut new_spans = vec![self.get_quote_marker(blockquote_depth)];
-                            new_spans.extend(segment.spans);
-                            segments.push(Segment::line(new_spans));
-                        }
-                    } else {
-                        segments.extend(table_segments);
+                    for segment in table.render(context) {
+                        let mut line = self.render_prefix(indent, quote_depth);
+                        line.extend(segment.spans);
+                        segments.push(Segment::line(line));
+                    }
+                    self.blank_line(segments, quote_depth);
+                }
+                ParsedBlock::Rule => {
+                    let rule = Rule::line().style(Style::new().foreground(Color::Yellow));
+                    for segment in rule.render(context) {
+                        let mut line = self.render_prefix(indent, quote_depth);
+                        line.extend(segment.spans);
+                        segments.push(Segment::line(line));
                     }
-                    self.add_blank_line(&mut segments, blockquote_depth);
+                    self.blank_line(segments, quote_depth);
                 }
             }
         }
-
-        if !current_line.is_empty() {
-            self.flush_line(&mut segments, &mut current_line, blockquote_depth);
-        }
-
-        segments
     }
-}
 
-impl Markdown {
-    fn flush_line(
-        &self,
-        segments: &mut Vec<Segment>,
-        current_line: &mut Vec<Span>,
-        quote_depth: usize,
-    ) {
+    /// Leading spans for a content line at the given list indent and
+    /// blockquote depth: one quote marker per blockquote level, then two
+    /// spaces per list-nesting level.
+    fn render_prefix(&self, indent: usize, quote_depth: usize) -> Vec<Span> {
         let mut spans = Vec::new();
         if quote_depth > 0 {
-            spans.push(self.get_quote_marker(quote_depth));
-            spans.push(Span::raw(" ")); // Space after marker
+            for _ in 0..quote_depth {
+                spans.push(self.get_quote_marker());
+            }
+            spans.push(Span::raw(" "));
         }
-        spans.append(current_line);
-        segments.push(Segment::line(spans));
+        if indent > 0 {
+            spans.push(Span::raw("  ".repeat(indent)));
+        }
+        spans
     }
 
-    fn add_blank_line(&self, segments: &mut Vec<Segment>, quote_depth: usize) {
+    fn blank_line(&self, segments: &mut Vec<Segment>, quote_depth: usize) {
         let mut spans = Vec::new();
-        if quote_depth > 0 {
-            spans.push(self.get_quote_marker(quote_depth));
+        for _ in 0..quote_depth {
+            spans.push(self.get_quote_marker());
         }
         segments.push(Segment::line(spans));
     }
 
-    fn get_quote_marker(&self, _depth: usize) -> Span {
+    fn get_quote_marker(&self) -> Span {
         Span::styled("▎", self.config.quote_style)
     }
+
+    /// Render the accumulated footnote definitions as a rule followed by a
+    /// numbered block (`[^1]: ...`), numbered in the order their
+    /// `[^label]` reference was first seen in the main document. A no-op
+    /// when the document has no footnotes.
+    fn render_footnotes(
+        &self,
+        footnotes: &[(String, Vec<ParsedBlock>)],
+        segments: &mut Vec<Segment>,
+        context: &RenderContext,
+    ) {
+        if footnotes.is_empty() {
+            return;
+        }
+
+        let rule = Rule::line().style(Style::new().foreground(Color::Yellow));
+        segments.extend(rule.render(context));
+        self.blank_line(segments, 0);
+
+        for (number, (_, body)) in footnotes.iter().enumerate() {
+            let number = number + 1;
+            let mut line = vec![Span::styled(
+                format!("[^{}]: ", number),
+                self.config.link_style,
+            )];
+
+            let mut rest = body.as_slice();
+            if let Some(ParsedBlock::Paragraph(lines)) = rest.first() {
+                if let Some(first_line) = lines.first() {
+                    line.extend(first_line.clone());
+                }
+                segments.push(Segment::line(line));
+                for extra_line in lines.iter().skip(1) {
+                    let mut cont = self.render_prefix(1, 0);
+                    cont.extend(extra_line.clone());
+                    segments.push(Segment::line(cont));
+                }
+                rest = &rest[1..];
+            } else {
+                segments.push(Segment::line(line));
+            }
+
+            if !rest.is_empty() {
+                self.render_blocks(rest, segments, context, 1, 0);
+            }
+        }
+    }
+
+    /// Carry `url` into `current_line[start_idx..]` (the already-rendered
+    /// label, which may be a link's anchor text or an image's alt text)
+    /// according to `self.config.hyperlinks`: tag each label span with the
+    /// link destination, then either wrap the label in an OSC 8 escape
+    /// sequence or append the URL inline in parentheses.
+    fn apply_link(&self, current_line: &mut Vec<Span>, start_idx: usize, url: &str) {
+        for span in current_line[start_idx.min(current_line.len())..].iter_mut() {
+            span.link = Some(url.to_string());
+        }
+
+        match self.config.hyperlinks {
+            LinkMode::Off => {}
+            LinkMode::Osc8 => wrap_osc8(current_line, start_idx, url),
+            LinkMode::Inline => {
+                current_line.push(Span::styled(format!(" ({})", url), self.config.link_style));
+            }
+            LinkMode::Auto => {
+                if osc8_supported() {
+                    wrap_osc8(current_line, start_idx, url);
+                } else {
+                    current_line
+                        .push(Span::styled(format!(" ({})", url), self.config.link_style));
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -544,6 +991,7 @@ mod tests {
         let context = RenderContext {
             width: 40,
             height: None,
+            direction: Default::default(),
         };
         let segments = md.render(&context);
         assert!(!segments.is_empty());
@@ -555,6 +1003,7 @@ mod tests {
         let context = RenderContext {
             width: 40,
             height: None,
+            direction: Default::default(),
         };
         let segments = md.render(&context);
         assert!(!segments.is_empty());
@@ -566,8 +1015,313 @@ mod tests {
         let context = RenderContext {
             width: 40,
             height: None,
+            direction: Default::default(),
         };
         let segments = md.render(&context);
         assert!(!segments.is_empty());
     }
+
+    #[test]
+    fn test_task_list_renders_checkbox_glyphs() {
+        let md = Markdown::new("- [ ] Todo\n- [x] Done");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains('☐'));
+        assert!(text.contains('☑'));
+    }
+
+    #[test]
+    fn test_link_osc8_mode_wraps_label_in_escape_sequence() {
+        let md = Markdown::new("[Rust](https://rust-lang.org)").config(MarkdownConfig {
+            hyperlinks: LinkMode::Osc8,
+            ..MarkdownConfig::default()
+        });
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains("\x1b]8;;https://rust-lang.org\x1b\\Rust"));
+        assert!(text.contains("\x1b]8;;\x1b\\"));
+    }
+
+    #[test]
+    fn test_link_inline_mode_appends_url_in_parens() {
+        let md = Markdown::new("[Rust](https://rust-lang.org)").config(MarkdownConfig {
+            hyperlinks: LinkMode::Inline,
+            ..MarkdownConfig::default()
+        });
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains("Rust (https://rust-lang.org)"));
+    }
+
+    #[test]
+    fn test_link_off_mode_drops_url() {
+        let md = Markdown::new("[Rust](https://rust-lang.org)").config(MarkdownConfig {
+            hyperlinks: LinkMode::Off,
+            ..MarkdownConfig::default()
+        });
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert_eq!(text.trim(), "Rust");
+    }
+
+    #[test]
+    fn test_image_renders_alt_text_as_hyperlink() {
+        let md = Markdown::new("![A cat](https://example.com/cat.png)").config(MarkdownConfig {
+            hyperlinks: LinkMode::Osc8,
+            ..MarkdownConfig::default()
+        });
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains("A cat"));
+        assert!(text.contains("https://example.com/cat.png"));
+    }
+
+    #[test]
+    fn test_heading_slugs_are_lowercased_and_hyphenated() {
+        let md = Markdown::new("# Hello World!\n## Another Section");
+        let slugs = md.heading_slugs();
+        assert_eq!(
+            slugs,
+            vec![
+                (0, "Hello World!".to_string(), "hello-world".to_string()),
+                (1, "Another Section".to_string(), "another-section".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading_slugs_deduplicate_collisions() {
+        let md = Markdown::new("# Intro\n# Intro\n# Intro");
+        let slugs: Vec<String> = md.heading_slugs().into_iter().map(|(_, _, s)| s).collect();
+        assert_eq!(slugs, vec!["intro", "intro-1", "intro-2"]);
+    }
+
+    #[test]
+    fn test_table_of_contents_indents_by_level_and_embeds_slug() {
+        let md = Markdown::new("# Top\n## Child");
+        let toc = md.table_of_contents();
+        let plain = toc.plain_text();
+        assert!(plain.contains("Top #top"));
+        assert!(plain.contains("  Child #child"));
+    }
+
+    #[test]
+    fn test_syntax_theme_resolves_known_name_case_insensitively() {
+        let md = Markdown::new("").syntax_theme("base16oceandark");
+        assert_eq!(
+            format!("{:?}", md.config.syntax_theme),
+            format!("{:?}", crate::syntax::Theme::Base16OceanDark)
+        );
+    }
+
+    #[test]
+    fn test_syntax_theme_falls_back_to_default_for_unknown_name() {
+        let md = Markdown::new("").syntax_theme("not-a-real-theme");
+        assert_eq!(
+            format!("{:?}", md.config.syntax_theme),
+            format!("{:?}", MarkdownConfig::default().syntax_theme)
+        );
+    }
+
+    #[test]
+    fn test_code_block_nested_in_list_item_is_indented() {
+        let md = Markdown::new("- Item\n\n  ```\n  code line\n  ```\n");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let lines: Vec<String> = segments
+            .iter()
+            .map(|s| s.spans.iter().map(|span| span.text.as_str()).collect::<String>())
+            .collect();
+        let code_line = lines
+            .iter()
+            .find(|line| line.contains("code line"))
+            .expect("nested code block should render");
+        assert!(code_line.starts_with("  "), "expected list indent before nested code block, got {:?}", code_line);
+    }
+
+    #[test]
+    fn test_sub_list_nested_in_blockquote_keeps_quote_marker() {
+        let md = Markdown::new("> - one\n> - two\n");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains('▎'));
+        assert!(text.contains("one"));
+        assert!(text.contains("two"));
+    }
+
+    #[test]
+    fn test_nested_list_indents_beyond_parent_bullet() {
+        let md = Markdown::new("- parent\n  - child\n");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let lines: Vec<String> = segments
+            .iter()
+            .map(|s| s.spans.iter().map(|span| span.text.as_str()).collect::<String>())
+            .collect();
+        let child_line = lines
+            .iter()
+            .find(|line| line.contains("child"))
+            .expect("nested list item should render");
+        assert!(child_line.starts_with("  "), "expected indent before nested bullet, got {:?}", child_line);
+    }
+
+    #[test]
+    fn test_render_reuses_cached_parse_across_calls() {
+        let md = Markdown::new("# Title\n\nSome body text.");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let first: String = md
+            .render(&context)
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        let second: String = md
+            .render(&context)
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert_eq!(first, second);
+        assert!(md.parse_cache.get().is_some());
+    }
+
+    #[test]
+    fn test_config_rebuild_invalidates_cached_parse() {
+        let md = Markdown::new("# Title").config(MarkdownConfig::default());
+        // Populate the cache once, then rebuild the config -- the cache
+        // should be cleared rather than serving a parse built with the
+        // prior style set.
+        let _ = md.parse();
+        assert!(md.parse_cache.get().is_some());
+        let md = md.config(MarkdownConfig {
+            heading_styles: [Style::new().foreground(Color::Red); 6],
+            ..MarkdownConfig::default()
+        });
+        assert!(md.parse_cache.get().is_none());
+    }
+
+    #[test]
+    fn test_strikethrough_applies_strikethrough_style() {
+        let md = Markdown::new("~~gone~~");
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let span = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .find(|span| span.text.as_str().contains("gone"))
+            .expect("strikethrough text should render");
+        assert_eq!(span.style, MarkdownConfig::default().strikethrough_style);
+    }
+
+    #[test]
+    fn test_footnote_reference_renders_numbered_marker_and_definition_block() {
+        let md = Markdown::new(
+            "Body text[^note].\n\n[^note]: The footnote body.\n",
+        );
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains("Body text[^1]"));
+        assert!(text.contains("[^1]: The footnote body."));
+    }
+
+    #[test]
+    fn test_multiple_footnotes_are_numbered_by_first_reference_order() {
+        let md = Markdown::new(
+            "First[^b] and second[^a].\n\n[^a]: Definition A.\n\n[^b]: Definition B.\n",
+        );
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = md.render(&context);
+        let text: String = segments
+            .iter()
+            .flat_map(|s| s.spans.iter())
+            .map(|span| span.text.as_str())
+            .collect();
+        assert!(text.contains("First[^1]"));
+        assert!(text.contains("second[^2]"));
+        assert!(text.contains("[^1]: Definition B."));
+        assert!(text.contains("[^2]: Definition A."));
+    }
 }
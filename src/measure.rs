@@ -14,21 +14,34 @@ pub struct Measurement {
     pub maximum: usize,
     /// Number of lines when rendered
     pub lines: usize,
+    /// Rendered lines beyond the available height, saturating at zero.
+    /// Always `0` for a [`Measurement::measure`] (width-only) measurement,
+    /// since there's no height bound to overflow.
+    pub overflow_lines: usize,
+    /// Whether the renderable overflowed the available height. Always
+    /// `false` for a [`Measurement::measure`] (width-only) measurement.
+    pub truncated: bool,
 }
 
 impl Measurement {
-    /// Create a new measurement.
+    /// Create a new measurement with no height bound.
     pub fn new(minimum: usize, maximum: usize, lines: usize) -> Self {
         Measurement {
             minimum,
             maximum,
             lines,
+            overflow_lines: 0,
+            truncated: false,
         }
     }
 
     /// Measure a renderable at a given width.
     pub fn measure(renderable: &impl Renderable, width: usize) -> Self {
-        let context = RenderContext { width };
+        let context = RenderContext {
+            width,
+            height: None,
+            direction: Default::default(),
+        };
         let segments = renderable.render(&context);
 
         let lines = segments.len();
@@ -39,6 +52,39 @@ impl Measurement {
             minimum,
             maximum,
             lines,
+            overflow_lines: 0,
+            truncated: false,
+        }
+    }
+
+    /// Measure a renderable within a bounded width *and* height, recording
+    /// how many rendered lines (if any) overflow `height` in
+    /// `overflow_lines`/`truncated`.
+    ///
+    /// Unlike [`Measurement::measure`], this renders with
+    /// `RenderContext.height` set, so renderables that truncate or scroll
+    /// their own output when given a height bound reflect that in
+    /// `lines` -- `overflow_lines` then only catches renderables that don't
+    /// self-truncate.
+    pub fn measure_in(renderable: &impl Renderable, width: usize, height: usize) -> Self {
+        let context = RenderContext {
+            width,
+            height: Some(height),
+            direction: Default::default(),
+        };
+        let segments = renderable.render(&context);
+
+        let lines = segments.len();
+        let minimum = renderable.min_width();
+        let maximum = renderable.max_width().min(width);
+        let overflow_lines = lines.saturating_sub(height);
+
+        Measurement {
+            minimum,
+            maximum,
+            lines,
+            overflow_lines,
+            truncated: overflow_lines > 0,
         }
     }
 
@@ -62,16 +108,74 @@ impl Measurement {
     }
 }
 
+/// Result of [`Measurable::fit`]: whether a renderable fits within a bounded
+/// width/height region, and if not, the narrowest width that would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FitResult {
+    /// Whether the content fits within `height` lines, at the requested
+    /// width or, failing that, at a narrower width found by the search.
+    pub fits: bool,
+    /// The requested width when the content already fits there; otherwise
+    /// the narrowest width between the renderable's `min_width()` and the
+    /// requested width that keeps the content within `height` lines, or
+    /// `None` if no such width exists.
+    pub width: Option<usize>,
+}
+
 /// Helper trait for measuring renderables.
 pub trait Measurable {
     /// Measure this renderable at the given width.
     fn measure(&self, width: usize) -> Measurement;
+
+    /// Check whether this renderable fits within `width` columns and
+    /// `height` lines, and if it doesn't, binary-search for the narrowest
+    /// width between its [`Renderable::min_width`] and `width` that would.
+    fn fit(&self, width: usize, height: usize) -> FitResult;
 }
 
 impl<T: Renderable> Measurable for T {
     fn measure(&self, width: usize) -> Measurement {
         Measurement::measure(self, width)
     }
+
+    fn fit(&self, width: usize, height: usize) -> FitResult {
+        if !Measurement::measure_in(self, width, height).truncated {
+            return FitResult {
+                fits: true,
+                width: Some(width),
+            };
+        }
+
+        let min_width = self.min_width();
+        if min_width >= width {
+            return FitResult {
+                fits: false,
+                width: None,
+            };
+        }
+
+        let mut lo = min_width;
+        let mut hi = width;
+        let mut narrowest_fit = None;
+
+        while lo <= hi {
+            let mid = lo + (hi - lo) / 2;
+            if Measurement::measure_in(self, mid, height).truncated {
+                lo = mid + 1;
+            } else {
+                narrowest_fit = Some(mid);
+                if mid == 0 {
+                    break;
+                }
+                hi = mid - 1;
+            }
+        }
+
+        FitResult {
+            fits: narrowest_fit.is_some(),
+            width: narrowest_fit,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -115,4 +219,36 @@ mod tests {
         let m = Measurement::new(10, 20, 5);
         assert_eq!(m.area(), 100); // 20 * 5
     }
+
+    #[test]
+    fn test_measure_records_no_overflow() {
+        let text = Text::plain("Hello, World!");
+        let m = Measurement::measure(&text, 80);
+        assert_eq!(m.overflow_lines, 0);
+        assert!(!m.truncated);
+    }
+
+    #[test]
+    fn test_measure_in_reports_overflow_when_too_short() {
+        let text = Text::plain("line one\nline two\nline three");
+        let m = Measurement::measure_in(&text, 80, 1);
+        assert!(m.truncated);
+        assert_eq!(m.overflow_lines, m.lines.saturating_sub(1));
+    }
+
+    #[test]
+    fn test_measure_in_no_overflow_when_tall_enough() {
+        let text = Text::plain("Hello, World!");
+        let m = Measurement::measure_in(&text, 80, 10);
+        assert_eq!(m.overflow_lines, 0);
+        assert!(!m.truncated);
+    }
+
+    #[test]
+    fn test_fit_reports_already_fitting_content() {
+        let text = Text::plain("Hello, World!");
+        let result = text.fit(80, 10);
+        assert!(result.fits);
+        assert_eq!(result.width, Some(80));
+    }
 }
@@ -1,6 +1,21 @@
 //! Nested progress bars support.
 //!
-//! Simplified version that demonstrates the concept.
+//! [`NestedProgress`] is a tree of progress tasks: [`Renderable`] draws it
+//! as an indented stack of bars (children under their parent), and
+//! [`NestedProgressView`] redraws that stack in place -- cursor up, clear
+//! each line, reprint -- so repeated [`NestedProgress::update`] calls
+//! animate like [`crate::live::Live`] does for a single renderable.
+
+use crate::console::RenderContext;
+use crate::renderable::{Renderable, Segment};
+use crate::style::{Color, Style};
+use crate::text::Span;
+use crossterm::{cursor, execute, terminal};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Width, in cells, of the rendered bar portion of each progress line.
+const BAR_WIDTH: usize = 30;
 
 /// A simple nested progress structure.
 pub struct NestedProgress {
@@ -45,6 +60,107 @@ impl NestedProgress {
     pub fn child_count(&self) -> usize {
         self.children.len()
     }
+
+    /// Render this node and its children into `segments`, indenting each
+    /// child two cells deeper than its parent.
+    fn render_into(&self, depth: usize, segments: &mut Vec<Segment>) {
+        let filled = ((self.percent() / 100.0) * BAR_WIDTH as f64).round() as usize;
+        let filled = filled.min(BAR_WIDTH);
+        let bar: String = "━".repeat(filled) + &"━".repeat(BAR_WIDTH - filled);
+
+        let indent = "  ".repeat(depth);
+        let mut spans = vec![Span::raw(format!("{indent}{} ", self.description))];
+        spans.push(Span::styled(bar[..filled * 3].to_string(), Style::new().foreground(Color::Cyan)));
+        spans.push(Span::styled(
+            bar[filled * 3..].to_string(),
+            Style::new().foreground(Color::BrightBlack),
+        ));
+        spans.push(Span::raw(format!(
+            " {:>5.1}% ({}/{})",
+            self.percent(),
+            self.current,
+            self.total
+        )));
+        segments.push(Segment::line(spans));
+
+        for child in &self.children {
+            child.render_into(depth + 1, segments);
+        }
+    }
+}
+
+impl Renderable for NestedProgress {
+    fn render(&self, _context: &RenderContext) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        self.render_into(0, &mut segments);
+        segments
+    }
+}
+
+/// Redraws a [`NestedProgress`] tree in place as it changes, mirroring
+/// [`crate::live::Live::refresh`]'s cursor-up-and-reprint approach but
+/// gated by a configurable minimum interval between redraws, since a
+/// progress tree is typically updated far more often than a terminal
+/// needs to repaint.
+pub struct NestedProgressView {
+    refresh_rate: Duration,
+    last_refresh: Option<Instant>,
+    last_height: usize,
+}
+
+impl NestedProgressView {
+    /// Create a view that redraws at most once per `refresh_rate`.
+    pub fn new(refresh_rate: Duration) -> Self {
+        NestedProgressView {
+            refresh_rate,
+            last_refresh: None,
+            last_height: 0,
+        }
+    }
+
+    /// Redraw `progress` in place if at least `refresh_rate` has elapsed
+    /// since the last redraw; otherwise do nothing, so a tight update loop
+    /// doesn't flood the terminal.
+    pub fn refresh(&mut self, progress: &NestedProgress) {
+        if let Some(last) = self.last_refresh {
+            if last.elapsed() < self.refresh_rate {
+                return;
+            }
+        }
+        self.redraw(progress);
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Redraw `progress` one final time, unconditionally, and leave the
+    /// cursor below the static output rather than clearing it on the next
+    /// call. Call this once the root task completes.
+    pub fn finish(&mut self, progress: &NestedProgress) {
+        self.redraw(progress);
+        println!();
+        self.last_height = 0;
+    }
+
+    fn redraw(&mut self, progress: &NestedProgress) {
+        use crate::console::Console;
+
+        let mut stdout = io::stdout();
+        if self.last_height > 0 {
+            let _ = execute!(stdout, cursor::MoveUp(self.last_height as u16));
+            for _ in 0..self.last_height {
+                let _ = execute!(stdout, terminal::Clear(terminal::ClearType::CurrentLine));
+                let _ = writeln!(stdout);
+            }
+            let _ = execute!(stdout, cursor::MoveUp(self.last_height as u16));
+        }
+
+        let capture = Console::capture();
+        capture.print_renderable(progress);
+        let output = capture.get_captured_output();
+
+        let _ = write!(stdout, "{output}");
+        let _ = stdout.flush();
+        self.last_height = output.matches('\n').count();
+    }
 }
 
 #[cfg(test)]
@@ -70,5 +186,45 @@ mod tests {
         nested.update(50);
         assert_eq!(nested.percent(), 50.0);
     }
+
+    #[test]
+    fn test_render_includes_description_and_percent() {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        let mut nested = NestedProgress::new("Root", 100);
+        nested.update(50);
+        let segments = nested.render(&context);
+        let plain = segments[0].plain_text();
+        assert!(plain.contains("Root"));
+        assert!(plain.contains("50.0%"));
+        assert!(plain.contains("(50/100)"));
+    }
+
+    #[test]
+    fn test_render_indents_children_under_parent() {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        let mut nested = NestedProgress::new("Parent", 100);
+        nested.add_child("Child", 10);
+        let segments = nested.render(&context);
+        assert_eq!(segments.len(), 2);
+        assert!(!segments[0].plain_text().starts_with("  "));
+        assert!(segments[1].plain_text().starts_with("  Child"));
+    }
+
+    #[test]
+    fn test_view_tracks_height_after_redraw() {
+        let mut nested = NestedProgress::new("Root", 100);
+        nested.add_child("Child", 10);
+        let mut view = NestedProgressView::new(Duration::from_millis(0));
+        view.refresh(&nested);
+        assert_eq!(view.last_height, 2);
+    }
 }
 
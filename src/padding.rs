@@ -92,7 +92,14 @@ impl Renderable for Padding {
         let child_width = context
             .width
             .saturating_sub(self.spec.left + self.spec.right);
-        let child_context = RenderContext { width: child_width };
+        let child_height = context
+            .height
+            .map(|h| h.saturating_sub(self.spec.top + self.spec.bottom));
+        let child_context = RenderContext {
+            width: child_width,
+            height: child_height,
+            direction: context.direction,
+        };
 
         // Render child
         let child_segments = self.child.render(&child_context);
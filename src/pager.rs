@@ -2,37 +2,237 @@
 //!
 //! Provides a less-like paging interface for large outputs.
 
+use std::collections::HashSet;
 use std::io::{self, Write};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
 use crossterm::{
     event::{self, Event, KeyCode, KeyEvent},
     terminal::{self, ClearType},
     cursor,
     execute,
 };
+use regex::Regex;
+use unicode_width::UnicodeWidthChar;
+
+use crate::style::{Color, Style};
+
+/// A sample pair of SGR codes used by the truncation tests below to stand
+/// in for arbitrary pre-existing styling on a line.
+const HIGHLIGHT_START: &str = "\x1b[43;30m";
+const HIGHLIGHT_END: &str = "\x1b[0m";
+
+/// The style [`Pager::search_style`] defaults to: reverse yellow-on-black,
+/// matching `less`'s classic search highlight.
+fn default_search_style() -> Style {
+    Style::new().foreground(Color::Black).background(Color::Yellow)
+}
+
+/// Which way `n`/`N` continue a search: the direction its `/` or `?` prompt
+/// searched in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchDirection {
+    Forward,
+    Backward,
+}
+
+impl SearchDirection {
+    /// The `jump_to_match` step (`1` or `-1`) that continues a search
+    /// started in this direction.
+    fn step(self) -> isize {
+        match self {
+            SearchDirection::Forward => 1,
+            SearchDirection::Backward => -1,
+        }
+    }
+}
+
+/// SGR reset re-emitted at a right-edge truncation point so an active
+/// color/style doesn't bleed past the `…` marker.
+const TRUNCATE_RESET: &str = "\x1b[0m";
+
+/// Columns moved per `Left`/`Right` keypress.
+const H_SCROLL_STEP: usize = 10;
+
+/// How long to wait for a key event before checking the streaming receiver
+/// (if any) for newly arrived lines.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// One unit of a terminal line for width accounting: either a single
+/// character with its display width, or a verbatim ANSI escape sequence
+/// (zero display width, but copied into the output so styling survives
+/// truncation).
+enum Token {
+    Char(char, usize),
+    Escape(String),
+}
+
+/// Consume one CSI escape sequence (`ESC '[' ... final-byte`) starting at
+/// the next character of `chars`, returning it verbatim. If the character
+/// after ESC isn't `[`, only the lone ESC is consumed and returned.
+fn consume_ansi_escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut escape = String::new();
+    escape.push(chars.next().expect("caller only invokes this at an ESC"));
+
+    if chars.peek() != Some(&'[') {
+        return escape;
+    }
+    escape.push(chars.next().expect("peeked"));
+
+    while let Some(c) = chars.next() {
+        escape.push(c);
+        if ('@'..='~').contains(&c) {
+            break;
+        }
+    }
+
+    escape
+}
+
+/// Split `line` into a sequence of display characters and verbatim ANSI
+/// escape sequences.
+fn tokenize(line: &str) -> Vec<Token> {
+    let mut chars = line.chars().peekable();
+    let mut tokens = Vec::new();
+
+    while let Some(&c) = chars.peek() {
+        if c == '\u{1b}' {
+            tokens.push(Token::Escape(consume_ansi_escape(&mut chars)));
+        } else {
+            chars.next();
+            tokens.push(Token::Char(c, UnicodeWidthChar::width(c).unwrap_or(0)));
+        }
+    }
+
+    tokens
+}
+
+/// Total display width of the `Token::Char`s in `tokens`, ignoring escapes.
+fn visible_width(tokens: &[Token]) -> usize {
+    tokens
+        .iter()
+        .map(|t| match t {
+            Token::Char(_, w) => *w,
+            Token::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// `line` with every ANSI escape sequence removed, leaving only the
+/// characters a user actually sees. Searches run against this instead of
+/// the raw line so a pattern matches visible text rather than bytes inside
+/// a color code.
+fn strip_ansi(line: &str) -> String {
+    tokenize(line)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Char(c, _) => Some(c),
+            Token::Escape(_) => None,
+        })
+        .collect()
+}
 
 /// A simple pager for displaying content with pagination.
 pub struct Pager {
     lines: Vec<String>,
     current_line: usize,
     terminal_height: usize,
+    terminal_width: usize,
+    /// Display columns scrolled in from the left, moved with `Left`/`Right`.
+    /// Lines longer than `terminal_width` after this offset is applied are
+    /// truncated in `render` instead of wrapping.
+    h_offset: usize,
+    /// The visible lines drawn on the last frame, so `render` can skip rows
+    /// whose content hasn't changed instead of rewriting the whole screen.
+    prev_visible: Vec<String>,
+    /// Whether `render` has drawn a frame yet -- the very first frame still
+    /// clears the whole screen once, to wipe whatever was on the terminal
+    /// before the pager started.
+    has_rendered: bool,
+    /// Whether the user is currently typing a `/` search query.
+    search_mode: bool,
+    /// The query being typed while `search_mode` is active.
+    search_query: String,
+    /// The compiled pattern of the active search, used to highlight matches
+    /// in `render`. `None` when there's no active search.
+    active_regex: Option<Regex>,
+    /// Direction the active search was started in (`/` forward, `?`
+    /// backward) -- `n` repeats it, `N` reverses it.
+    search_direction: SearchDirection,
+    /// Style used to highlight matches, set via [`Pager::search_style`].
+    search_style: Style,
+    /// Line indices (into `lines`) that contain a hit for `active_regex`.
+    matches: Vec<usize>,
+    /// Index into `matches` of the currently-selected hit.
+    current_match: Option<usize>,
+    /// A one-shot message (a search error, or "pattern not found") shown on
+    /// the status line instead of the usual position/percentage.
+    status_message: Option<String>,
+    /// Source of new lines for streaming/follow mode, set by
+    /// [`Pager::from_receiver`]. `None` for a plain, fully-materialized
+    /// pager.
+    receiver: Option<Receiver<String>>,
+    /// Whether the view stays pinned to the bottom as new lines arrive.
+    /// Scrolling up disables it; `F` re-enables it.
+    follow: bool,
 }
 
 impl Pager {
     /// Create a new pager with content.
     pub fn new(content: String) -> Self {
         let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-        let terminal_height = terminal::size()
-            .map(|(_, h)| h as usize)
-            .unwrap_or(24)
-            .saturating_sub(1); // Reserve one line for status
+        let (width, height) = terminal::size().unwrap_or((80, 24));
+        let terminal_height = (height as usize).saturating_sub(1); // Reserve one line for status
 
         Pager {
             lines,
             current_line: 0,
             terminal_height,
+            terminal_width: width as usize,
+            h_offset: 0,
+            prev_visible: Vec::new(),
+            has_rendered: false,
+            search_mode: false,
+            search_query: String::new(),
+            active_regex: None,
+            search_direction: SearchDirection::Forward,
+            search_style: default_search_style(),
+            matches: Vec::new(),
+            current_match: None,
+            status_message: None,
+            receiver: None,
+            follow: false,
+        }
+    }
+
+    /// Create a pager that streams lines from `rx` as they arrive (like
+    /// `tail -f`), instead of requiring the full content up front.
+    ///
+    /// Starts in follow mode: the view stays pinned to the bottom as new
+    /// lines come in until the user scrolls up, at which point follow
+    /// auto-disables (press `F` to jump back to the bottom and resume it).
+    pub fn from_receiver(rx: Receiver<String>) -> Self {
+        Pager {
+            receiver: Some(rx),
+            follow: true,
+            ..Pager::new(String::new())
         }
     }
 
+    /// Set the style applied to search matches (default: reverse
+    /// yellow-on-black).
+    pub fn search_style(mut self, style: Style) -> Self {
+        self.search_style = style;
+        self
+    }
+
+    /// The 1-based position of the current search match and the total
+    /// match count, e.g. `(3, 17)` for a "match 3/17" status line --
+    /// `None` when there's no active search or it matched nothing.
+    pub fn current_match_display(&self) -> Option<(usize, usize)> {
+        self.current_match.map(|i| (i + 1, self.matches.len()))
+    }
+
     /// Show the pager and handle user interaction.
     pub fn show(&mut self) -> io::Result<()> {
         terminal::enable_raw_mode()?;
@@ -47,61 +247,469 @@ impl Pager {
 
     fn run(&mut self) -> io::Result<()> {
         loop {
+            self.drain_receiver();
             self.render()?;
 
-            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
-                match code {
-                    KeyCode::Char('q') | KeyCode::Esc => break,
-                    KeyCode::Down | KeyCode::Char('j') => self.scroll_down(1),
-                    KeyCode::Up | KeyCode::Char('k') => self.scroll_up(1),
-                    KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_down(self.terminal_height),
-                    KeyCode::PageUp => self.scroll_up(self.terminal_height),
-                    KeyCode::Home | KeyCode::Char('g') => self.current_line = 0,
-                    KeyCode::End | KeyCode::Char('G') => {
-                        self.current_line = self.lines.len().saturating_sub(self.terminal_height);
+            // Poll instead of blocking on `event::read` so a streaming
+            // pager keeps checking `receiver` for new lines between
+            // keypresses; a plain pager just loops back around immediately
+            // after each timeout with nothing to do.
+            if !event::poll(POLL_INTERVAL)? {
+                continue;
+            }
+
+            match event::read()? {
+                Event::Resize(cols, rows) => self.handle_resize(cols, rows),
+                Event::Key(KeyEvent { code, .. }) => {
+                    if self.search_mode {
+                        match code {
+                            KeyCode::Enter => self.submit_search(),
+                            KeyCode::Esc => {
+                                self.search_mode = false;
+                                self.search_query.clear();
+                            }
+                            KeyCode::Backspace => {
+                                self.search_query.pop();
+                            }
+                            KeyCode::Char(c) => self.search_query.push(c),
+                            _ => {}
+                        }
+                        continue;
+                    }
+
+                    match code {
+                        KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Down | KeyCode::Char('j') => self.scroll_down(1),
+                        KeyCode::Up | KeyCode::Char('k') => {
+                            self.follow = false;
+                            self.scroll_up(1);
+                        }
+                        KeyCode::PageDown | KeyCode::Char(' ') => {
+                            self.scroll_down(self.terminal_height)
+                        }
+                        KeyCode::PageUp => {
+                            self.follow = false;
+                            self.scroll_up(self.terminal_height);
+                        }
+                        KeyCode::Home | KeyCode::Char('g') => {
+                            self.follow = false;
+                            self.current_line = 0;
+                        }
+                        KeyCode::End | KeyCode::Char('G') => {
+                            self.current_line = self.lines.len().saturating_sub(self.terminal_height);
+                        }
+                        KeyCode::Char('/') => {
+                            self.search_mode = true;
+                            self.search_direction = SearchDirection::Forward;
+                            self.search_query.clear();
+                            self.status_message = None;
+                        }
+                        KeyCode::Char('?') => {
+                            self.search_mode = true;
+                            self.search_direction = SearchDirection::Backward;
+                            self.search_query.clear();
+                            self.status_message = None;
+                        }
+                        KeyCode::Char('n') => self.jump_to_match(self.search_direction.step()),
+                        KeyCode::Char('N') => self.jump_to_match(-self.search_direction.step()),
+                        KeyCode::Right => {
+                            self.h_offset = self.h_offset.saturating_add(H_SCROLL_STEP);
+                        }
+                        KeyCode::Left => {
+                            self.h_offset = self.h_offset.saturating_sub(H_SCROLL_STEP);
+                        }
+                        KeyCode::Char('F') => {
+                            self.follow = true;
+                            self.current_line = self.lines.len().saturating_sub(self.terminal_height);
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
+                _ => {}
             }
         }
 
         Ok(())
     }
 
-    fn render(&self) -> io::Result<()> {
-        let mut stdout = io::stdout();
+    /// Recompute `terminal_height`/`terminal_width` from the new size, clamp
+    /// `current_line` to the new `max_scroll`, and force the next `render`
+    /// to redraw every row instead of diffing against the old layout (whose
+    /// row indices no longer line up with the resized terminal).
+    fn handle_resize(&mut self, cols: u16, rows: u16) {
+        self.terminal_height = (rows as usize).saturating_sub(1);
+        self.terminal_width = cols as usize;
+        let max_scroll = self.lines.len().saturating_sub(self.terminal_height);
+        self.current_line = self.current_line.min(max_scroll);
+        self.has_rendered = false;
+        self.prev_visible.clear();
+    }
 
-        // Clear screen
-        execute!(
-            stdout,
-            terminal::Clear(ClearType::All),
-            cursor::MoveTo(0, 0),
-            cursor::Hide
-        )?;
+    /// Append any lines that have arrived on `receiver` without blocking,
+    /// and, while `follow` is enabled, keep the view pinned to the bottom.
+    fn drain_receiver(&mut self) {
+        let Some(receiver) = &self.receiver else {
+            return;
+        };
 
-        // Display visible lines
-        let end_line = (self.current_line + self.terminal_height).min(self.lines.len());
-        for line in &self.lines[self.current_line..end_line] {
-            writeln!(stdout, "{}", line)?;
+        loop {
+            match receiver.try_recv() {
+                Ok(line) => self.lines.push(line),
+                Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => break,
+            }
+        }
+
+        if self.follow {
+            self.current_line = self.lines.len().saturating_sub(self.terminal_height);
+        }
+    }
+
+    /// Compile `search_query`, scan `lines` for hits (against each line with
+    /// ANSI styling stripped, so a pattern matches the text a user sees
+    /// rather than bytes inside a color code), and jump to the nearest one
+    /// in `search_direction` from `current_line` (wrapping around the ends
+    /// of the document if there isn't one).
+    ///
+    /// An empty query clears the highlight and match state instead of
+    /// searching. An invalid pattern reports the error on the status line
+    /// rather than crashing. A query with no uppercase letters searches
+    /// case-insensitively (smart-case), matching `less`'s `/` behavior.
+    fn submit_search(&mut self) {
+        self.search_mode = false;
+
+        if self.search_query.is_empty() {
+            self.active_regex = None;
+            self.matches.clear();
+            self.current_match = None;
+            self.status_message = None;
+            return;
         }
 
-        // Show status line
-        let percent = if self.lines.is_empty() {
-            100
+        let smart_case_insensitive = !self.search_query.chars().any(char::is_uppercase);
+        let pattern = if smart_case_insensitive {
+            format!("(?i){}", self.search_query)
         } else {
-            (self.current_line * 100) / self.lines.len().max(1)
+            self.search_query.clone()
         };
 
-        execute!(stdout, cursor::MoveTo(0, self.terminal_height as u16))?;
-        write!(
+        let regex = match Regex::new(&pattern) {
+            Ok(regex) => regex,
+            Err(err) => {
+                self.status_message = Some(format!("Invalid regex: {err}"));
+                self.active_regex = None;
+                self.matches.clear();
+                self.current_match = None;
+                return;
+            }
+        };
+
+        self.matches = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| regex.is_match(&strip_ansi(line)))
+            .map(|(i, _)| i)
+            .collect();
+
+        if self.matches.is_empty() {
+            self.active_regex = Some(regex);
+            self.current_match = None;
+            self.status_message = Some(format!("Pattern not found: {}", self.search_query));
+            return;
+        }
+
+        self.active_regex = Some(regex);
+        self.status_message = None;
+        self.current_match = Some(match self.search_direction {
+            SearchDirection::Forward => self
+                .matches
+                .iter()
+                .position(|&line| line >= self.current_line)
+                .unwrap_or(0),
+            SearchDirection::Backward => self
+                .matches
+                .iter()
+                .rposition(|&line| line <= self.current_line)
+                .unwrap_or(self.matches.len() - 1),
+        });
+        self.jump_to_current_match();
+    }
+
+    /// Move `current_match` by `direction` (`1` for `n`, `-1` for `N`),
+    /// wrapping around the ends of `matches`, and scroll so it's visible.
+    fn jump_to_match(&mut self, direction: isize) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        let len = self.matches.len() as isize;
+        let current = self.current_match.map_or(-1, |i| i as isize);
+        let next = (current + direction).rem_euclid(len);
+        self.current_match = Some(next as usize);
+        self.jump_to_current_match();
+    }
+
+    /// Scroll so the line containing the current match is visible.
+    fn jump_to_current_match(&mut self) {
+        if let Some(idx) = self.current_match {
+            let line = self.matches[idx];
+            let max_scroll = self.lines.len().saturating_sub(self.terminal_height);
+            self.current_line = line.min(max_scroll);
+        }
+    }
+
+    /// Wrap every match of `active_regex` in `search_style`'s ANSI codes,
+    /// leaving the line unchanged when there's no active search.
+    ///
+    /// The regex runs against `line` with its ANSI escapes stripped, and
+    /// the resulting byte ranges are mapped back onto `line`'s own tokens
+    /// (via [`tokenize`]) before inserting the highlight, so matches land
+    /// on the right columns even when the line already carries styling.
+    fn highlight_line(&self, line: &str) -> String {
+        let Some(regex) = &self.active_regex else {
+            return line.to_string();
+        };
+
+        let tokens = tokenize(line);
+        let char_token_indices: Vec<usize> = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| matches!(token, Token::Char(_, _)))
+            .map(|(index, _)| index)
+            .collect();
+        let stripped: String = char_token_indices
+            .iter()
+            .map(|&index| match tokens[index] {
+                Token::Char(c, _) => c,
+                Token::Escape(_) => unreachable!("char_token_indices only holds Char tokens"),
+            })
+            .collect();
+
+        let mut starts = HashSet::new();
+        let mut ends = HashSet::new();
+        for found in regex.find_iter(&stripped) {
+            let start_char = stripped[..found.start()].chars().count();
+            let end_char = stripped[..found.end()].chars().count();
+            if let Some(&token_index) = char_token_indices.get(start_char) {
+                starts.insert(token_index);
+            }
+            match char_token_indices.get(end_char) {
+                Some(&token_index) => {
+                    ends.insert(token_index);
+                }
+                None => {
+                    ends.insert(tokens.len());
+                }
+            }
+        }
+
+        if starts.is_empty() {
+            return line.to_string();
+        }
+
+        let prefix = self.search_style.to_ansi_prefix();
+        let suffix = self.search_style.to_ansi_suffix();
+        let mut result = String::with_capacity(line.len() + (prefix.len() + suffix.len()) * starts.len());
+        for (token_index, token) in tokens.iter().enumerate() {
+            if ends.contains(&token_index) {
+                result.push_str(&suffix);
+            }
+            if starts.contains(&token_index) {
+                result.push_str(&prefix);
+            }
+            match token {
+                Token::Char(c, _) => result.push(*c),
+                Token::Escape(s) => result.push_str(s),
+            }
+        }
+        if ends.contains(&tokens.len()) {
+            result.push_str(&suffix);
+        }
+        result
+    }
+
+    /// Truncate `line` to `width` display columns, scrolled in by
+    /// `self.h_offset` columns from the left.
+    fn truncate_line(&self, line: &str, width: usize) -> String {
+        self.truncate_line_from(line, self.h_offset, width)
+    }
+
+    /// Truncate `line` to `width` display columns after skipping
+    /// `h_offset` columns from the left, for horizontal scrolling past long
+    /// lines.
+    ///
+    /// Double-width glyphs count as two columns and combining marks as
+    /// zero, via [`UnicodeWidthChar`]. ANSI SGR escape sequences are
+    /// zero-width for measuring and skipping purposes but are always
+    /// copied into the output so styling survives truncation; a reset
+    /// ([`TRUNCATE_RESET`]) is re-emitted at a right-edge truncation if any
+    /// escape sequence was carried past it, so color doesn't bleed past
+    /// the `…` marker.
+    fn truncate_line_from(&self, line: &str, h_offset: usize, width: usize) -> String {
+        if width == 0 {
+            return String::new();
+        }
+
+        let tokens = tokenize(line);
+
+        // Skip h_offset columns, collecting any escapes encountered before
+        // the visible window so their styling still applies to what is
+        // shown.
+        let mut idx = 0;
+        let mut column = 0usize;
+        let mut prefix_escapes = String::new();
+        let mut skipped_any = false;
+
+        while idx < tokens.len() {
+            match &tokens[idx] {
+                Token::Escape(s) => {
+                    prefix_escapes.push_str(s);
+                    idx += 1;
+                }
+                Token::Char(_, w) if column < h_offset => {
+                    skipped_any = true;
+                    column += w;
+                    idx += 1;
+                }
+                Token::Char(_, _) => break,
+            }
+        }
+
+        let leading_cost = usize::from(skipped_any);
+        let remaining = &tokens[idx..];
+        let fits_without_trailing =
+            visible_width(remaining) <= width.saturating_sub(leading_cost);
+        let trailing_cost = usize::from(!fits_without_trailing);
+        let budget = width
+            .saturating_sub(leading_cost)
+            .saturating_sub(trailing_cost);
+
+        let mut body = String::new();
+        let mut used = 0usize;
+        let mut clipped_right = false;
+        let mut body_has_escape = false;
+
+        for token in remaining {
+            match token {
+                Token::Escape(s) => {
+                    body.push_str(s);
+                    body_has_escape = true;
+                }
+                Token::Char(c, w) => {
+                    if used + w > budget {
+                        clipped_right = true;
+                        break;
+                    }
+                    body.push(*c);
+                    used += w;
+                }
+            }
+        }
+
+        let mut out = String::new();
+        out.push_str(&prefix_escapes);
+        if skipped_any {
+            out.push('…');
+        }
+        out.push_str(&body);
+        if clipped_right {
+            if !prefix_escapes.is_empty() || body_has_escape {
+                out.push_str(TRUNCATE_RESET);
+            }
+            out.push('…');
+        }
+        out
+    }
+
+    /// Redraw the pager, rewriting only the rows whose content changed
+    /// since the last frame instead of clearing and redrawing the whole
+    /// screen. Single-line scrolling (`j`/`k`) then only touches the one
+    /// newly-exposed row and the status line; a full-page jump changes
+    /// every row anyway, so it naturally falls back to a full redraw
+    /// without any special-casing.
+    fn render(&mut self) -> io::Result<()> {
+        let mut stdout = io::stdout();
+
+        if !self.has_rendered {
+            execute!(stdout, terminal::Clear(ClearType::All), cursor::Hide)?;
+            self.has_rendered = true;
+        } else {
+            execute!(stdout, cursor::Hide)?;
+        }
+
+        let end_line = (self.current_line + self.terminal_height).min(self.lines.len());
+        let visible: Vec<String> = self.lines[self.current_line..end_line]
+            .iter()
+            .map(|line| self.truncate_line(&self.highlight_line(line), self.terminal_width))
+            .collect();
+
+        for (i, line) in visible.iter().enumerate() {
+            if self.prev_visible.get(i).map(String::as_str) != Some(line.as_str()) {
+                execute!(
+                    stdout,
+                    cursor::MoveTo(0, i as u16),
+                    terminal::Clear(ClearType::CurrentLine)
+                )?;
+                write!(stdout, "{}", line)?;
+            }
+        }
+
+        // The window shrank (e.g. scrolled to the last, short page): clear
+        // the rows that were drawn last frame but have nothing to show now.
+        for i in visible.len()..self.prev_visible.len() {
+            execute!(
+                stdout,
+                cursor::MoveTo(0, i as u16),
+                terminal::Clear(ClearType::CurrentLine)
+            )?;
+        }
+
+        self.prev_visible = visible;
+
+        // Always redraw the status line: the search prompt while typing a
+        // query, a one-shot error/not-found message, or the usual position.
+        execute!(
             stdout,
-            "\r\x1b[7m Lines {}-{}/{} ({}%) | q: quit, arrows/jk: scroll, space: page down \x1b[0m",
-            self.current_line + 1,
-            end_line,
-            self.lines.len(),
-            percent
+            cursor::MoveTo(0, self.terminal_height as u16),
+            terminal::Clear(ClearType::CurrentLine)
         )?;
 
+        if self.search_mode {
+            let prompt = match self.search_direction {
+                SearchDirection::Forward => '/',
+                SearchDirection::Backward => '?',
+            };
+            write!(stdout, "\r{prompt}{}", self.search_query)?;
+        } else if let Some(message) = &self.status_message {
+            write!(stdout, "\r\x1b[7m {message} \x1b[0m")?;
+        } else {
+            let percent = if self.lines.is_empty() {
+                100
+            } else {
+                (self.current_line * 100) / self.lines.len().max(1)
+            };
+
+            let follow_indicator = match &self.receiver {
+                Some(_) if self.follow => " [FOLLOWING]",
+                Some(_) => " [PAUSED]",
+                None => "",
+            };
+
+            let match_indicator = self
+                .current_match_display()
+                .map(|(position, total)| format!(" | match {position}/{total}"))
+                .unwrap_or_default();
+
+            write!(
+                stdout,
+                "\r\x1b[7m Lines {}-{}/{} ({}%){follow_indicator}{match_indicator} | q: quit, /: search, ?: search back, n/N: next/prev match \x1b[0m",
+                self.current_line + 1,
+                end_line,
+                self.lines.len(),
+                percent
+            )?;
+        }
+
         stdout.flush()?;
         Ok(())
     }
@@ -172,4 +780,327 @@ mod tests {
         pager.scroll_down(1000);
         assert!(pager.current_line <= pager.line_count());
     }
+
+    #[test]
+    fn test_search_is_case_insensitive_when_query_is_lowercase() {
+        let content = "Alpha\nbeta\nGAMMA".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "alpha".to_string();
+        pager.submit_search();
+
+        assert_eq!(pager.matches, vec![0]);
+        assert_eq!(pager.current_match, Some(0));
+    }
+
+    #[test]
+    fn test_search_is_case_sensitive_when_query_has_uppercase() {
+        let content = "Alpha\nalpha".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "Alpha".to_string();
+        pager.submit_search();
+
+        assert_eq!(pager.matches, vec![0]);
+    }
+
+    #[test]
+    fn test_empty_query_clears_search_state() {
+        let content = "Alpha\nbeta".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "alpha".to_string();
+        pager.submit_search();
+        assert!(!pager.matches.is_empty());
+
+        pager.search_query.clear();
+        pager.submit_search();
+
+        assert!(pager.matches.is_empty());
+        assert!(pager.active_regex.is_none());
+        assert!(pager.current_match.is_none());
+    }
+
+    #[test]
+    fn test_invalid_regex_sets_status_message_instead_of_panicking() {
+        let content = "Alpha\nbeta".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "(unclosed".to_string();
+        pager.submit_search();
+
+        assert!(pager.status_message.is_some());
+        assert!(pager.matches.is_empty());
+    }
+
+    #[test]
+    fn test_no_match_reports_pattern_not_found() {
+        let content = "Alpha\nbeta".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "zzz".to_string();
+        pager.submit_search();
+
+        assert!(pager.matches.is_empty());
+        assert_eq!(
+            pager.status_message.as_deref(),
+            Some("Pattern not found: zzz")
+        );
+    }
+
+    #[test]
+    fn test_jump_to_match_wraps_around() {
+        let content = "a\nmatch\nb\nmatch\nc".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "match".to_string();
+        pager.submit_search();
+        assert_eq!(pager.matches, vec![1, 3]);
+        assert_eq!(pager.current_match, Some(0));
+
+        pager.jump_to_match(1);
+        assert_eq!(pager.current_match, Some(1));
+
+        pager.jump_to_match(1);
+        assert_eq!(pager.current_match, Some(0)); // wraps forward
+
+        pager.jump_to_match(-1);
+        assert_eq!(pager.current_match, Some(1)); // wraps backward
+    }
+
+    #[test]
+    fn test_highlight_line_wraps_matches_in_the_search_style() {
+        let content = "hello world".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "world".to_string();
+        pager.submit_search();
+
+        let prefix = pager.search_style.to_ansi_prefix();
+        let suffix = pager.search_style.to_ansi_suffix();
+        let highlighted = pager.highlight_line("hello world");
+        assert_eq!(highlighted, format!("hello {prefix}world{suffix}"));
+    }
+
+    #[test]
+    fn test_search_style_overrides_the_default_highlight() {
+        let content = "hello world".to_string();
+        let style = Style::new().foreground(Color::Red);
+        let mut pager = Pager::new(content).search_style(style.clone());
+
+        pager.search_query = "world".to_string();
+        pager.submit_search();
+
+        let highlighted = pager.highlight_line("hello world");
+        assert_eq!(
+            highlighted,
+            format!("hello {}world{}", style.to_ansi_prefix(), style.to_ansi_suffix())
+        );
+    }
+
+    #[test]
+    fn test_highlight_matches_land_on_the_right_columns_despite_ansi_styling() {
+        let content = "line".to_string();
+        let mut pager = Pager::new(content);
+
+        pager.search_query = "world".to_string();
+        pager.submit_search();
+
+        let styled_line = format!("{HIGHLIGHT_START}hello {HIGHLIGHT_END}world");
+        let highlighted = pager.highlight_line(&styled_line);
+        let prefix = pager.search_style.to_ansi_prefix();
+        let suffix = pager.search_style.to_ansi_suffix();
+
+        assert_eq!(
+            highlighted,
+            format!("{HIGHLIGHT_START}hello {HIGHLIGHT_END}{prefix}world{suffix}")
+        );
+    }
+
+    #[test]
+    fn test_question_mark_search_direction_finds_the_nearest_match_at_or_before_current_line() {
+        let content = "a\nmatch\nb\nmatch\nc".to_string();
+        let mut pager = Pager::new(content);
+        pager.current_line = 3;
+
+        pager.search_direction = SearchDirection::Backward;
+        pager.search_query = "match".to_string();
+        pager.submit_search();
+
+        assert_eq!(pager.matches, vec![1, 3]);
+        assert_eq!(pager.current_match, Some(1));
+    }
+
+    #[test]
+    fn test_n_and_shift_n_respect_the_search_direction() {
+        let content = "match\na\nmatch\nb\nmatch".to_string();
+        let mut pager = Pager::new(content);
+        pager.current_line = 4;
+
+        pager.search_direction = SearchDirection::Backward;
+        pager.search_query = "match".to_string();
+        pager.submit_search();
+        assert_eq!(pager.current_match, Some(2)); // line 4, the nearest match at/before it
+
+        pager.jump_to_match(pager.search_direction.step());
+        assert_eq!(pager.current_match, Some(1)); // `n` continues backward
+
+        pager.jump_to_match(-pager.search_direction.step());
+        assert_eq!(pager.current_match, Some(2)); // `N` reverses to forward
+    }
+
+    #[test]
+    fn test_current_match_display_reports_one_based_position_and_total() {
+        let content = "a\nmatch\nb\nmatch\nc".to_string();
+        let mut pager = Pager::new(content);
+
+        assert_eq!(pager.current_match_display(), None);
+
+        pager.search_query = "match".to_string();
+        pager.submit_search();
+        assert_eq!(pager.current_match_display(), Some((1, 2)));
+
+        pager.jump_to_match(1);
+        assert_eq!(pager.current_match_display(), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_highlight_line_is_identity_without_active_search() {
+        let content = "hello world".to_string();
+        let pager = Pager::new(content);
+
+        assert_eq!(pager.highlight_line("hello world"), "hello world");
+    }
+
+    #[test]
+    fn test_from_receiver_starts_empty_and_following() {
+        let (_tx, rx) = std::sync::mpsc::channel();
+        let pager = Pager::from_receiver(rx);
+
+        assert_eq!(pager.line_count(), 0);
+        assert!(pager.follow);
+        assert!(pager.receiver.is_some());
+    }
+
+    #[test]
+    fn test_drain_receiver_appends_lines_and_pins_to_bottom_while_following() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut pager = Pager::from_receiver(rx);
+        pager.terminal_height = 5;
+
+        for i in 0..20 {
+            tx.send(format!("line {i}")).unwrap();
+        }
+
+        pager.drain_receiver();
+
+        assert_eq!(pager.line_count(), 20);
+        assert_eq!(pager.current_line, 20usize.saturating_sub(5));
+    }
+
+    #[test]
+    fn test_drain_receiver_does_not_move_view_once_follow_is_disabled() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut pager = Pager::from_receiver(rx);
+        pager.terminal_height = 5;
+        tx.send("line 0".to_string()).unwrap();
+        pager.drain_receiver();
+
+        pager.follow = false;
+        pager.current_line = 0;
+
+        tx.send("line 1".to_string()).unwrap();
+        pager.drain_receiver();
+
+        assert_eq!(pager.current_line, 0);
+        assert_eq!(pager.line_count(), 2);
+    }
+
+    #[test]
+    fn test_handle_resize_recomputes_height_and_clamps_current_line() {
+        let content = (0..100)
+            .map(|i| format!("Line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut pager = Pager::new(content);
+        pager.terminal_height = 20;
+        pager.current_line = 95; // near the end with the old, taller window
+
+        pager.handle_resize(80, 11); // new height: 11 rows, 10 reserved for content
+
+        assert_eq!(pager.terminal_height, 10);
+        assert_eq!(pager.terminal_width, 80);
+        assert_eq!(pager.current_line, 90); // clamped to the new max_scroll
+        assert!(!pager.has_rendered);
+        assert!(pager.prev_visible.is_empty());
+    }
+
+    #[test]
+    fn test_handle_resize_forces_full_redraw_flag() {
+        let content = "Line 1\nLine 2".to_string();
+        let mut pager = Pager::new(content);
+        pager.has_rendered = true;
+        pager.prev_visible = vec!["Line 1".to_string()];
+
+        pager.handle_resize(80, 24);
+
+        assert!(!pager.has_rendered);
+        assert!(pager.prev_visible.is_empty());
+    }
+
+    #[test]
+    fn test_truncate_visible_appends_ellipsis_when_clipped_on_the_right() {
+        let pager = Pager::new(String::new());
+        let line = "a".repeat(20);
+
+        let truncated = pager.truncate_line(&line, 10);
+
+        assert_eq!(truncated.chars().count(), 10);
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn test_truncate_visible_counts_wide_chars_as_two_columns() {
+        let pager = Pager::new(String::new());
+        // Three full-width characters, 6 display columns total.
+        let line = "\u{6f22}\u{5b57}\u{6587}";
+
+        let truncated = pager.truncate_line(&line, 4);
+
+        // Only the first full-width char (2 cols) fits before the ellipsis
+        // (1 col) within a 4-column budget.
+        assert_eq!(truncated, "\u{6f22}…");
+    }
+
+    #[test]
+    fn test_truncate_visible_preserves_ansi_escapes_around_the_cut() {
+        let pager = Pager::new(String::new());
+        let line = format!("{HIGHLIGHT_START}hello{HIGHLIGHT_END} world");
+
+        let truncated = pager.truncate_line(&line, 7);
+
+        assert!(truncated.starts_with(HIGHLIGHT_START));
+        assert!(truncated.contains(TRUNCATE_RESET));
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn test_truncate_visible_skips_h_offset_columns_with_leading_ellipsis() {
+        let pager = Pager::new(String::new());
+        let line = "0123456789";
+
+        let truncated = pager.truncate_line_from(&line, 5, 10);
+
+        assert_eq!(truncated, "…56789");
+    }
+
+    #[test]
+    fn test_truncate_visible_fits_whole_line_without_any_ellipsis() {
+        let pager = Pager::new(String::new());
+        let line = "short";
+
+        let truncated = pager.truncate_line(&line, 80);
+
+        assert_eq!(truncated, "short");
+    }
 }
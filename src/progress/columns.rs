@@ -1,15 +1,21 @@
+use crate::filesize::{format_bytes, format_bytes_per_sec};
+use crate::progress::bar::TaskUnit;
 use crate::progress::Task;
-// use crate::progress::bar::ProgressBar; 
+// use crate::progress::bar::ProgressBar;
 use crate::progress::spinner::{Spinner, SpinnerStyle};
 use crate::style::{Color, Style};
 use crate::text::Span;
 use std::fmt::Debug;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// A trait for rendering a column in a progress bar.
 pub trait ProgressColumn: Send + Sync + Debug {
-    /// Render the column for the given task.
-    fn render(&self, task: &Task) -> Vec<Span>;
+    /// Render the column for the given task. `now` is the instant the whole
+    /// display is being redrawn at, threaded through from [`Progress`](crate::progress::bar::Progress)
+    /// rather than read fresh via `Instant::now()`, so time-driven animation
+    /// (e.g. [`SpinnerColumn`], [`BarColumn`]'s pulse mode) stays deterministic
+    /// and testable.
+    fn render(&self, task: &Task, now: Instant) -> Vec<Span>;
 }
 
 /// Renders a static text string or text based on task properties.
@@ -36,7 +42,7 @@ impl TextColumn {
 }
 
 impl ProgressColumn for TextColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
         // Simple interpolation for now task.description
          let text = if self.text == "[progress.description]" {
              &task.description
@@ -55,6 +61,9 @@ pub struct BarColumn {
     pub complete_style: Style,
     pub finished_style: Option<Style>,
     pub pulse_style: Option<Style>,
+    /// Render fractional fill with Unicode eighth-block glyphs instead of
+    /// rounding to whole cells (default: false). See [`BarColumn::fine`].
+    pub smooth: bool,
 }
 
 impl BarColumn {
@@ -64,26 +73,88 @@ impl BarColumn {
             complete_style: Style::new().foreground(Color::Magenta), // Default rich color
             finished_style: Some(Style::new().foreground(Color::Green)),
             pulse_style: None,
+            smooth: false,
         }
     }
+
+    /// A bar column that draws sub-cell progress with the Unicode
+    /// horizontal eighth-block ramp (`▏▎▍▌▋▊▉█`) instead of snapping the
+    /// fill to whole cells, so progress reads smoothly even on a narrow bar.
+    pub fn fine(bar_width: usize) -> Self {
+        Self {
+            smooth: true,
+            ..Self::new(bar_width)
+        }
+    }
+
+    /// Render with sub-cell resolution: `floor(exact)` full `█` cells, then
+    /// one partial glyph from the eighth-block ramp chosen by the rounded
+    /// fractional remainder (0 means no partial cell, 8 rolls over into an
+    /// extra full cell), then grey `█` padding for the remainder.
+    fn render_fine(&self, style: Style, percentage: f64) -> Vec<Span> {
+        const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+        let width = self.bar_width;
+        let exact = width as f64 * percentage;
+        let full = exact.floor() as usize;
+        let index = ((exact - full as f64) * 8.0).round() as usize;
+
+        let (full_cells, partial) = if index == 0 {
+            (full.min(width), None)
+        } else if index >= 8 {
+            ((full + 1).min(width), None)
+        } else {
+            (full.min(width), Some(EIGHTHS[index - 1]))
+        };
+
+        let mut spans = Vec::new();
+        if full_cells > 0 {
+            spans.push(Span::styled("█".repeat(full_cells), style));
+        }
+
+        let mut used = full_cells;
+        if let Some(ch) = partial {
+            if used < width {
+                spans.push(Span::styled(ch.to_string(), style));
+                used += 1;
+            }
+        }
+
+        let empty_width = width.saturating_sub(used);
+        if empty_width > 0 {
+            spans.push(Span::styled(
+                "█".repeat(empty_width),
+                Style::new().foreground(Color::Ansi256(237)), // Grey
+            ));
+        }
+        spans
+    }
 }
 
 impl ProgressColumn for BarColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, now: Instant) -> Vec<Span> {
+        if task.total.is_none() && !task.finished {
+            return self.render_pulse(now.duration_since(task.start_time));
+        }
+
         let total = task.total.unwrap_or(100) as f64;
         let completed = task.completed as f64;
         let percentage = (completed / total).min(1.0).max(0.0);
-        
-        let width = self.bar_width;
-        let filled_width = (width as f64 * percentage).round() as usize;
-        let empty_width = width.saturating_sub(filled_width);
-        
+
         let style = if task.finished {
             self.finished_style.unwrap_or(self.complete_style)
         } else {
             self.complete_style
         };
 
+        if self.smooth {
+            return self.render_fine(style, percentage);
+        }
+
+        let width = self.bar_width;
+        let filled_width = (width as f64 * percentage).round() as usize;
+        let empty_width = width.saturating_sub(filled_width);
+
         let mut spans = Vec::new();
         if filled_width > 0 {
              spans.push(Span::styled("━".repeat(filled_width), style));
@@ -95,6 +166,44 @@ impl ProgressColumn for BarColumn {
     }
 }
 
+impl BarColumn {
+    /// Animate a block sliding back and forth across the bar's width, for
+    /// tasks created with `total: None` whose true length is unknown.
+    /// `elapsed` drives the block's position so the animation is a pure
+    /// function of time rather than stored, drifting state.
+    fn render_pulse(&self, elapsed: Duration) -> Vec<Span> {
+        let width = self.bar_width;
+        if width == 0 {
+            return Vec::new();
+        }
+
+        let block_width = (width / 3).clamp(1, width);
+        let travel = width - block_width;
+        let step_ms = 50u64;
+        let step = (elapsed.as_millis() as u64 / step_ms) as usize;
+
+        // Bounce the block back and forth across `travel` rather than
+        // wrapping, so it reads as a single block sweeping the bar.
+        let bounce_period = (travel * 2).max(1);
+        let offset = step % bounce_period;
+        let position = if offset <= travel { offset } else { bounce_period - offset };
+
+        let style = self.pulse_style.unwrap_or(self.complete_style);
+        let grey = Style::new().foreground(Color::Ansi256(237));
+
+        let mut spans = Vec::new();
+        if position > 0 {
+            spans.push(Span::styled("━".repeat(position), grey));
+        }
+        spans.push(Span::styled("━".repeat(block_width), style));
+        let after = width - position - block_width;
+        if after > 0 {
+            spans.push(Span::styled("━".repeat(after), grey));
+        }
+        spans
+    }
+}
+
 /// Renders the percentage complete (e.g. "50%").
 #[derive(Debug)]
 pub struct PercentageColumn(pub Style);
@@ -106,7 +215,7 @@ impl PercentageColumn {
 }
 
 impl ProgressColumn for PercentageColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
         let percentage = task.percentage() * 100.0;
         vec![Span::styled(format!("{:>3.0}%", percentage), self.0)]
     }
@@ -127,40 +236,60 @@ impl SpinnerColumn {
 }
 
 impl ProgressColumn for SpinnerColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
-        // We use the task's elapsed time to calculate the frame
-        // This keeps it stateless with respect to the column, but animated by the task's lifetime.
-        // For a global spinner independent of task start, we might need a shared start time.
-        // But usually spinners in task rows indicate THAT task's activity.
-        
-        // However, generic Spinner uses its own start_time.
-        // We should probably rely on `SpinnerStyle` and manual calculation using task.elapsed()
-        // to avoid storing state that drifts.
-        
-        // Let's copy logic from Spinner::current_frame but use task.elapsed()
-        let style = self.spinner.get_style();
-        let interval = style.interval_ms();
-        let frames = style.frames();
-        let elapsed_ms = task.elapsed().as_millis() as u64;
+    fn render(&self, task: &Task, now: Instant) -> Vec<Span> {
+        // Derive the frame from `now - task.start_time` rather than storing
+        // our own start time (which would drift from the task's own clock)
+        // or calling `Instant::now()` directly (which isn't testable).
+        let frames = self.spinner.get_frames();
+        let interval = frames.interval_ms();
+        let elapsed_ms = now.duration_since(task.start_time).as_millis() as u64;
         let idx = ((elapsed_ms / interval) as usize) % frames.len();
-        
-        vec![Span::styled(frames[idx].to_string(), Style::new().foreground(Color::Green))]
+
+        vec![Span::styled(frames.frame(idx).to_string(), Style::new().foreground(Color::Green))]
+    }
+}
+
+/// Renders `completed/total` formatted as human-readable byte counts
+/// (`"45.2MiB/134.0MiB"`), via [`crate::filesize::format_bytes`]. Intended for
+/// tasks whose [`TaskUnit`] is [`TaskUnit::Bytes`], but formats any task's
+/// counts as bytes regardless of `unit` — pair it with [`TaskUnit::Bytes`]
+/// so other columns (e.g. [`TransferSpeedColumn`]) format consistently.
+#[derive(Debug)]
+pub struct DownloadColumn;
+
+impl ProgressColumn for DownloadColumn {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
+        let completed = format_bytes(task.completed);
+        let total = match task.total {
+            Some(total) => format_bytes(total),
+            None => "?".to_string(),
+        };
+        vec![Span::styled(format!("{}/{}", completed, total), Style::new().foreground(Color::Green))]
     }
 }
 
-/// Renders transfer speed
+/// Renders transfer speed, in byte units (via [`crate::filesize`]) when the
+/// task's [`TaskUnit`] is [`TaskUnit::Bytes`], otherwise in plain units/sec.
+/// Shows `"--"` until the task has been running for at least a second, to
+/// avoid an early, noisy speed estimate.
 #[derive(Debug)]
 pub struct TransferSpeedColumn;
 
 impl ProgressColumn for TransferSpeedColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
-        let speed = task.speed();
-         let speed_str = if speed >= 1_000_000.0 {
-            format!("{:.1} MB/s", speed / 1_000_000.0)
-        } else if speed >= 1_000.0 {
-            format!("{:.1} KB/s", speed / 1_000.0)
-        } else {
-             format!("{:.0} B/s", speed)
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
+        if task.elapsed().as_secs_f64() < 1.0 {
+            return vec![Span::styled("--".to_string(), Style::new().foreground(Color::Red))];
+        }
+
+        // Prefer the raw windowed rate (responsive to recent bursts); fall
+        // back to the EMA-smoothed lifetime-aware speed() before the window
+        // has two samples.
+        let speed = task.speed_windowed().unwrap_or_else(|| task.speed());
+        let speed_str = match task.unit {
+            TaskUnit::Bytes => format_bytes_per_sec(speed),
+            TaskUnit::Count if speed >= 1_000_000.0 => format!("{:.1} MB/s", speed / 1_000_000.0),
+            TaskUnit::Count if speed >= 1_000.0 => format!("{:.1} KB/s", speed / 1_000.0),
+            TaskUnit::Count => format!("{:.0} B/s", speed),
         };
         vec![Span::styled(speed_str, Style::new().foreground(Color::Red))]
     }
@@ -171,7 +300,7 @@ impl ProgressColumn for TransferSpeedColumn {
 pub struct TimeRemainingColumn;
 
 impl ProgressColumn for TimeRemainingColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
         let eta = match task.eta() {
              Some(d) => format_duration(d),
              None => "-:--:--".to_string(),
@@ -201,7 +330,7 @@ impl MofNColumn {
 }
 
 impl ProgressColumn for MofNColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
         let completed = task.completed;
         let total = task.total.unwrap_or(0);
         vec![Span::styled(format!("{}{}{}", completed, self.separator, total), Style::new().foreground(Color::Green))]
@@ -212,7 +341,7 @@ impl ProgressColumn for MofNColumn {
 pub struct ElapsedColumn;
 
 impl ProgressColumn for ElapsedColumn {
-    fn render(&self, task: &Task) -> Vec<Span> {
+    fn render(&self, task: &Task, _now: Instant) -> Vec<Span> {
         let elapsed = task.elapsed();
         vec![Span::styled(format_duration(elapsed), Style::new().foreground(Color::Cyan))]
     }
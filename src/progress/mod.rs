@@ -11,7 +11,7 @@ mod spinner;
 mod status;
 mod track;
 
-pub use bar::{Progress, ProgressBar, ProgressColumn, Task};
-pub use spinner::{Spinner, SpinnerStyle};
+pub use bar::{Progress, ProgressBar, ProgressColumn, Task, TaskUnit};
+pub use spinner::{Spinner, SpinnerBoard, SpinnerBoardView, SpinnerFrames, SpinnerManager, SpinnerStyle};
 pub use status::{Status, with_status};
 pub use track::track;
@@ -2,15 +2,44 @@
 //!
 //! Provides 85 spinner styles from cli-spinners.
 //!
+//! Each style (other than the default, `Dots`, which is always available)
+//! sits behind its own `spinner-<name>` Cargo feature, mirroring spinoff
+//! 0.7's per-spinner feature split, so a size-sensitive embedded/WASM build
+//! can pull in only the handful it actually uses instead of all 85 static
+//! frame tables. The crate's default feature set enables all of them
+//! (`all-spinners`); `from_name` simply returns `None` for a style whose
+//! feature isn't enabled, the same as it does for an unrecognized name.
+//!
 //! # Attribution
 //! Spinner definitions are sourced from cli-spinners:
 //! MIT License - Copyright (c) Sindre Sorhus <sindresorhus@gmail.com>
 //! https://github.com/sindresorhus/cli-spinners
 
 use super::spinner_data::*;
+use crate::console::{Console, RenderContext};
+use crate::renderable::{Renderable, Segment};
 use crate::style::{Color, Style};
 use crate::text::Span;
-use std::time::Instant;
+use crossterm::{cursor, execute, terminal};
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Whether spinner output should include ANSI color escapes, mirroring
+/// hwylterm's color-support resolution order:
+/// 1. `NO_COLOR` set to any non-empty value -> no color.
+/// 2. `FAST_RICH_FORCE_COLOR` set (to anything) -> color.
+/// 3. Otherwise, color only if stdout is a TTY.
+fn detect_color_support() -> bool {
+    if std::env::var("NO_COLOR").is_ok_and(|v| !v.is_empty()) {
+        return false;
+    }
+    if std::env::var("FAST_RICH_FORCE_COLOR").is_ok() {
+        return true;
+    }
+    std::io::stdout().is_terminal()
+}
 
 /// Spinner animation style.
 ///
@@ -33,192 +62,276 @@ pub enum SpinnerStyle {
     #[default]
     Dots,
     /// Dots variant 2
+    #[cfg(feature = "spinner-dots2")]
     Dots2,
     /// Dots variant 3
+    #[cfg(feature = "spinner-dots3")]
     Dots3,
     /// Dots variant 4
+    #[cfg(feature = "spinner-dots4")]
     Dots4,
     /// Dots variant 5
+    #[cfg(feature = "spinner-dots5")]
     Dots5,
     /// Dots variant 6
+    #[cfg(feature = "spinner-dots6")]
     Dots6,
     /// Dots variant 7
+    #[cfg(feature = "spinner-dots7")]
     Dots7,
     /// Dots variant 8
+    #[cfg(feature = "spinner-dots8")]
     Dots8,
     /// Dots variant 9
+    #[cfg(feature = "spinner-dots9")]
     Dots9,
     /// Dots variant 10
+    #[cfg(feature = "spinner-dots10")]
     Dots10,
     /// Dots variant 11
+    #[cfg(feature = "spinner-dots11")]
     Dots11,
     /// Dots variant 12 (two-character)
+    #[cfg(feature = "spinner-dots12")]
     Dots12,
     /// Dots variant 13
+    #[cfg(feature = "spinner-dots13")]
     Dots13,
     /// Dots variant 14
+    #[cfg(feature = "spinner-dots14")]
     Dots14,
     /// Circular dots pattern
+    #[cfg(feature = "spinner-dots-circle")]
     DotsCircle,
     /// Sand falling animation
+    #[cfg(feature = "spinner-sand")]
     Sand,
     /// Bounce animation
+    #[cfg(feature = "spinner-bounce")]
     Bounce,
 
     // ==================== LINES ====================
     /// Classic line spinner (-\|/)
+    #[cfg(feature = "spinner-line")]
     Line,
     /// Line variant 2
+    #[cfg(feature = "spinner-line2")]
     Line2,
     /// Pipe corners
+    #[cfg(feature = "spinner-pipe")]
     Pipe,
     /// Rolling line
+    #[cfg(feature = "spinner-rolling-line")]
     RollingLine,
     /// Simple dots (...)
+    #[cfg(feature = "spinner-simple-dots")]
     SimpleDots,
     /// Simple dots scrolling
+    #[cfg(feature = "spinner-simple-dots-scrolling")]
     SimpleDotsScrolling,
 
     // ==================== STARS ====================
     /// Star animation
+    #[cfg(feature = "spinner-star")]
     Star,
     /// Star variant 2
+    #[cfg(feature = "spinner-star2")]
     Star2,
 
     // ==================== SHAPES ====================
     /// Arc animation
+    #[cfg(feature = "spinner-arc")]
     Arc,
     /// Circle animation
+    #[cfg(feature = "spinner-circle")]
     Circle,
     /// Circle halves
+    #[cfg(feature = "spinner-circle-halves")]
     CircleHalves,
     /// Circle quarters
+    #[cfg(feature = "spinner-circle-quarters")]
     CircleQuarters,
     /// Square corners
+    #[cfg(feature = "spinner-square-corners")]
     SquareCorners,
     /// Triangle animation
+    #[cfg(feature = "spinner-triangle")]
     Triangle,
     /// Binary sequence
+    #[cfg(feature = "spinner-binary")]
     Binary,
     /// Squish animation
+    #[cfg(feature = "spinner-squish")]
     Squish,
     /// Flip animation
+    #[cfg(feature = "spinner-flip")]
     Flip,
     /// Hamburger menu animation
+    #[cfg(feature = "spinner-hamburger")]
     Hamburger,
 
     // ==================== BOXES ====================
     /// Box bounce
+    #[cfg(feature = "spinner-box-bounce")]
     BoxBounce,
     /// Box bounce variant 2
+    #[cfg(feature = "spinner-box-bounce2")]
     BoxBounce2,
     /// Noise animation
+    #[cfg(feature = "spinner-noise")]
     Noise,
 
     // ==================== GROWING ====================
     /// Vertical growing bar
+    #[cfg(feature = "spinner-grow-vertical")]
     GrowVertical,
     /// Horizontal growing bar
+    #[cfg(feature = "spinner-grow-horizontal")]
     GrowHorizontal,
     /// Balloon animation
+    #[cfg(feature = "spinner-balloon")]
     Balloon,
     /// Balloon variant 2
+    #[cfg(feature = "spinner-balloon2")]
     Balloon2,
 
     // ==================== TOGGLES ====================
     /// Toggle animation
+    #[cfg(feature = "spinner-toggle")]
     Toggle,
     /// Toggle variant 2
+    #[cfg(feature = "spinner-toggle2")]
     Toggle2,
     /// Toggle variant 3
+    #[cfg(feature = "spinner-toggle3")]
     Toggle3,
     /// Toggle variant 4
+    #[cfg(feature = "spinner-toggle4")]
     Toggle4,
     /// Toggle variant 5
+    #[cfg(feature = "spinner-toggle5")]
     Toggle5,
     /// Toggle variant 6
+    #[cfg(feature = "spinner-toggle6")]
     Toggle6,
     /// Toggle variant 7
+    #[cfg(feature = "spinner-toggle7")]
     Toggle7,
     /// Toggle variant 8
+    #[cfg(feature = "spinner-toggle8")]
     Toggle8,
     /// Toggle variant 9
+    #[cfg(feature = "spinner-toggle9")]
     Toggle9,
     /// Toggle variant 10
+    #[cfg(feature = "spinner-toggle10")]
     Toggle10,
     /// Toggle variant 11
+    #[cfg(feature = "spinner-toggle11")]
     Toggle11,
     /// Toggle variant 12
+    #[cfg(feature = "spinner-toggle12")]
     Toggle12,
     /// Toggle variant 13
+    #[cfg(feature = "spinner-toggle13")]
     Toggle13,
 
     // ==================== ARROWS ====================
     /// Arrow animation
+    #[cfg(feature = "spinner-arrow")]
     Arrow,
     /// Arrow variant 2 (emoji)
+    #[cfg(feature = "spinner-arrow2")]
     Arrow2,
     /// Arrow variant 3
+    #[cfg(feature = "spinner-arrow3")]
     Arrow3,
 
     // ==================== ANIMATIONS ====================
     /// Bouncing bar animation
+    #[cfg(feature = "spinner-bouncing-bar")]
     BouncingBar,
     /// Bouncing ball animation
+    #[cfg(feature = "spinner-bouncing-ball")]
     BouncingBall,
     /// Pong game animation
+    #[cfg(feature = "spinner-pong")]
     Pong,
     /// Shark animation
+    #[cfg(feature = "spinner-shark")]
     Shark,
     /// Beta wave animation
+    #[cfg(feature = "spinner-beta-wave")]
     BetaWave,
     /// Aesthetic loading bar
+    #[cfg(feature = "spinner-aesthetic")]
     Aesthetic,
     /// Material design animation
+    #[cfg(feature = "spinner-material")]
     Material,
 
     // ==================== EMOJI ====================
     /// Clock animation 🕐
+    #[cfg(feature = "spinner-clock")]
     Clock,
     /// Moon phases 🌑🌒🌓🌔🌕
+    #[cfg(feature = "spinner-moon")]
     Moon,
     /// Earth rotation 🌍🌎🌏
+    #[cfg(feature = "spinner-earth")]
     Earth,
     /// Hearts 💛💙💜💚
+    #[cfg(feature = "spinner-hearts")]
     Hearts,
     /// Smiley 😄
+    #[cfg(feature = "spinner-smiley")]
     Smiley,
     /// See no evil monkey 🙈🙉🙊
+    #[cfg(feature = "spinner-monkey")]
     Monkey,
     /// Runner 🚶🏃
+    #[cfg(feature = "spinner-runner")]
     Runner,
     /// Weather animation ☀️🌧
+    #[cfg(feature = "spinner-weather")]
     Weather,
     /// Christmas tree 🌲🎄
+    #[cfg(feature = "spinner-christmas")]
     Christmas,
     /// Grenade explosion
+    #[cfg(feature = "spinner-grenade")]
     Grenade,
     /// Finger dance 🤘🤟
+    #[cfg(feature = "spinner-finger-dance")]
     FingerDance,
     /// Speaker 🔈🔉🔊
+    #[cfg(feature = "spinner-speaker")]
     Speaker,
     /// Orange pulse 🔸🔶🟠
+    #[cfg(feature = "spinner-orange-pulse")]
     OrangePulse,
     /// Blue pulse 🔹🔷🔵
+    #[cfg(feature = "spinner-blue-pulse")]
     BluePulse,
     /// Orange-blue pulse
+    #[cfg(feature = "spinner-orange-blue-pulse")]
     OrangeBluePulse,
     /// Time travel (reverse clock)
+    #[cfg(feature = "spinner-time-travel")]
     TimeTravel,
     /// Mind blown 🤯
+    #[cfg(feature = "spinner-mindblown")]
     Mindblown,
 
     // ==================== MISC ====================
     /// dqpb animation
+    #[cfg(feature = "spinner-dqpb")]
     Dqpb,
     /// Point animation
+    #[cfg(feature = "spinner-point")]
     Point,
     /// Layer animation
+    #[cfg(feature = "spinner-layer")]
     Layer,
 }
 
@@ -228,109 +341,193 @@ impl SpinnerStyle {
         match self {
             // Braille dots
             SpinnerStyle::Dots => &DOTS,
+            #[cfg(feature = "spinner-dots2")]
             SpinnerStyle::Dots2 => &DOTS2,
+            #[cfg(feature = "spinner-dots3")]
             SpinnerStyle::Dots3 => &DOTS3,
+            #[cfg(feature = "spinner-dots4")]
             SpinnerStyle::Dots4 => &DOTS4,
+            #[cfg(feature = "spinner-dots5")]
             SpinnerStyle::Dots5 => &DOTS5,
+            #[cfg(feature = "spinner-dots6")]
             SpinnerStyle::Dots6 => &DOTS6,
+            #[cfg(feature = "spinner-dots7")]
             SpinnerStyle::Dots7 => &DOTS7,
+            #[cfg(feature = "spinner-dots8")]
             SpinnerStyle::Dots8 => &DOTS8,
+            #[cfg(feature = "spinner-dots9")]
             SpinnerStyle::Dots9 => &DOTS9,
+            #[cfg(feature = "spinner-dots10")]
             SpinnerStyle::Dots10 => &DOTS10,
+            #[cfg(feature = "spinner-dots11")]
             SpinnerStyle::Dots11 => &DOTS11,
+            #[cfg(feature = "spinner-dots12")]
             SpinnerStyle::Dots12 => &DOTS12,
+            #[cfg(feature = "spinner-dots13")]
             SpinnerStyle::Dots13 => &DOTS13,
+            #[cfg(feature = "spinner-dots14")]
             SpinnerStyle::Dots14 => &DOTS14,
+            #[cfg(feature = "spinner-dots-circle")]
             SpinnerStyle::DotsCircle => &DOTS_CIRCLE,
+            #[cfg(feature = "spinner-sand")]
             SpinnerStyle::Sand => &SAND,
+            #[cfg(feature = "spinner-bounce")]
             SpinnerStyle::Bounce => &BOUNCE,
 
             // Lines
+            #[cfg(feature = "spinner-line")]
             SpinnerStyle::Line => &LINE,
+            #[cfg(feature = "spinner-line2")]
             SpinnerStyle::Line2 => &LINE2,
+            #[cfg(feature = "spinner-pipe")]
             SpinnerStyle::Pipe => &PIPE,
+            #[cfg(feature = "spinner-rolling-line")]
             SpinnerStyle::RollingLine => &ROLLING_LINE,
+            #[cfg(feature = "spinner-simple-dots")]
             SpinnerStyle::SimpleDots => &SIMPLE_DOTS,
+            #[cfg(feature = "spinner-simple-dots-scrolling")]
             SpinnerStyle::SimpleDotsScrolling => &SIMPLE_DOTS_SCROLLING,
 
             // Stars
+            #[cfg(feature = "spinner-star")]
             SpinnerStyle::Star => &STAR,
+            #[cfg(feature = "spinner-star2")]
             SpinnerStyle::Star2 => &STAR2,
 
             // Shapes
+            #[cfg(feature = "spinner-arc")]
             SpinnerStyle::Arc => &ARC,
+            #[cfg(feature = "spinner-circle")]
             SpinnerStyle::Circle => &CIRCLE,
+            #[cfg(feature = "spinner-circle-halves")]
             SpinnerStyle::CircleHalves => &CIRCLE_HALVES,
+            #[cfg(feature = "spinner-circle-quarters")]
             SpinnerStyle::CircleQuarters => &CIRCLE_QUARTERS,
+            #[cfg(feature = "spinner-square-corners")]
             SpinnerStyle::SquareCorners => &SQUARE_CORNERS,
+            #[cfg(feature = "spinner-triangle")]
             SpinnerStyle::Triangle => &TRIANGLE,
+            #[cfg(feature = "spinner-binary")]
             SpinnerStyle::Binary => &BINARY,
+            #[cfg(feature = "spinner-squish")]
             SpinnerStyle::Squish => &SQUISH,
+            #[cfg(feature = "spinner-flip")]
             SpinnerStyle::Flip => &FLIP,
+            #[cfg(feature = "spinner-hamburger")]
             SpinnerStyle::Hamburger => &HAMBURGER,
 
             // Boxes
+            #[cfg(feature = "spinner-box-bounce")]
             SpinnerStyle::BoxBounce => &BOX_BOUNCE,
+            #[cfg(feature = "spinner-box-bounce2")]
             SpinnerStyle::BoxBounce2 => &BOX_BOUNCE2,
+            #[cfg(feature = "spinner-noise")]
             SpinnerStyle::Noise => &NOISE,
 
             // Growing
+            #[cfg(feature = "spinner-grow-vertical")]
             SpinnerStyle::GrowVertical => &GROW_VERTICAL,
+            #[cfg(feature = "spinner-grow-horizontal")]
             SpinnerStyle::GrowHorizontal => &GROW_HORIZONTAL,
+            #[cfg(feature = "spinner-balloon")]
             SpinnerStyle::Balloon => &BALLOON,
+            #[cfg(feature = "spinner-balloon2")]
             SpinnerStyle::Balloon2 => &BALLOON2,
 
             // Toggles
+            #[cfg(feature = "spinner-toggle")]
             SpinnerStyle::Toggle => &TOGGLE,
+            #[cfg(feature = "spinner-toggle2")]
             SpinnerStyle::Toggle2 => &TOGGLE2,
+            #[cfg(feature = "spinner-toggle3")]
             SpinnerStyle::Toggle3 => &TOGGLE3,
+            #[cfg(feature = "spinner-toggle4")]
             SpinnerStyle::Toggle4 => &TOGGLE4,
+            #[cfg(feature = "spinner-toggle5")]
             SpinnerStyle::Toggle5 => &TOGGLE5,
+            #[cfg(feature = "spinner-toggle6")]
             SpinnerStyle::Toggle6 => &TOGGLE6,
+            #[cfg(feature = "spinner-toggle7")]
             SpinnerStyle::Toggle7 => &TOGGLE7,
+            #[cfg(feature = "spinner-toggle8")]
             SpinnerStyle::Toggle8 => &TOGGLE8,
+            #[cfg(feature = "spinner-toggle9")]
             SpinnerStyle::Toggle9 => &TOGGLE9,
+            #[cfg(feature = "spinner-toggle10")]
             SpinnerStyle::Toggle10 => &TOGGLE10,
+            #[cfg(feature = "spinner-toggle11")]
             SpinnerStyle::Toggle11 => &TOGGLE11,
+            #[cfg(feature = "spinner-toggle12")]
             SpinnerStyle::Toggle12 => &TOGGLE12,
+            #[cfg(feature = "spinner-toggle13")]
             SpinnerStyle::Toggle13 => &TOGGLE13,
 
             // Arrows
+            #[cfg(feature = "spinner-arrow")]
             SpinnerStyle::Arrow => &ARROW,
+            #[cfg(feature = "spinner-arrow2")]
             SpinnerStyle::Arrow2 => &ARROW2,
+            #[cfg(feature = "spinner-arrow3")]
             SpinnerStyle::Arrow3 => &ARROW3,
 
             // Animations
+            #[cfg(feature = "spinner-bouncing-bar")]
             SpinnerStyle::BouncingBar => &BOUNCING_BAR,
+            #[cfg(feature = "spinner-bouncing-ball")]
             SpinnerStyle::BouncingBall => &BOUNCING_BALL,
+            #[cfg(feature = "spinner-pong")]
             SpinnerStyle::Pong => &PONG,
+            #[cfg(feature = "spinner-shark")]
             SpinnerStyle::Shark => &SHARK,
+            #[cfg(feature = "spinner-beta-wave")]
             SpinnerStyle::BetaWave => &BETA_WAVE,
+            #[cfg(feature = "spinner-aesthetic")]
             SpinnerStyle::Aesthetic => &AESTHETIC,
+            #[cfg(feature = "spinner-material")]
             SpinnerStyle::Material => &MATERIAL,
 
             // Emoji
+            #[cfg(feature = "spinner-clock")]
             SpinnerStyle::Clock => &CLOCK,
+            #[cfg(feature = "spinner-moon")]
             SpinnerStyle::Moon => &MOON,
+            #[cfg(feature = "spinner-earth")]
             SpinnerStyle::Earth => &EARTH,
+            #[cfg(feature = "spinner-hearts")]
             SpinnerStyle::Hearts => &HEARTS,
+            #[cfg(feature = "spinner-smiley")]
             SpinnerStyle::Smiley => &SMILEY,
+            #[cfg(feature = "spinner-monkey")]
             SpinnerStyle::Monkey => &MONKEY,
+            #[cfg(feature = "spinner-runner")]
             SpinnerStyle::Runner => &RUNNER,
+            #[cfg(feature = "spinner-weather")]
             SpinnerStyle::Weather => &WEATHER,
+            #[cfg(feature = "spinner-christmas")]
             SpinnerStyle::Christmas => &CHRISTMAS,
+            #[cfg(feature = "spinner-grenade")]
             SpinnerStyle::Grenade => &GRENADE,
+            #[cfg(feature = "spinner-finger-dance")]
             SpinnerStyle::FingerDance => &FINGER_DANCE,
+            #[cfg(feature = "spinner-speaker")]
             SpinnerStyle::Speaker => &SPEAKER,
+            #[cfg(feature = "spinner-orange-pulse")]
             SpinnerStyle::OrangePulse => &ORANGE_PULSE,
+            #[cfg(feature = "spinner-blue-pulse")]
             SpinnerStyle::BluePulse => &BLUE_PULSE,
+            #[cfg(feature = "spinner-orange-blue-pulse")]
             SpinnerStyle::OrangeBluePulse => &ORANGE_BLUE_PULSE,
+            #[cfg(feature = "spinner-time-travel")]
             SpinnerStyle::TimeTravel => &TIME_TRAVEL,
+            #[cfg(feature = "spinner-mindblown")]
             SpinnerStyle::Mindblown => &MINDBLOWN,
 
             // Misc
+            #[cfg(feature = "spinner-dqpb")]
             SpinnerStyle::Dqpb => &DQPB,
+            #[cfg(feature = "spinner-point")]
             SpinnerStyle::Point => &POINT,
+            #[cfg(feature = "spinner-layer")]
             SpinnerStyle::Layer => &LAYER,
         }
     }
@@ -363,212 +560,466 @@ impl SpinnerStyle {
         match name_lower.as_str() {
             // Braille dots
             "dots" => Some(SpinnerStyle::Dots),
+            #[cfg(feature = "spinner-dots2")]
             "dots2" => Some(SpinnerStyle::Dots2),
+            #[cfg(feature = "spinner-dots3")]
             "dots3" => Some(SpinnerStyle::Dots3),
+            #[cfg(feature = "spinner-dots4")]
             "dots4" => Some(SpinnerStyle::Dots4),
+            #[cfg(feature = "spinner-dots5")]
             "dots5" => Some(SpinnerStyle::Dots5),
+            #[cfg(feature = "spinner-dots6")]
             "dots6" => Some(SpinnerStyle::Dots6),
+            #[cfg(feature = "spinner-dots7")]
             "dots7" => Some(SpinnerStyle::Dots7),
+            #[cfg(feature = "spinner-dots8")]
             "dots8" => Some(SpinnerStyle::Dots8),
+            #[cfg(feature = "spinner-dots9")]
             "dots9" => Some(SpinnerStyle::Dots9),
+            #[cfg(feature = "spinner-dots10")]
             "dots10" => Some(SpinnerStyle::Dots10),
+            #[cfg(feature = "spinner-dots11")]
             "dots11" => Some(SpinnerStyle::Dots11),
+            #[cfg(feature = "spinner-dots12")]
             "dots12" => Some(SpinnerStyle::Dots12),
+            #[cfg(feature = "spinner-dots13")]
             "dots13" => Some(SpinnerStyle::Dots13),
+            #[cfg(feature = "spinner-dots14")]
             "dots14" => Some(SpinnerStyle::Dots14),
+            #[cfg(feature = "spinner-dots-circle")]
             "dotscircle" => Some(SpinnerStyle::DotsCircle),
+            #[cfg(feature = "spinner-sand")]
             "sand" => Some(SpinnerStyle::Sand),
+            #[cfg(feature = "spinner-bounce")]
             "bounce" => Some(SpinnerStyle::Bounce),
 
             // Lines
+            #[cfg(feature = "spinner-line")]
             "line" => Some(SpinnerStyle::Line),
+            #[cfg(feature = "spinner-line2")]
             "line2" => Some(SpinnerStyle::Line2),
+            #[cfg(feature = "spinner-pipe")]
             "pipe" => Some(SpinnerStyle::Pipe),
+            #[cfg(feature = "spinner-rolling-line")]
             "rollingline" => Some(SpinnerStyle::RollingLine),
+            #[cfg(feature = "spinner-simple-dots")]
             "simpledots" => Some(SpinnerStyle::SimpleDots),
+            #[cfg(feature = "spinner-simple-dots-scrolling")]
             "simpledotsscrolling" => Some(SpinnerStyle::SimpleDotsScrolling),
 
             // Stars
+            #[cfg(feature = "spinner-star")]
             "star" => Some(SpinnerStyle::Star),
+            #[cfg(feature = "spinner-star2")]
             "star2" => Some(SpinnerStyle::Star2),
 
             // Shapes
+            #[cfg(feature = "spinner-arc")]
             "arc" => Some(SpinnerStyle::Arc),
+            #[cfg(feature = "spinner-circle")]
             "circle" => Some(SpinnerStyle::Circle),
+            #[cfg(feature = "spinner-circle-halves")]
             "circlehalves" => Some(SpinnerStyle::CircleHalves),
+            #[cfg(feature = "spinner-circle-quarters")]
             "circlequarters" => Some(SpinnerStyle::CircleQuarters),
+            #[cfg(feature = "spinner-square-corners")]
             "squarecorners" => Some(SpinnerStyle::SquareCorners),
+            #[cfg(feature = "spinner-triangle")]
             "triangle" => Some(SpinnerStyle::Triangle),
+            #[cfg(feature = "spinner-binary")]
             "binary" => Some(SpinnerStyle::Binary),
+            #[cfg(feature = "spinner-squish")]
             "squish" => Some(SpinnerStyle::Squish),
+            #[cfg(feature = "spinner-flip")]
             "flip" => Some(SpinnerStyle::Flip),
+            #[cfg(feature = "spinner-hamburger")]
             "hamburger" => Some(SpinnerStyle::Hamburger),
 
             // Boxes
+            #[cfg(feature = "spinner-box-bounce")]
             "boxbounce" => Some(SpinnerStyle::BoxBounce),
+            #[cfg(feature = "spinner-box-bounce2")]
             "boxbounce2" => Some(SpinnerStyle::BoxBounce2),
+            #[cfg(feature = "spinner-noise")]
             "noise" => Some(SpinnerStyle::Noise),
 
             // Growing
+            #[cfg(feature = "spinner-grow-vertical")]
             "growvertical" => Some(SpinnerStyle::GrowVertical),
+            #[cfg(feature = "spinner-grow-horizontal")]
             "growhorizontal" => Some(SpinnerStyle::GrowHorizontal),
+            #[cfg(feature = "spinner-balloon")]
             "balloon" => Some(SpinnerStyle::Balloon),
+            #[cfg(feature = "spinner-balloon2")]
             "balloon2" => Some(SpinnerStyle::Balloon2),
 
             // Toggles
+            #[cfg(feature = "spinner-toggle")]
             "toggle" => Some(SpinnerStyle::Toggle),
+            #[cfg(feature = "spinner-toggle2")]
             "toggle2" => Some(SpinnerStyle::Toggle2),
+            #[cfg(feature = "spinner-toggle3")]
             "toggle3" => Some(SpinnerStyle::Toggle3),
+            #[cfg(feature = "spinner-toggle4")]
             "toggle4" => Some(SpinnerStyle::Toggle4),
+            #[cfg(feature = "spinner-toggle5")]
             "toggle5" => Some(SpinnerStyle::Toggle5),
+            #[cfg(feature = "spinner-toggle6")]
             "toggle6" => Some(SpinnerStyle::Toggle6),
+            #[cfg(feature = "spinner-toggle7")]
             "toggle7" => Some(SpinnerStyle::Toggle7),
+            #[cfg(feature = "spinner-toggle8")]
             "toggle8" => Some(SpinnerStyle::Toggle8),
+            #[cfg(feature = "spinner-toggle9")]
             "toggle9" => Some(SpinnerStyle::Toggle9),
+            #[cfg(feature = "spinner-toggle10")]
             "toggle10" => Some(SpinnerStyle::Toggle10),
+            #[cfg(feature = "spinner-toggle11")]
             "toggle11" => Some(SpinnerStyle::Toggle11),
+            #[cfg(feature = "spinner-toggle12")]
             "toggle12" => Some(SpinnerStyle::Toggle12),
+            #[cfg(feature = "spinner-toggle13")]
             "toggle13" => Some(SpinnerStyle::Toggle13),
 
             // Arrows
+            #[cfg(feature = "spinner-arrow")]
             "arrow" => Some(SpinnerStyle::Arrow),
+            #[cfg(feature = "spinner-arrow2")]
             "arrow2" => Some(SpinnerStyle::Arrow2),
+            #[cfg(feature = "spinner-arrow3")]
             "arrow3" => Some(SpinnerStyle::Arrow3),
 
             // Animations
+            #[cfg(feature = "spinner-bouncing-bar")]
             "bouncingbar" => Some(SpinnerStyle::BouncingBar),
+            #[cfg(feature = "spinner-bouncing-ball")]
             "bouncingball" => Some(SpinnerStyle::BouncingBall),
+            #[cfg(feature = "spinner-pong")]
             "pong" => Some(SpinnerStyle::Pong),
+            #[cfg(feature = "spinner-shark")]
             "shark" => Some(SpinnerStyle::Shark),
+            #[cfg(feature = "spinner-beta-wave")]
             "betawave" => Some(SpinnerStyle::BetaWave),
+            #[cfg(feature = "spinner-aesthetic")]
             "aesthetic" => Some(SpinnerStyle::Aesthetic),
+            #[cfg(feature = "spinner-material")]
             "material" => Some(SpinnerStyle::Material),
 
             // Emoji
+            #[cfg(feature = "spinner-clock")]
             "clock" => Some(SpinnerStyle::Clock),
+            #[cfg(feature = "spinner-moon")]
             "moon" => Some(SpinnerStyle::Moon),
+            #[cfg(feature = "spinner-earth")]
             "earth" => Some(SpinnerStyle::Earth),
+            #[cfg(feature = "spinner-hearts")]
             "hearts" => Some(SpinnerStyle::Hearts),
+            #[cfg(feature = "spinner-smiley")]
             "smiley" => Some(SpinnerStyle::Smiley),
+            #[cfg(feature = "spinner-monkey")]
             "monkey" => Some(SpinnerStyle::Monkey),
+            #[cfg(feature = "spinner-runner")]
             "runner" => Some(SpinnerStyle::Runner),
+            #[cfg(feature = "spinner-weather")]
             "weather" => Some(SpinnerStyle::Weather),
+            #[cfg(feature = "spinner-christmas")]
             "christmas" => Some(SpinnerStyle::Christmas),
+            #[cfg(feature = "spinner-grenade")]
             "grenade" => Some(SpinnerStyle::Grenade),
+            #[cfg(feature = "spinner-finger-dance")]
             "fingerdance" => Some(SpinnerStyle::FingerDance),
+            #[cfg(feature = "spinner-speaker")]
             "speaker" => Some(SpinnerStyle::Speaker),
+            #[cfg(feature = "spinner-orange-pulse")]
             "orangepulse" => Some(SpinnerStyle::OrangePulse),
+            #[cfg(feature = "spinner-blue-pulse")]
             "bluepulse" => Some(SpinnerStyle::BluePulse),
+            #[cfg(feature = "spinner-orange-blue-pulse")]
             "orangebluepulse" => Some(SpinnerStyle::OrangeBluePulse),
+            #[cfg(feature = "spinner-time-travel")]
             "timetravel" => Some(SpinnerStyle::TimeTravel),
+            #[cfg(feature = "spinner-mindblown")]
             "mindblown" => Some(SpinnerStyle::Mindblown),
 
             // Misc
+            #[cfg(feature = "spinner-dqpb")]
             "dqpb" => Some(SpinnerStyle::Dqpb),
+            #[cfg(feature = "spinner-point")]
             "point" => Some(SpinnerStyle::Point),
+            #[cfg(feature = "spinner-layer")]
             "layer" => Some(SpinnerStyle::Layer),
 
             _ => None,
         }
     }
 
-    /// Get all available spinner style names.
+    /// Pick a uniformly random built-in style, reseeded from the system
+    /// clock each call. Handy for demos and CLIs that want visual variety
+    /// without the author choosing a style.
+    pub fn random() -> SpinnerStyle {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self::random_with_seed(seed)
+    }
+
+    /// Deterministically pick one of the built-in styles from `seed`, so
+    /// tests and reproducible runs don't flake the way `random()` would.
+    pub fn random_with_seed(seed: u64) -> SpinnerStyle {
+        let names = Self::all_names();
+        let idx = (splitmix64(seed) as usize) % names.len();
+        Self::from_name(names[idx]).expect("all_names() entries always resolve via from_name()")
+    }
+
+    /// Get all available spinner style names whose feature is enabled.
     pub fn all_names() -> &'static [&'static str] {
-        &[
-            "dots",
-            "dots2",
-            "dots3",
-            "dots4",
-            "dots5",
-            "dots6",
-            "dots7",
-            "dots8",
-            "dots9",
-            "dots10",
-            "dots11",
-            "dots12",
-            "dots13",
-            "dots14",
-            "dotsCircle",
-            "sand",
-            "bounce",
-            "line",
-            "line2",
-            "pipe",
-            "rollingLine",
-            "simpleDots",
-            "simpleDotsScrolling",
-            "star",
-            "star2",
-            "arc",
-            "circle",
-            "circleHalves",
-            "circleQuarters",
-            "squareCorners",
-            "triangle",
-            "binary",
-            "squish",
-            "flip",
-            "hamburger",
-            "boxBounce",
-            "boxBounce2",
-            "noise",
-            "growVertical",
-            "growHorizontal",
-            "balloon",
-            "balloon2",
-            "toggle",
-            "toggle2",
-            "toggle3",
-            "toggle4",
-            "toggle5",
-            "toggle6",
-            "toggle7",
-            "toggle8",
-            "toggle9",
-            "toggle10",
-            "toggle11",
-            "toggle12",
-            "toggle13",
-            "arrow",
-            "arrow2",
-            "arrow3",
-            "bouncingBar",
-            "bouncingBall",
-            "pong",
-            "shark",
-            "betaWave",
-            "aesthetic",
-            "material",
-            "clock",
-            "moon",
-            "earth",
-            "hearts",
-            "smiley",
-            "monkey",
-            "runner",
-            "weather",
-            "christmas",
-            "grenade",
-            "fingerDance",
-            "speaker",
-            "orangePulse",
-            "bluePulse",
-            "orangeBluePulse",
-            "timeTravel",
-            "mindblown",
-            "dqpb",
-            "point",
-            "layer",
-        ]
+        static NAMES: std::sync::LazyLock<Vec<&'static str>> = std::sync::LazyLock::new(|| {
+            let mut names = Vec::new();
+            names.push("dots");
+            #[cfg(feature = "spinner-dots2")]
+            names.push("dots2");
+            #[cfg(feature = "spinner-dots3")]
+            names.push("dots3");
+            #[cfg(feature = "spinner-dots4")]
+            names.push("dots4");
+            #[cfg(feature = "spinner-dots5")]
+            names.push("dots5");
+            #[cfg(feature = "spinner-dots6")]
+            names.push("dots6");
+            #[cfg(feature = "spinner-dots7")]
+            names.push("dots7");
+            #[cfg(feature = "spinner-dots8")]
+            names.push("dots8");
+            #[cfg(feature = "spinner-dots9")]
+            names.push("dots9");
+            #[cfg(feature = "spinner-dots10")]
+            names.push("dots10");
+            #[cfg(feature = "spinner-dots11")]
+            names.push("dots11");
+            #[cfg(feature = "spinner-dots12")]
+            names.push("dots12");
+            #[cfg(feature = "spinner-dots13")]
+            names.push("dots13");
+            #[cfg(feature = "spinner-dots14")]
+            names.push("dots14");
+            #[cfg(feature = "spinner-dots-circle")]
+            names.push("dotsCircle");
+            #[cfg(feature = "spinner-sand")]
+            names.push("sand");
+            #[cfg(feature = "spinner-bounce")]
+            names.push("bounce");
+            #[cfg(feature = "spinner-line")]
+            names.push("line");
+            #[cfg(feature = "spinner-line2")]
+            names.push("line2");
+            #[cfg(feature = "spinner-pipe")]
+            names.push("pipe");
+            #[cfg(feature = "spinner-rolling-line")]
+            names.push("rollingLine");
+            #[cfg(feature = "spinner-simple-dots")]
+            names.push("simpleDots");
+            #[cfg(feature = "spinner-simple-dots-scrolling")]
+            names.push("simpleDotsScrolling");
+            #[cfg(feature = "spinner-star")]
+            names.push("star");
+            #[cfg(feature = "spinner-star2")]
+            names.push("star2");
+            #[cfg(feature = "spinner-arc")]
+            names.push("arc");
+            #[cfg(feature = "spinner-circle")]
+            names.push("circle");
+            #[cfg(feature = "spinner-circle-halves")]
+            names.push("circleHalves");
+            #[cfg(feature = "spinner-circle-quarters")]
+            names.push("circleQuarters");
+            #[cfg(feature = "spinner-square-corners")]
+            names.push("squareCorners");
+            #[cfg(feature = "spinner-triangle")]
+            names.push("triangle");
+            #[cfg(feature = "spinner-binary")]
+            names.push("binary");
+            #[cfg(feature = "spinner-squish")]
+            names.push("squish");
+            #[cfg(feature = "spinner-flip")]
+            names.push("flip");
+            #[cfg(feature = "spinner-hamburger")]
+            names.push("hamburger");
+            #[cfg(feature = "spinner-box-bounce")]
+            names.push("boxBounce");
+            #[cfg(feature = "spinner-box-bounce2")]
+            names.push("boxBounce2");
+            #[cfg(feature = "spinner-noise")]
+            names.push("noise");
+            #[cfg(feature = "spinner-grow-vertical")]
+            names.push("growVertical");
+            #[cfg(feature = "spinner-grow-horizontal")]
+            names.push("growHorizontal");
+            #[cfg(feature = "spinner-balloon")]
+            names.push("balloon");
+            #[cfg(feature = "spinner-balloon2")]
+            names.push("balloon2");
+            #[cfg(feature = "spinner-toggle")]
+            names.push("toggle");
+            #[cfg(feature = "spinner-toggle2")]
+            names.push("toggle2");
+            #[cfg(feature = "spinner-toggle3")]
+            names.push("toggle3");
+            #[cfg(feature = "spinner-toggle4")]
+            names.push("toggle4");
+            #[cfg(feature = "spinner-toggle5")]
+            names.push("toggle5");
+            #[cfg(feature = "spinner-toggle6")]
+            names.push("toggle6");
+            #[cfg(feature = "spinner-toggle7")]
+            names.push("toggle7");
+            #[cfg(feature = "spinner-toggle8")]
+            names.push("toggle8");
+            #[cfg(feature = "spinner-toggle9")]
+            names.push("toggle9");
+            #[cfg(feature = "spinner-toggle10")]
+            names.push("toggle10");
+            #[cfg(feature = "spinner-toggle11")]
+            names.push("toggle11");
+            #[cfg(feature = "spinner-toggle12")]
+            names.push("toggle12");
+            #[cfg(feature = "spinner-toggle13")]
+            names.push("toggle13");
+            #[cfg(feature = "spinner-arrow")]
+            names.push("arrow");
+            #[cfg(feature = "spinner-arrow2")]
+            names.push("arrow2");
+            #[cfg(feature = "spinner-arrow3")]
+            names.push("arrow3");
+            #[cfg(feature = "spinner-bouncing-bar")]
+            names.push("bouncingBar");
+            #[cfg(feature = "spinner-bouncing-ball")]
+            names.push("bouncingBall");
+            #[cfg(feature = "spinner-pong")]
+            names.push("pong");
+            #[cfg(feature = "spinner-shark")]
+            names.push("shark");
+            #[cfg(feature = "spinner-beta-wave")]
+            names.push("betaWave");
+            #[cfg(feature = "spinner-aesthetic")]
+            names.push("aesthetic");
+            #[cfg(feature = "spinner-material")]
+            names.push("material");
+            #[cfg(feature = "spinner-clock")]
+            names.push("clock");
+            #[cfg(feature = "spinner-moon")]
+            names.push("moon");
+            #[cfg(feature = "spinner-earth")]
+            names.push("earth");
+            #[cfg(feature = "spinner-hearts")]
+            names.push("hearts");
+            #[cfg(feature = "spinner-smiley")]
+            names.push("smiley");
+            #[cfg(feature = "spinner-monkey")]
+            names.push("monkey");
+            #[cfg(feature = "spinner-runner")]
+            names.push("runner");
+            #[cfg(feature = "spinner-weather")]
+            names.push("weather");
+            #[cfg(feature = "spinner-christmas")]
+            names.push("christmas");
+            #[cfg(feature = "spinner-grenade")]
+            names.push("grenade");
+            #[cfg(feature = "spinner-finger-dance")]
+            names.push("fingerDance");
+            #[cfg(feature = "spinner-speaker")]
+            names.push("speaker");
+            #[cfg(feature = "spinner-orange-pulse")]
+            names.push("orangePulse");
+            #[cfg(feature = "spinner-blue-pulse")]
+            names.push("bluePulse");
+            #[cfg(feature = "spinner-orange-blue-pulse")]
+            names.push("orangeBluePulse");
+            #[cfg(feature = "spinner-time-travel")]
+            names.push("timeTravel");
+            #[cfg(feature = "spinner-mindblown")]
+            names.push("mindblown");
+            #[cfg(feature = "spinner-dqpb")]
+            names.push("dqpb");
+            #[cfg(feature = "spinner-point")]
+            names.push("point");
+            #[cfg(feature = "spinner-layer")]
+            names.push("layer");
+            names
+        });
+        &NAMES
+    }
+}
+
+/// A single splitmix64 step, used to turn a seed into a frame index without
+/// pulling in a dependency on an external RNG crate.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Either a built-in cli-spinners style or a user-supplied frame sequence
+/// and interval, e.g. pasted from tty-spinner (pulse bars `▁▃▅▆▇█▇▆▅▃`,
+/// quarter circles `◴◷◶◵`).
+#[derive(Debug, Clone)]
+pub enum SpinnerFrames {
+    /// One of the 85 built-in cli-spinners definitions.
+    Builtin(SpinnerStyle),
+    /// User-supplied frames and the interval between them, in milliseconds.
+    Custom(Arc<[String]>, u64),
+}
+
+impl SpinnerFrames {
+    /// Interval between frames in milliseconds.
+    pub fn interval_ms(&self) -> u64 {
+        match self {
+            SpinnerFrames::Builtin(style) => style.interval_ms(),
+            SpinnerFrames::Custom(_, interval_ms) => *interval_ms,
+        }
+    }
+
+    /// Number of frames in the sequence.
+    pub fn len(&self) -> usize {
+        match self {
+            SpinnerFrames::Builtin(style) => style.frames().len(),
+            SpinnerFrames::Custom(frames, _) => frames.len(),
+        }
+    }
+
+    /// Whether the sequence has no frames (only reachable with an empty
+    /// custom sequence; built-in styles always have at least one frame).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The frame at `index`.
+    pub fn frame(&self, index: usize) -> &str {
+        match self {
+            SpinnerFrames::Builtin(style) => style.frames()[index],
+            SpinnerFrames::Custom(frames, _) => &frames[index],
+        }
+    }
+}
+
+impl Default for SpinnerFrames {
+    fn default() -> Self {
+        SpinnerFrames::Builtin(SpinnerStyle::default())
+    }
+}
+
+impl From<SpinnerStyle> for SpinnerFrames {
+    fn from(style: SpinnerStyle) -> Self {
+        SpinnerFrames::Builtin(style)
     }
 }
 
 /// A spinner for indeterminate progress.
 #[derive(Debug, Clone)]
 pub struct Spinner {
-    /// Spinner style
-    style: SpinnerStyle,
+    /// Frame source: a built-in style, or user-supplied custom frames.
+    frames: SpinnerFrames,
     /// Start time for animation
     start_time: Instant,
     /// Text to display after the spinner
@@ -577,23 +1028,56 @@ pub struct Spinner {
     spinner_style: Style,
     /// Style for the text
     text_style: Style,
+    /// Explicit color override (`Some(true)` forces plain output,
+    /// `Some(false)` forces color); `None` means auto-detect via
+    /// [`detect_color_support`].
+    no_color: Option<bool>,
 }
 
 impl Spinner {
     /// Create a new spinner with optional text.
     pub fn new(text: &str) -> Self {
         Spinner {
-            style: SpinnerStyle::Dots,
+            frames: SpinnerFrames::default(),
             start_time: Instant::now(),
             text: text.to_string(),
             spinner_style: Style::new().foreground(Color::Cyan),
             text_style: Style::new(),
+            no_color: None,
+        }
+    }
+
+    /// Override automatic color-support detection. `true` forces plain,
+    /// uncolored output (e.g. for logs or CI); `false` forces color even
+    /// when stdout isn't a TTY.
+    pub fn no_color(mut self, no_color: bool) -> Self {
+        self.no_color = Some(no_color);
+        self
+    }
+
+    /// Whether rendering should include ANSI color escapes, honoring any
+    /// explicit [`Spinner::no_color`] override before falling back to
+    /// [`detect_color_support`].
+    fn color_enabled(&self) -> bool {
+        match self.no_color {
+            Some(no_color) => !no_color,
+            None => detect_color_support(),
+        }
+    }
+
+    /// Render `text` styled with `style` if color is enabled, or as plain
+    /// text otherwise.
+    fn styled_or_plain(&self, text: String, style: Style) -> Span {
+        if self.color_enabled() {
+            Span::styled(text, style)
+        } else {
+            Span::raw(text)
         }
     }
 
     /// Set the spinner style.
     pub fn style(mut self, style: SpinnerStyle) -> Self {
-        self.style = style;
+        self.frames = SpinnerFrames::Builtin(style);
         self
     }
 
@@ -602,11 +1086,24 @@ impl Spinner {
     /// Returns `None` if the name is not recognized.
     pub fn style_name(mut self, name: &str) -> Option<Self> {
         SpinnerStyle::from_name(name).map(|s| {
-            self.style = s;
+            self.frames = SpinnerFrames::Builtin(s);
             self
         })
     }
 
+    /// Create a new spinner with a uniformly random built-in style.
+    pub fn new_random(text: &str) -> Self {
+        Spinner::new(text).style(SpinnerStyle::random())
+    }
+
+    /// Drive this spinner from an arbitrary, user-supplied frame sequence
+    /// instead of one of the 85 built-in styles (e.g. frames copied from
+    /// tty-spinner or another terminal animation).
+    pub fn custom(mut self, frames: Vec<String>, interval_ms: u64) -> Self {
+        self.frames = SpinnerFrames::Custom(frames.into(), interval_ms);
+        self
+    }
+
     /// Set the spinner character style.
     pub fn spinner_style(mut self, style: Style) -> Self {
         self.spinner_style = style;
@@ -635,32 +1132,40 @@ impl Spinner {
         &self.text
     }
 
-    /// Get the spinner style.
-    pub fn get_style(&self) -> SpinnerStyle {
-        self.style
+    /// Get the spinner's frame source (a built-in style, or custom frames).
+    pub fn get_frames(&self) -> &SpinnerFrames {
+        &self.frames
     }
 
     /// Get the current frame index.
     fn current_frame_index(&self) -> usize {
         let elapsed_ms = self.start_time.elapsed().as_millis() as u64;
-        let interval = self.style.interval_ms();
-        let frames = self.style.frames();
-        ((elapsed_ms / interval) as usize) % frames.len()
+        let interval = self.frames.interval_ms();
+        ((elapsed_ms / interval) as usize) % self.frames.len()
     }
 
     /// Get the current frame character.
-    pub fn current_frame(&self) -> &'static str {
-        let frames = self.style.frames();
+    pub fn current_frame(&self) -> &str {
         let idx = self.current_frame_index();
-        frames[idx]
+        self.frames.frame(idx)
+    }
+
+    /// Time remaining until this spinner's animation advances to its next
+    /// frame, for a live-display loop coordinating redraw timing (see
+    /// [`SpinnerManager::next_wakeup`]).
+    pub fn time_until_next_frame(&self) -> Duration {
+        let interval = self.frames.interval_ms();
+        let elapsed_ms = self.start_time.elapsed().as_millis() as u64;
+        let remainder_ms = elapsed_ms % interval;
+        Duration::from_millis(interval - remainder_ms)
     }
 
     /// Render the spinner to spans.
     pub fn render(&self) -> Vec<Span> {
         vec![
-            Span::styled(self.current_frame().to_string(), self.spinner_style),
+            self.styled_or_plain(self.current_frame().to_string(), self.spinner_style),
             Span::raw(" "),
-            Span::styled(self.text.clone(), self.text_style),
+            self.styled_or_plain(self.text.clone(), self.text_style),
         ]
     }
 
@@ -668,6 +1173,47 @@ impl Spinner {
     pub fn to_string_colored(&self) -> String {
         format!("{} {}", self.current_frame(), self.text)
     }
+
+    /// Stop animating and render a fixed final line with `symbol` in place
+    /// of the animated frame -- the persistent, completed state of an
+    /// indeterminate task.
+    pub fn stop_and_persist(self, symbol: &str, text: &str) -> Vec<Span> {
+        vec![
+            self.styled_or_plain(symbol.to_string(), self.spinner_style),
+            Span::raw(" "),
+            self.styled_or_plain(text.to_string(), self.text_style),
+        ]
+    }
+
+    /// `stop_and_persist` with `symbol` styled in `color`, used by the
+    /// success/warn/fail/info convenience methods below.
+    fn persist_colored(self, symbol: &str, color: Color, text: &str) -> Vec<Span> {
+        vec![
+            self.styled_or_plain(symbol.to_string(), Style::new().foreground(color)),
+            Span::raw(" "),
+            self.styled_or_plain(text.to_string(), self.text_style),
+        ]
+    }
+
+    /// Persist a green `✔` success line.
+    pub fn succeed(self, text: &str) -> Vec<Span> {
+        self.persist_colored("✔", Color::Green, text)
+    }
+
+    /// Persist a yellow `⚠` warning line.
+    pub fn warn(self, text: &str) -> Vec<Span> {
+        self.persist_colored("⚠", Color::Yellow, text)
+    }
+
+    /// Persist a red `✖` failure line.
+    pub fn fail(self, text: &str) -> Vec<Span> {
+        self.persist_colored("✖", Color::Red, text)
+    }
+
+    /// Persist a blue `ℹ` info line.
+    pub fn info(self, text: &str) -> Vec<Span> {
+        self.persist_colored("ℹ", Color::Blue, text)
+    }
 }
 
 impl Default for Spinner {
@@ -676,6 +1222,274 @@ impl Default for Spinner {
     }
 }
 
+/// Coordinates several independently-animated spinners -- e.g. a throbber
+/// demo or a status bar where each in-flight task gets its own spinner --
+/// so a single live-display loop can drive all of them without each one
+/// tracking its own refresh timer.
+#[derive(Debug, Default)]
+pub struct SpinnerManager {
+    spinners: Vec<(usize, Spinner)>,
+    next_id: usize,
+}
+
+impl SpinnerManager {
+    /// Create an empty manager.
+    pub fn new() -> Self {
+        SpinnerManager {
+            spinners: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a spinner, returning a handle that can be used to update,
+    /// finish, or remove it later.
+    pub fn add(&mut self, spinner: Spinner) -> usize {
+        let handle = self.next_id;
+        self.next_id += 1;
+        self.spinners.push((handle, spinner));
+        handle
+    }
+
+    /// Update the text shown after `handle`'s spinner frame.
+    pub fn set_text(&mut self, handle: usize, text: &str) {
+        if let Some((_, spinner)) = self.spinners.iter_mut().find(|(id, _)| *id == handle) {
+            spinner.set_text(text);
+        }
+    }
+
+    /// Remove a spinner from the managed set without persisting a final
+    /// line for it.
+    pub fn remove(&mut self, handle: usize) {
+        self.spinners.retain(|(id, _)| *id != handle);
+    }
+
+    /// Stop and persist `handle`'s spinner with a final symbol and text,
+    /// removing it from the managed set and returning its rendered final
+    /// line (`None` if `handle` is not (or no longer) managed).
+    pub fn finish(&mut self, handle: usize, symbol: &str, text: &str) -> Option<Vec<Span>> {
+        let pos = self.spinners.iter().position(|(id, _)| *id == handle)?;
+        let (_, spinner) = self.spinners.remove(pos);
+        Some(spinner.stop_and_persist(symbol, text))
+    }
+
+    /// The minimum time remaining across all managed spinners before any of
+    /// them needs to advance to its next frame -- the interval a live
+    /// display loop should sleep for to keep every spinner animating at its
+    /// own cadence without redrawing more often than necessary. `None` if
+    /// no spinners are managed.
+    pub fn next_wakeup(&self) -> Option<Duration> {
+        self.spinners
+            .iter()
+            .map(|(_, spinner)| spinner.time_until_next_frame())
+            .min()
+    }
+
+    /// Render every managed spinner as a stacked block of lines, in the
+    /// order each was added.
+    pub fn render_all(&self) -> Vec<Vec<Span>> {
+        self.spinners
+            .iter()
+            .map(|(_, spinner)| spinner.render())
+            .collect()
+    }
+}
+
+/// Lifecycle of a task tracked by a [`SpinnerBoard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskState {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// One task tracked by a [`SpinnerBoard`]: either still animating, or
+/// finished and frozen with its final leader line.
+#[derive(Debug, Clone)]
+struct BoardTask {
+    spinner: Spinner,
+    state: TaskState,
+    /// Set once `state` is no longer `Running`: the `✓`/`✗` leader and its
+    /// text, frozen in place of the animated frame.
+    finished: Option<Vec<Span>>,
+}
+
+/// Drives many independently-styled, independently-animated spinners keyed
+/// by a task id, rendered as a single stable multi-line block -- the way an
+/// editor shows one spinner per background language server. Unlike
+/// [`SpinnerManager`], tasks are addressed by name rather than an opaque
+/// handle, and a finished task stays on the board with a `✓`/`✗` leader
+/// instead of being dropped.
+///
+/// A caller registers a task with [`SpinnerBoard::add`], updates its
+/// message while it runs, and marks it done with [`SpinnerBoard::succeed`]
+/// or [`SpinnerBoard::fail`]; [`SpinnerBoardView`] drives the actual
+/// in-place redraw on a single thread.
+#[derive(Debug, Default)]
+pub struct SpinnerBoard {
+    order: Vec<String>,
+    tasks: HashMap<String, BoardTask>,
+}
+
+impl SpinnerBoard {
+    /// Create an empty board.
+    pub fn new() -> Self {
+        SpinnerBoard {
+            order: Vec::new(),
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Register a running task under `id`, replacing any previous task with
+    /// the same id (its position in the block is kept).
+    pub fn add(&mut self, id: impl Into<String>, spinner: Spinner) {
+        let id = id.into();
+        if !self.tasks.contains_key(&id) {
+            self.order.push(id.clone());
+        }
+        self.tasks.insert(
+            id,
+            BoardTask {
+                spinner,
+                state: TaskState::Running,
+                finished: None,
+            },
+        );
+    }
+
+    /// Update `id`'s displayed text. No-op for a finished or unknown task.
+    pub fn set_text(&mut self, id: &str, text: &str) {
+        if let Some(task) = self.tasks.get_mut(id) {
+            if task.state == TaskState::Running {
+                task.spinner.set_text(text);
+            }
+        }
+    }
+
+    /// Remove a task from the board entirely, running or finished, without
+    /// freezing a final line for it.
+    pub fn remove(&mut self, id: &str) {
+        if self.tasks.remove(id).is_some() {
+            self.order.retain(|existing| existing != id);
+        }
+    }
+
+    /// Mark `id` succeeded, freezing a green `✓` leader with `text` in its
+    /// place. No-op for an unknown id.
+    pub fn succeed(&mut self, id: &str, text: &str) {
+        self.finish(id, TaskState::Succeeded, "✓", Color::Green, text);
+    }
+
+    /// Mark `id` failed, freezing a red `✗` leader with `text` in its
+    /// place. No-op for an unknown id.
+    pub fn fail(&mut self, id: &str, text: &str) {
+        self.finish(id, TaskState::Failed, "✗", Color::Red, text);
+    }
+
+    fn finish(&mut self, id: &str, state: TaskState, symbol: &str, color: Color, text: &str) {
+        if let Some(task) = self.tasks.get_mut(id) {
+            task.finished = Some(vec![
+                Span::styled(symbol.to_string(), Style::new().foreground(color)),
+                Span::raw(" "),
+                Span::styled(text.to_string(), task.spinner.text_style),
+            ]);
+            task.state = state;
+        }
+    }
+
+    /// Whether every registered task has finished (succeeded or failed).
+    pub fn all_finished(&self) -> bool {
+        !self.tasks.is_empty() && self.tasks.values().all(|task| task.state != TaskState::Running)
+    }
+
+    /// The minimum time remaining across all still-running tasks before one
+    /// needs to advance to its next frame (see [`SpinnerManager::next_wakeup`]);
+    /// `None` if every task is finished or the board is empty.
+    pub fn next_wakeup(&self) -> Option<Duration> {
+        self.tasks
+            .values()
+            .filter(|task| task.state == TaskState::Running)
+            .map(|task| task.spinner.time_until_next_frame())
+            .min()
+    }
+
+    /// Render every task in registration order: an animated frame for a
+    /// running task, or its frozen `✓`/`✗` leader for a finished one.
+    pub fn render_all(&self) -> Vec<Vec<Span>> {
+        self.order
+            .iter()
+            .filter_map(|id| self.tasks.get(id))
+            .map(|task| {
+                task.finished
+                    .clone()
+                    .unwrap_or_else(|| task.spinner.render())
+            })
+            .collect()
+    }
+}
+
+impl Renderable for SpinnerBoard {
+    fn render(&self, _context: &RenderContext) -> Vec<Segment> {
+        self.render_all().into_iter().map(Segment::line).collect()
+    }
+}
+
+/// Redraws a [`SpinnerBoard`] in place as tasks are added, updated, and
+/// finished, mirroring [`crate::nested_progress::NestedProgressView`]'s
+/// cursor-up-and-reprint approach. A caller ticks the board on its own
+/// single-threaded loop (sleeping for [`SpinnerBoard::next_wakeup`] between
+/// redraws) and calls [`SpinnerBoardView::refresh`] after each change.
+pub struct SpinnerBoardView {
+    last_height: usize,
+}
+
+impl SpinnerBoardView {
+    /// Create a view that hasn't drawn anything yet.
+    pub fn new() -> Self {
+        SpinnerBoardView { last_height: 0 }
+    }
+
+    /// Redraw `board` in place.
+    pub fn refresh(&mut self, board: &SpinnerBoard) {
+        self.redraw(board);
+    }
+
+    /// Redraw `board` one final time, unconditionally, and leave the cursor
+    /// below the static output instead of clearing it on the next call --
+    /// a clean teardown that leaves every task's final `✓`/`✗` status
+    /// printed. Call this once [`SpinnerBoard::all_finished`] is true.
+    pub fn finish(&mut self, board: &SpinnerBoard) {
+        self.redraw(board);
+        println!();
+        self.last_height = 0;
+    }
+
+    fn redraw(&mut self, board: &SpinnerBoard) {
+        let mut stdout = io::stdout();
+        if self.last_height > 0 {
+            let _ = execute!(stdout, cursor::MoveUp(self.last_height as u16));
+            for _ in 0..self.last_height {
+                let _ = execute!(stdout, terminal::Clear(terminal::ClearType::CurrentLine));
+                let _ = writeln!(stdout);
+            }
+            let _ = execute!(stdout, cursor::MoveUp(self.last_height as u16));
+        }
+
+        let capture = Console::capture();
+        capture.print_renderable(board);
+        let output = capture.get_captured_output();
+
+        let _ = write!(stdout, "{output}");
+        let _ = stdout.flush();
+        self.last_height = output.matches('\n').count();
+    }
+}
+
+impl Default for SpinnerBoardView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -718,6 +1532,32 @@ mod tests {
         assert!(SpinnerStyle::from_name("invalid_name").is_none());
     }
 
+    #[test]
+    fn test_custom_spinner_cycles_through_user_frames() {
+        let frames = vec!["▁".to_string(), "▃".to_string(), "▅".to_string()];
+        let spinner = Spinner::new("Loading...").custom(frames.clone(), 10_000);
+
+        assert_eq!(spinner.get_frames().len(), 3);
+        assert_eq!(spinner.get_frames().interval_ms(), 10_000);
+        // Right after construction we're still in frame 0.
+        assert_eq!(spinner.current_frame(), frames[0]);
+    }
+
+    #[test]
+    fn test_random_with_seed_is_deterministic() {
+        let a = SpinnerStyle::random_with_seed(42);
+        let b = SpinnerStyle::random_with_seed(42);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_random_with_seed_stays_within_known_styles() {
+        for seed in 0..20u64 {
+            let style = SpinnerStyle::random_with_seed(seed);
+            assert!(!style.frames().is_empty());
+        }
+    }
+
     #[test]
     fn test_emoji_spinners() {
         let clock = SpinnerStyle::Clock;
@@ -726,4 +1566,188 @@ mod tests {
         let moon = SpinnerStyle::Moon;
         assert!(moon.frames().iter().any(|f| f.contains("🌕")));
     }
+
+    #[test]
+    fn test_succeed_renders_green_check_and_text() {
+        let spinner = Spinner::new("Loading...").no_color(false);
+        let spans = spinner.succeed("Done");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].text, "✔");
+        assert_eq!(spans[0].style.foreground, Some(Color::Green));
+        assert_eq!(spans[2].text, "Done");
+    }
+
+    #[test]
+    fn test_fail_renders_red_cross_and_text() {
+        let spinner = Spinner::new("Loading...").no_color(false);
+        let spans = spinner.fail("Broken");
+        assert_eq!(spans[0].text, "✖");
+        assert_eq!(spans[0].style.foreground, Some(Color::Red));
+        assert_eq!(spans[2].text, "Broken");
+    }
+
+    #[test]
+    fn test_stop_and_persist_uses_caller_chosen_symbol() {
+        let spinner = Spinner::new("Loading...");
+        let spans = spinner.stop_and_persist("*", "Custom");
+        assert_eq!(spans[0].text, "*");
+        assert_eq!(spans[2].text, "Custom");
+    }
+
+    #[test]
+    fn test_spinner_manager_advances_spinners_independently() {
+        let mut manager = SpinnerManager::new();
+        let fast = Spinner::new("fast").custom(vec!["a".to_string(), "b".to_string()], 10);
+        let slow = Spinner::new("slow").custom(vec!["x".to_string(), "y".to_string()], 10_000);
+        let fast_handle = manager.add(fast);
+        let slow_handle = manager.add(slow);
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        let rendered = manager.render_all();
+        assert_eq!(rendered.len(), 2);
+        // The slow spinner's interval is far longer than our sleep, so it
+        // must still be showing its first frame.
+        assert_eq!(rendered[1][0].text, "x");
+
+        manager.set_text(fast_handle, "updated");
+        assert_eq!(manager.render_all()[0][2].text, "updated");
+
+        let finished = manager.finish(slow_handle, "✔", "done");
+        assert!(finished.is_some());
+        assert_eq!(finished.unwrap()[0].text, "✔");
+        assert_eq!(manager.render_all().len(), 1);
+    }
+
+    #[test]
+    fn test_next_wakeup_is_bounded_by_fastest_spinner() {
+        let mut manager = SpinnerManager::new();
+        manager.add(Spinner::new("fast").custom(vec!["a".to_string(), "b".to_string()], 10));
+        manager.add(Spinner::new("slow").custom(vec!["x".to_string(), "y".to_string()], 1_000));
+
+        let wakeup = manager.next_wakeup().unwrap();
+        assert!(wakeup <= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_no_color_override_forces_plain_spans() {
+        let spinner = Spinner::new("Loading...").no_color(true);
+        let spans = spinner.render();
+        assert_eq!(spans[0].style, Style::new());
+        assert_eq!(spans[2].style, Style::new());
+    }
+
+    #[test]
+    fn test_no_color_false_override_forces_styled_spans() {
+        let spinner = Spinner::new("Loading...").no_color(false);
+        let spans = spinner.render();
+        assert_eq!(spans[0].style.foreground, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn test_spinner_board_keeps_tasks_in_registration_order() {
+        let mut board = SpinnerBoard::new();
+        board.add("build", Spinner::new("Building..."));
+        board.add("test", Spinner::new("Testing..."));
+
+        let rendered = board.render_all();
+        assert_eq!(rendered.len(), 2);
+        assert_eq!(rendered[0][2].text, "Building...");
+        assert_eq!(rendered[1][2].text, "Testing...");
+    }
+
+    #[test]
+    fn test_spinner_board_set_text_updates_a_running_task() {
+        let mut board = SpinnerBoard::new();
+        board.add("build", Spinner::new("Building..."));
+
+        board.set_text("build", "Linking...");
+
+        assert_eq!(board.render_all()[0][2].text, "Linking...");
+    }
+
+    #[test]
+    fn test_spinner_board_succeed_freezes_a_check_leader_in_place() {
+        let mut board = SpinnerBoard::new();
+        board.add("build", Spinner::new("Building..."));
+        board.add("test", Spinner::new("Testing..."));
+
+        board.succeed("build", "Build OK");
+
+        let rendered = board.render_all();
+        assert_eq!(rendered.len(), 2);
+        assert_eq!(rendered[0][0].text, "✓");
+        assert_eq!(rendered[0][0].style.foreground, Some(Color::Green));
+        assert_eq!(rendered[0][2].text, "Build OK");
+
+        // A finished task no longer reacts to set_text.
+        board.set_text("build", "ignored");
+        assert_eq!(board.render_all()[0][2].text, "Build OK");
+    }
+
+    #[test]
+    fn test_spinner_board_fail_freezes_a_cross_leader_in_place() {
+        let mut board = SpinnerBoard::new();
+        board.add("deploy", Spinner::new("Deploying..."));
+
+        board.fail("deploy", "Deploy failed");
+
+        let rendered = board.render_all();
+        assert_eq!(rendered[0][0].text, "✗");
+        assert_eq!(rendered[0][0].style.foreground, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_spinner_board_remove_drops_a_task_entirely() {
+        let mut board = SpinnerBoard::new();
+        board.add("build", Spinner::new("Building..."));
+        board.add("test", Spinner::new("Testing..."));
+
+        board.remove("build");
+
+        let rendered = board.render_all();
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0][2].text, "Testing...");
+    }
+
+    #[test]
+    fn test_spinner_board_all_finished_tracks_every_task() {
+        let mut board = SpinnerBoard::new();
+        assert!(!board.all_finished());
+
+        board.add("build", Spinner::new("Building..."));
+        board.add("test", Spinner::new("Testing..."));
+        assert!(!board.all_finished());
+
+        board.succeed("build", "Build OK");
+        assert!(!board.all_finished());
+
+        board.fail("test", "Tests failed");
+        assert!(board.all_finished());
+    }
+
+    #[test]
+    fn test_spinner_board_next_wakeup_ignores_finished_tasks() {
+        let mut board = SpinnerBoard::new();
+        board.add(
+            "slow",
+            Spinner::new("slow").custom(vec!["x".to_string(), "y".to_string()], 1_000),
+        );
+        board.succeed("slow", "done");
+
+        assert_eq!(board.next_wakeup(), None);
+    }
+
+    #[test]
+    fn test_spinner_board_view_finish_leaves_output_printed_and_resets_height() {
+        let mut board = SpinnerBoard::new();
+        board.add("build", Spinner::new("Building..."));
+        board.succeed("build", "Build OK");
+
+        let mut view = SpinnerBoardView::new();
+        view.refresh(&board);
+        view.finish(&board);
+
+        assert_eq!(view.last_height, 0);
+    }
 }
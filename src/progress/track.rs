@@ -0,0 +1,179 @@
+//! Iterator adapters that pair a [`Progress`] task with the items being
+//! iterated, so callers don't have to manually interleave `add_task` and
+//! `advance` calls around a loop body.
+
+use crate::progress::bar::Progress;
+
+/// Iterator returned by [`Progress::track`]/[`Progress::track_sized`].
+/// Advances its task by one per item yielded and marks it finished once the
+/// inner iterator is exhausted, or as soon as the adapter itself is dropped
+/// (e.g. a `break` out of the `for` loop), so a task never gets stuck
+/// showing partial progress.
+pub struct Track<'a, I> {
+    iter: I,
+    progress: &'a Progress,
+    task_id: usize,
+    done: bool,
+}
+
+impl<'a, I> Track<'a, I> {
+    fn new(progress: &'a Progress, task_id: usize, iter: I) -> Self {
+        Track { iter, progress, task_id, done: false }
+    }
+
+    fn mark_finished(&mut self) {
+        if !self.done {
+            self.done = true;
+            self.progress.finish(self.task_id);
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Track<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.progress.advance(self.task_id, 1);
+                self.progress.print();
+                Some(item)
+            }
+            None => {
+                self.mark_finished();
+                None
+            }
+        }
+    }
+}
+
+impl<I> Drop for Track<'_, I> {
+    fn drop(&mut self) {
+        self.mark_finished();
+    }
+}
+
+impl Progress {
+    /// Register a task named `description` and return an iterator over
+    /// `iter` that advances it by one per item, finishing it once the
+    /// iterator is exhausted or dropped. The task has no known total (a
+    /// spinner/pulse-style display), since `iter` isn't required to know
+    /// its length up front; see [`Progress::track_sized`] for a task with a
+    /// total derived from the iterator's length.
+    pub fn track<I: IntoIterator>(&self, description: &str, iter: I) -> Track<'_, I::IntoIter> {
+        let task_id = self.add_task(description, None);
+        Track::new(self, task_id, iter.into_iter())
+    }
+
+    /// Like [`Progress::track`], but sets the task's total up front from
+    /// `iter`'s [`ExactSizeIterator::len`], giving a percentage/ETA instead
+    /// of an indeterminate spinner.
+    pub fn track_sized<I>(&self, description: &str, iter: I) -> Track<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let total = iter.len() as u64;
+        let task_id = self.add_task(description, Some(total));
+        Track::new(self, task_id, iter)
+    }
+}
+
+/// Iterator returned by [`track`], owning a private [`Progress`] so the
+/// caller doesn't need to create one themselves for a single ad hoc loop.
+pub struct OwnedTrack<I> {
+    iter: I,
+    progress: Progress,
+    task_id: usize,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for OwnedTrack<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.progress.advance(self.task_id, 1);
+                self.progress.print();
+                Some(item)
+            }
+            None => {
+                if !self.done {
+                    self.done = true;
+                    self.progress.finish(self.task_id);
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<I> Drop for OwnedTrack<I> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.done = true;
+            self.progress.finish(self.task_id);
+        }
+    }
+}
+
+/// Iterate over `iter` with a standalone progress bar, advancing it by one
+/// item per `next()` call. For attaching a task to a [`Progress`] you
+/// already display alongside other tasks, use [`Progress::track`]/
+/// [`Progress::track_sized`] instead.
+pub fn track<I: IntoIterator>(description: &str, iter: I) -> OwnedTrack<I::IntoIter> {
+    let progress = Progress::new();
+    let task_id = progress.add_task(description, None);
+    OwnedTrack { iter: iter.into_iter(), progress, task_id, done: false }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_track_advances_and_finishes() {
+        let progress = Progress::new();
+        let items: Vec<i32> = progress.track("Processing", vec![1, 2, 3]).collect();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        let task = progress.task(0).unwrap();
+        assert_eq!(task.completed, 3);
+        assert!(task.finished);
+    }
+
+    #[test]
+    fn test_progress_track_sized_sets_total_from_len() {
+        let progress = Progress::new();
+        let files = vec!["a", "b", "c", "d"];
+
+        let collected: Vec<&str> = progress.track_sized("Files", files).collect();
+        assert_eq!(collected.len(), 4);
+
+        let task = progress.task(0).unwrap();
+        assert_eq!(task.total, Some(4));
+        assert!(task.finished);
+    }
+
+    #[test]
+    fn test_progress_track_finishes_early_on_drop() {
+        let progress = Progress::new();
+        {
+            let mut iter = progress.track("Partial", vec![1, 2, 3, 4, 5]);
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.next(), Some(2));
+        }
+
+        let task = progress.task(0).unwrap();
+        assert_eq!(task.completed, 2);
+        assert!(task.finished);
+    }
+
+    #[test]
+    fn test_track_free_function_yields_all_items() {
+        let items: Vec<i32> = track("Standalone", vec![10, 20, 30]).collect();
+        assert_eq!(items, vec![10, 20, 30]);
+    }
+}
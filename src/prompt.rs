@@ -1,10 +1,73 @@
 //! Interactive prompt module.
 
 use crate::console::Console;
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::terminal;
 use std::fmt::Display;
 use std::io::{self, Write};
 use std::str::FromStr;
 
+/// RAII guard that enables raw mode for the duration of secret input, so a
+/// panic mid-read can't leave the terminal stuck in raw mode.
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn new() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(RawModeGuard)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Read a line of masked input one key event at a time: each typed
+/// character echoes as `*` (a grapheme made of several characters echoes
+/// one `*` per character), Backspace/Delete remove the last character and
+/// erase its mask, Enter submits, and Ctrl-C aborts the process after
+/// restoring the terminal.
+fn read_secret_line() -> io::Result<String> {
+    let _guard = RawModeGuard::new()?;
+    let mut buffer = String::new();
+
+    loop {
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Enter => {
+                    print!("\r\n");
+                    let _ = io::stdout().flush();
+                    break;
+                }
+                KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    // `process::exit` skips destructors, so restore the
+                    // terminal explicitly rather than relying on the guard.
+                    let _ = terminal::disable_raw_mode();
+                    print!("\r\n");
+                    let _ = io::stdout().flush();
+                    std::process::exit(130);
+                }
+                KeyCode::Backspace | KeyCode::Delete => {
+                    if buffer.pop().is_some() {
+                        print!("\u{8} \u{8}");
+                        let _ = io::stdout().flush();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    buffer.push(c);
+                    print!("*");
+                    let _ = io::stdout().flush();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(buffer)
+}
+
 /// Interactive prompt to ask for user input.
 pub struct Prompt<T> {
     prompt: String,
@@ -74,10 +137,7 @@ where
 
             let mut input = String::new();
             if self.password {
-                // For MVP, just read line. Ideally use crossterm to hide input.
-                // crossterm::terminal::enable_raw_mode() ... 
-                // But keeping it simple for now to ensure stability.
-                io::stdin().read_line(&mut input).unwrap_or_default();
+                input = read_secret_line().unwrap_or_default();
             } else {
                 io::stdin().read_line(&mut input).unwrap_or_default();
             }
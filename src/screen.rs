@@ -2,33 +2,55 @@
 //!
 //! Provides utilities for entering/exiting alternate screen buffer.
 
-use crossterm::{
-    cursor, execute,
-    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use std::io::{self, Write};
+use crate::backend::{Backend, CrosstermBackend};
+use crossterm::{cursor, execute, terminal::LeaveAlternateScreen};
+use std::io;
 
 /// Guard that automatically exits alternate screen when dropped.
-pub struct AlternateScreen {
+///
+/// Generic over the [`Backend`] driving the real terminal work, so tests
+/// can swap in a [`crate::backend::TestBackend`] and assert on the
+/// operations it recorded instead of touching a real tty. Defaults to
+/// [`CrosstermBackend`], the default in ordinary use.
+pub struct AlternateScreen<B: Backend = CrosstermBackend> {
+    backend: B,
     active: bool,
 }
 
-impl AlternateScreen {
-    /// Enter the alternate screen.
+impl AlternateScreen<CrosstermBackend> {
+    /// Enter the alternate screen, using the default [`CrosstermBackend`].
     pub fn enter() -> io::Result<Self> {
-        execute!(io::stdout(), EnterAlternateScreen)?;
-        terminal::enable_raw_mode()?;
-        execute!(io::stdout(), cursor::Hide)?;
+        Self::enter_with_backend(CrosstermBackend)
+    }
+
+    /// Enter the alternate screen after installing a panic hook (see
+    /// [`install_panic_hook`]), so a panic while the guard is held still
+    /// leaves the terminal clean for the backtrace.
+    pub fn enter_with_panic_hook() -> io::Result<Self> {
+        install_panic_hook();
+        Self::enter()
+    }
+}
 
-        Ok(AlternateScreen { active: true })
+impl<B: Backend> AlternateScreen<B> {
+    /// Enter the alternate screen using a specific [`Backend`].
+    pub fn enter_with_backend(mut backend: B) -> io::Result<Self> {
+        backend.enter_alternate_screen()?;
+        backend.enable_raw_mode()?;
+        backend.hide_cursor()?;
+
+        Ok(AlternateScreen {
+            backend,
+            active: true,
+        })
     }
 
     /// Manually exit the alternate screen.
     pub fn exit(&mut self) -> io::Result<()> {
         if self.active {
-            execute!(io::stdout(), cursor::Show)?;
-            terminal::disable_raw_mode()?;
-            execute!(io::stdout(), LeaveAlternateScreen)?;
+            self.backend.show_cursor()?;
+            self.backend.disable_raw_mode()?;
+            self.backend.leave_alternate_screen()?;
             self.active = false;
         }
         Ok(())
@@ -40,43 +62,144 @@ impl AlternateScreen {
     }
 
     /// Clear the alternate screen.
-    pub fn clear(&self) -> io::Result<()> {
-        execute!(
-            io::stdout(),
-            terminal::Clear(terminal::ClearType::All),
-            cursor::MoveTo(0, 0)
-        )?;
-        io::stdout().flush()
+    pub fn clear(&mut self) -> io::Result<()> {
+        self.backend.clear()?;
+        self.backend.move_to(0, 0)?;
+        self.backend.flush()
     }
 
     /// Get terminal size.
     pub fn size(&self) -> io::Result<(u16, u16)> {
-        terminal::size()
+        self.backend.size()
+    }
+
+    /// The backend driving this screen, for inspecting recorded operations
+    /// (e.g. with a [`crate::backend::TestBackend`]).
+    pub fn backend(&self) -> &B {
+        &self.backend
     }
 }
 
-impl Drop for AlternateScreen {
+impl<B: Backend> Drop for AlternateScreen<B> {
     fn drop(&mut self) {
         let _ = self.exit();
     }
 }
 
-/// Run a function in alternate screen mode.
+/// Install a panic hook that resets the terminal -- leaves the alternate
+/// screen, disables raw mode, and shows the cursor -- before running the
+/// previously installed hook.
+///
+/// `AlternateScreen`'s `Drop` impl isn't guaranteed to run before a panic's
+/// backtrace is printed, which otherwise leaves the terminal in raw mode
+/// with a hidden cursor and a garbled backtrace. Call this once, before
+/// entering the alternate screen, in any full-screen application that might
+/// panic while the guard is held; [`AlternateScreen::enter_with_panic_hook`]
+/// does this automatically. This always goes through the real terminal
+/// (rather than a pluggable [`Backend`]), since a panic handler can't
+/// assume its closure's captured backend is still in a usable state.
+///
+/// Composes with [`traceback::install_panic_hook`](crate::traceback::install_panic_hook)
+/// the same way: each chains to whatever hook was already installed instead
+/// of replacing it. Install this one *after* `traceback::install_panic_hook`
+/// so it ends up as the outermost hook -- the terminal is then restored
+/// before the traceback prints, instead of the traceback printing into a
+/// still-raw-mode terminal.
+pub fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = execute!(io::stdout(), cursor::Show);
+        let _ = crossterm::terminal::disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        previous_hook(panic_info);
+    }));
+}
+
+/// Run a function in alternate screen mode, using the default
+/// [`CrosstermBackend`].
 pub fn with_alternate_screen<F, R>(f: F) -> io::Result<R>
 where
-    F: FnOnce(&AlternateScreen) -> io::Result<R>,
+    F: FnOnce(&mut AlternateScreen) -> io::Result<R>,
 {
-    let screen = AlternateScreen::enter()?;
-    let result = f(&screen);
+    let mut screen = AlternateScreen::enter()?;
+    let result = f(&mut screen);
     drop(screen); // Explicit drop to exit alternate screen
     result
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::backend::{BackendOp, TestBackend};
+
     #[test]
     fn test_alternate_screen_creation() {
         // Can't easily test in CI, but we can verify the struct compiles
         assert!(true);
     }
+
+    #[test]
+    fn test_install_panic_hook_does_not_panic() {
+        // Can't easily assert on terminal state in CI, but installing the
+        // hook (and chaining onto whatever was there before) should never
+        // itself panic.
+        super::install_panic_hook();
+    }
+
+    #[test]
+    fn test_enter_with_backend_drives_the_expected_ops_in_order() {
+        let backend = TestBackend::new(80, 24);
+        let screen = AlternateScreen::enter_with_backend(backend).unwrap();
+
+        assert_eq!(
+            screen.backend().ops(),
+            &[
+                BackendOp::EnterAlternateScreen,
+                BackendOp::EnableRawMode,
+                BackendOp::HideCursor,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exit_drives_the_reverse_ops_and_becomes_inactive() {
+        let backend = TestBackend::new(80, 24);
+        let mut screen = AlternateScreen::enter_with_backend(backend).unwrap();
+        screen.exit().unwrap();
+
+        assert!(!screen.is_active());
+        assert_eq!(
+            screen.backend().ops(),
+            &[
+                BackendOp::EnterAlternateScreen,
+                BackendOp::EnableRawMode,
+                BackendOp::HideCursor,
+                BackendOp::ShowCursor,
+                BackendOp::DisableRawMode,
+                BackendOp::LeaveAlternateScreen,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clear_moves_cursor_home_and_flushes() {
+        let backend = TestBackend::new(80, 24);
+        let mut screen = AlternateScreen::enter_with_backend(backend).unwrap();
+        screen.clear().unwrap();
+
+        let ops = screen.backend().ops();
+        assert_eq!(&ops[ops.len() - 3..], &[
+            BackendOp::Clear,
+            BackendOp::MoveTo(0, 0),
+            BackendOp::Flush,
+        ]);
+    }
+
+    #[test]
+    fn test_size_reports_the_backends_configured_size() {
+        let backend = TestBackend::new(120, 40);
+        let screen = AlternateScreen::enter_with_backend(backend).unwrap();
+
+        assert_eq!(screen.size().unwrap(), (120, 40));
+    }
 }
@@ -42,17 +42,214 @@ static RESHAPER: LazyLock<ArabicReshaper> = LazyLock::new(|| ArabicReshaper::def
 /// ```
 #[cfg(feature = "rtl")]
 pub fn reshape(text: &str) -> Cow<'_, str> {
-    // Fast path: check if reshaping is needed
-    if !RESHAPER.need_reshape(text) {
+    reshape_with(text, &default_profile())
+}
+
+/// Stub implementation when RTL feature is disabled
+#[cfg(not(feature = "rtl"))]
+pub fn reshape(text: &str) -> Cow<'_, str> {
+    Cow::Borrowed(text)
+}
+
+/// Ligature group toggles, mirroring the coarse categories
+/// `python-arabic-reshaper`'s default config toggles (sentence-, word-, and
+/// letter-level ligatures). These are stored and serialized only: shaping
+/// substitutes `ar-reshaper`'s default ligatures whatever they are set to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LigatureGroups {
+    /// Multi-word religious/phrase ligatures (e.g. besmellah).
+    pub sentences: bool,
+    /// Two-word ligatures.
+    pub words: bool,
+    /// Two-letter ligatures (e.g. lam-alef).
+    pub letters: bool,
+}
+
+impl Default for LigatureGroups {
+    fn default() -> Self {
+        LigatureGroups {
+            sentences: true,
+            words: true,
+            letters: true,
+        }
+    }
+}
+
+/// Configuration for [`reshape_with`] (and, once installed via
+/// [`set_default_profile`], for [`reshape`] itself), with the knobs
+/// `python-arabic-reshaper` exposes through its `ReshaperConfig`: ligature
+/// groups, harakat (diacritics), tatweel (kashida), zero-width joiners, and
+/// isolated-form fallbacks. Serde round-trippable so a `Console` config file
+/// can carry shaping settings. Only `delete_harakat` and `delete_tatweel`
+/// change the shaped output; the other fields are stored and serialized,
+/// but shaping always uses `ar-reshaper`'s default behavior for them.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReshaperProfile {
+    /// Strip Arabic harakat (diacritic marks) before shaping.
+    pub delete_harakat: bool,
+    /// Strip tatweel (kashida elongation) before shaping.
+    pub delete_tatweel: bool,
+    /// Which ligature groups to substitute (stored only; not applied by
+    /// [`reshape_with`]).
+    pub ligatures: LigatureGroups,
+    /// Preserve zero-width joiners around shaped runs (stored only; not
+    /// applied by [`reshape_with`]).
+    pub support_zwj: bool,
+    /// Use a letter's unshaped form instead of its isolated form when it has
+    /// no neighbors to connect to (stored only; not applied by
+    /// [`reshape_with`]).
+    pub use_unshaped_instead_of_isolated: bool,
+}
+
+impl Default for ReshaperProfile {
+    fn default() -> Self {
+        ReshaperProfile {
+            delete_harakat: false,
+            delete_tatweel: false,
+            ligatures: LigatureGroups::default(),
+            support_zwj: true,
+            use_unshaped_instead_of_isolated: false,
+        }
+    }
+}
+
+/// Whether `c` is an Arabic harakat (diacritic) mark.
+fn is_harakat(c: char) -> bool {
+    matches!(c as u32, 0x064B..=0x065F | 0x0670)
+}
+
+const TATWEEL: char = '\u{0640}';
+
+/// Reshape `text` using an explicit [`ReshaperProfile`] instead of the
+/// installed process-wide default.
+///
+/// `delete_harakat` and `delete_tatweel` are applied as a pre-processing
+/// filter, matching `python-arabic-reshaper`'s documented order of
+/// operations: strip diacritics/kashida first, then shape the remaining
+/// letters. The ligature-group, `support_zwj`, and
+/// `use_unshaped_instead_of_isolated` toggles round-trip through serde for
+/// config files, but shaping always runs `ar-reshaper`'s single
+/// default-configured reshaper, so they do not affect the output.
+#[cfg(feature = "rtl")]
+pub fn reshape_with(text: &str, profile: &ReshaperProfile) -> Cow<'_, str> {
+    if !profile.delete_harakat && !profile.delete_tatweel {
+        if !RESHAPER.need_reshape(text) {
+            return Cow::Borrowed(text);
+        }
+        return Cow::Owned(RESHAPER.reshape(text));
+    }
+
+    let filtered: String = text
+        .chars()
+        .filter(|&c| !(profile.delete_harakat && is_harakat(c)) && !(profile.delete_tatweel && c == TATWEEL))
+        .collect();
+
+    if !RESHAPER.need_reshape(&filtered) {
+        return Cow::Owned(filtered);
+    }
+    Cow::Owned(RESHAPER.reshape(&filtered))
+}
+
+/// Stub implementation when RTL feature is disabled
+#[cfg(not(feature = "rtl"))]
+pub fn reshape_with(text: &str, _profile: &ReshaperProfile) -> Cow<'_, str> {
+    Cow::Borrowed(text)
+}
+
+#[cfg(feature = "rtl")]
+static DEFAULT_PROFILE: std::sync::OnceLock<std::sync::RwLock<ReshaperProfile>> = std::sync::OnceLock::new();
+
+#[cfg(feature = "rtl")]
+fn default_profile_lock() -> &'static std::sync::RwLock<ReshaperProfile> {
+    DEFAULT_PROFILE.get_or_init(|| std::sync::RwLock::new(ReshaperProfile::default()))
+}
+
+/// Install a process-wide default [`ReshaperProfile`], used by [`reshape`]
+/// (and everything built on it, including `Console::print_bidi`) from then on.
+#[cfg(feature = "rtl")]
+pub fn set_default_profile(profile: ReshaperProfile) {
+    *default_profile_lock().write().expect("reshaper profile lock poisoned") = profile;
+}
+
+/// The currently installed process-wide default profile.
+#[cfg(feature = "rtl")]
+pub fn default_profile() -> ReshaperProfile {
+    default_profile_lock().read().expect("reshaper profile lock poisoned").clone()
+}
+
+/// Stub implementation when RTL feature is disabled
+#[cfg(not(feature = "rtl"))]
+pub fn set_default_profile(_profile: ReshaperProfile) {}
+
+/// Stub implementation when RTL feature is disabled
+#[cfg(not(feature = "rtl"))]
+pub fn default_profile() -> ReshaperProfile {
+    ReshaperProfile::default()
+}
+
+/// Reorder already-shaped text into visual (left-to-right terminal cell)
+/// order by running the Unicode Bidirectional Algorithm, mirroring what
+/// `python-bidi`'s `get_display` does downstream of the reshaper.
+///
+/// This only reorders; it does not map Arabic letters to their contextual
+/// forms. Call [`reshape`] (or use [`reshape_and_display`]) first, since
+/// reshaping must happen in logical order, before the run is reversed.
+///
+/// # Example
+/// ```
+/// use fast_rich::shaping::{reshape, to_display};
+///
+/// let shaped = reshape("مرحبا World");
+/// let visual = to_display(&shaped);
+/// ```
+#[cfg(feature = "rtl")]
+pub fn to_display(text: &str) -> Cow<'_, str> {
+    use unicode_bidi::BidiInfo;
+
+    if text.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let bidi_info = BidiInfo::new(text, None);
+    if bidi_info.paragraphs.is_empty() {
         return Cow::Borrowed(text);
     }
-    
-    Cow::Owned(RESHAPER.reshape(text))
+
+    let mut result = String::with_capacity(text.len());
+    for para in &bidi_info.paragraphs {
+        let line = para.range.clone();
+        result.push_str(&bidi_info.reorder_line(para, line));
+    }
+    Cow::Owned(result)
 }
 
 /// Stub implementation when RTL feature is disabled
 #[cfg(not(feature = "rtl"))]
-pub fn reshape(text: &str) -> Cow<'_, str> {
+pub fn to_display(text: &str) -> Cow<'_, str> {
+    Cow::Borrowed(text)
+}
+
+/// Reshape Arabic letters and then reorder the result into visual order --
+/// the full `ar-reshaper` + BiDi pipeline in one call, auto-detecting the
+/// paragraph's base direction from its first strong character.
+///
+/// # Example
+/// ```
+/// use fast_rich::shaping::reshape_and_display;
+///
+/// let visual = reshape_and_display("Hello مرحبا World");
+/// ```
+#[cfg(feature = "rtl")]
+pub fn reshape_and_display(text: &str) -> Cow<'_, str> {
+    Cow::Owned(crate::bidi::reorder_for_display(
+        text,
+        crate::bidi::TextDirection::Auto,
+    ))
+}
+
+/// Stub implementation when RTL feature is disabled
+#[cfg(not(feature = "rtl"))]
+pub fn reshape_and_display(text: &str) -> Cow<'_, str> {
     Cow::Borrowed(text)
 }
 
@@ -98,4 +295,69 @@ mod tests {
         assert!(reshaped.contains("Hello"));
         assert!(reshaped.contains("World"));
     }
+
+    #[test]
+    #[cfg(feature = "rtl")]
+    fn test_to_display_leaves_pure_ltr_unchanged() {
+        let input = "Hello World";
+        assert_eq!(to_display(input), input);
+    }
+
+    #[test]
+    #[cfg(feature = "rtl")]
+    fn test_reshape_and_display_keeps_leading_english_on_the_left() {
+        // "Hello " (LTR) + "مرحبا" (RTL), base direction auto-detects LTR
+        // since "Hello " leads; the Arabic run still gets shaped and
+        // reordered within its own run.
+        let visual = reshape_and_display("Hello مرحبا");
+        assert!(visual.starts_with("Hello "));
+
+        let shaped_ref = reshape("مرحبا");
+        assert!(visual.chars().any(|c| shaped_ref.contains(c)));
+    }
+
+    #[test]
+    #[cfg(feature = "rtl")]
+    fn test_reshape_with_deletes_harakat() {
+        let profile = ReshaperProfile {
+            delete_harakat: true,
+            ..ReshaperProfile::default()
+        };
+        // "مَرْحَبًا" carries fatha/sukun/tanwin marks over the bare letters.
+        let reshaped = reshape_with("مَرْحَبًا", &profile);
+        assert!(!reshaped.chars().any(is_harakat));
+    }
+
+    #[test]
+    #[cfg(feature = "rtl")]
+    fn test_reshape_with_deletes_tatweel() {
+        let profile = ReshaperProfile {
+            delete_tatweel: true,
+            ..ReshaperProfile::default()
+        };
+        let reshaped = reshape_with("مـرحبا", &profile);
+        assert!(!reshaped.contains(TATWEEL));
+    }
+
+    #[test]
+    fn test_reshaper_profile_default_keeps_ligatures_and_zwj_on() {
+        let profile = ReshaperProfile::default();
+        assert!(!profile.delete_harakat);
+        assert!(!profile.delete_tatweel);
+        assert!(profile.support_zwj);
+        assert!(profile.ligatures.sentences);
+        assert!(profile.ligatures.words);
+        assert!(profile.ligatures.letters);
+    }
+
+    #[test]
+    fn test_reshaper_profile_round_trips_through_json() {
+        let mut profile = ReshaperProfile::default();
+        profile.delete_harakat = true;
+        profile.ligatures.sentences = false;
+
+        let json = serde_json::to_string(&profile).expect("profile should serialize");
+        let restored: ReshaperProfile = serde_json::from_str(&json).expect("profile should deserialize");
+        assert_eq!(profile, restored);
+    }
 }
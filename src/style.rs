@@ -13,6 +13,7 @@
 //!     .underline();
 //! ```
 
+use crate::console::ColorSystem;
 use std::fmt;
 
 /// Represents a terminal color.
@@ -73,8 +74,10 @@ impl Color {
     ///
     /// Supports:
     /// - Named colors: "red", "blue", "bright_red", etc.
+    /// - CSS/X11 named colors: "rebeccapurple", "coral", "dodgerblue", etc.
     /// - Hex colors: "#ff0000", "#f00"
     /// - RGB: "rgb(255, 0, 0)"
+    /// - HSL: "hsl(210, 50%, 40%)"
     /// - 256-color: "color(196)"
     pub fn parse(s: &str) -> Option<Self> {
         let s = s.trim().to_lowercase();
@@ -101,6 +104,11 @@ impl Color {
             _ => {}
         }
 
+        // CSS/X11 named colors
+        if let Some((r, g, b)) = Self::parse_css_name(&s) {
+            return Some(Color::Rgb { r, g, b });
+        }
+
         // Hex colors: #rgb or #rrggbb
         if let Some(hex) = s.strip_prefix('#') {
             return Self::parse_hex(hex);
@@ -117,6 +125,18 @@ impl Color {
             }
         }
 
+        // HSL: hsl(h, s%, l%)
+        if let Some(inner) = s.strip_prefix("hsl(").and_then(|s| s.strip_suffix(')')) {
+            let parts: Vec<&str> = inner.split(',').collect();
+            if parts.len() == 3 {
+                let h: f32 = parts[0].trim().parse().ok()?;
+                let s_pct: f32 = parts[1].trim().trim_end_matches('%').parse().ok()?;
+                let l_pct: f32 = parts[2].trim().trim_end_matches('%').parse().ok()?;
+                let (r, g, b) = Self::hsl_to_rgb(h, s_pct / 100.0, l_pct / 100.0);
+                return Some(Color::Rgb { r, g, b });
+            }
+        }
+
         // 256-color: color(n)
         if let Some(inner) = s.strip_prefix("color(").and_then(|s| s.strip_suffix(')')) {
             let code: u8 = inner.trim().parse().ok()?;
@@ -126,6 +146,202 @@ impl Color {
         None
     }
 
+    /// Convert an `(h, s, l)` triple (hue in degrees, saturation/lightness in
+    /// `0.0..=1.0`) to `(r, g, b)` bytes using the standard HSL->RGB algorithm.
+    fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+        let h = h.rem_euclid(360.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r1, g1, b1) = match h as u32 {
+            0..=59 => (c, x, 0.0),
+            60..=119 => (x, c, 0.0),
+            120..=179 => (0.0, c, x),
+            180..=239 => (0.0, x, c),
+            240..=299 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        (
+            ((r1 + m) * 255.0).round() as u8,
+            ((g1 + m) * 255.0).round() as u8,
+            ((b1 + m) * 255.0).round() as u8,
+        )
+    }
+
+    /// Convert `(r, g, b)` bytes to an `(h, s, l)` triple (hue in degrees,
+    /// saturation/lightness in `0.0..=1.0`), the inverse of [`Color::hsl_to_rgb`].
+    fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+        let r = r as f32 / 255.0;
+        let g = g as f32 / 255.0;
+        let b = b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+
+        if delta.abs() < f32::EPSILON {
+            return (0.0, 0.0, l);
+        }
+
+        let s = if l > 0.5 {
+            delta / (2.0 - max - min)
+        } else {
+            delta / (max + min)
+        };
+
+        let h = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        (h * 60.0, s, l)
+    }
+
+    /// Resolve a CSS/X11 named color to its `(r, g, b)` bytes.
+    #[rustfmt::skip]
+    fn parse_css_name(name: &str) -> Option<(u8, u8, u8)> {
+        Some(match name {
+            "aliceblue" => (240, 248, 255),
+            "antiquewhite" => (250, 235, 215),
+            "aqua" => (0, 255, 255),
+            "aquamarine" => (127, 255, 212),
+            "azure" => (240, 255, 255),
+            "beige" => (245, 245, 220),
+            "bisque" => (255, 228, 196),
+            "blanchedalmond" => (255, 235, 205),
+            "blueviolet" => (138, 43, 226),
+            "brown" => (165, 42, 42),
+            "burlywood" => (222, 184, 135),
+            "cadetblue" => (95, 158, 160),
+            "chartreuse" => (127, 255, 0),
+            "chocolate" => (210, 105, 30),
+            "coral" => (255, 127, 80),
+            "cornflowerblue" => (100, 149, 237),
+            "cornsilk" => (255, 248, 220),
+            "crimson" => (220, 20, 60),
+            "darkblue" => (0, 0, 139),
+            "darkcyan" => (0, 139, 139),
+            "darkgoldenrod" => (184, 134, 11),
+            "darkgray" | "darkgrey" => (169, 169, 169),
+            "darkgreen" => (0, 100, 0),
+            "darkkhaki" => (189, 183, 107),
+            "darkmagenta" => (139, 0, 139),
+            "darkolivegreen" => (85, 107, 47),
+            "darkorange" => (255, 140, 0),
+            "darkorchid" => (153, 50, 204),
+            "darkred" => (139, 0, 0),
+            "darksalmon" => (233, 150, 122),
+            "darkseagreen" => (143, 188, 143),
+            "darkslateblue" => (72, 61, 139),
+            "darkslategray" | "darkslategrey" => (47, 79, 79),
+            "darkturquoise" => (0, 206, 209),
+            "darkviolet" => (148, 0, 211),
+            "deeppink" => (255, 20, 147),
+            "deepskyblue" => (0, 191, 255),
+            "dimgray" | "dimgrey" => (105, 105, 105),
+            "dodgerblue" => (30, 144, 255),
+            "firebrick" => (178, 34, 34),
+            "floralwhite" => (255, 250, 240),
+            "forestgreen" => (34, 139, 34),
+            "fuchsia" => (255, 0, 255),
+            "gainsboro" => (220, 220, 220),
+            "ghostwhite" => (248, 248, 255),
+            "gold" => (255, 215, 0),
+            "goldenrod" => (218, 165, 32),
+            "greenyellow" => (173, 255, 47),
+            "honeydew" => (240, 255, 240),
+            "hotpink" => (255, 105, 180),
+            "indianred" => (205, 92, 92),
+            "indigo" => (75, 0, 130),
+            "ivory" => (255, 255, 240),
+            "khaki" => (240, 230, 140),
+            "lavender" => (230, 230, 250),
+            "lavenderblush" => (255, 240, 245),
+            "lawngreen" => (124, 252, 0),
+            "lemonchiffon" => (255, 250, 205),
+            "lightblue" => (173, 216, 230),
+            "lightcoral" => (240, 128, 128),
+            "lightcyan" => (224, 255, 255),
+            "lightgoldenrodyellow" => (250, 250, 210),
+            "lightgray" | "lightgrey" => (211, 211, 211),
+            "lightgreen" => (144, 238, 144),
+            "lightpink" => (255, 182, 193),
+            "lightsalmon" => (255, 160, 122),
+            "lightseagreen" => (32, 178, 170),
+            "lightskyblue" => (135, 206, 250),
+            "lightslategray" | "lightslategrey" => (119, 136, 153),
+            "lightsteelblue" => (176, 196, 222),
+            "lightyellow" => (255, 255, 224),
+            "lime" => (0, 255, 0),
+            "limegreen" => (50, 205, 50),
+            "linen" => (250, 240, 230),
+            "maroon" => (128, 0, 0),
+            "mediumaquamarine" => (102, 205, 170),
+            "mediumblue" => (0, 0, 205),
+            "mediumorchid" => (186, 85, 211),
+            "mediumpurple" => (147, 112, 219),
+            "mediumseagreen" => (60, 179, 113),
+            "mediumslateblue" => (123, 104, 238),
+            "mediumspringgreen" => (0, 250, 154),
+            "mediumturquoise" => (72, 209, 204),
+            "mediumvioletred" => (199, 21, 133),
+            "midnightblue" => (25, 25, 112),
+            "mintcream" => (245, 255, 250),
+            "mistyrose" => (255, 228, 225),
+            "moccasin" => (255, 228, 181),
+            "navajowhite" => (255, 222, 173),
+            "navy" => (0, 0, 128),
+            "oldlace" => (253, 245, 230),
+            "olive" => (128, 128, 0),
+            "olivedrab" => (107, 142, 35),
+            "orange" => (255, 165, 0),
+            "orangered" => (255, 69, 0),
+            "orchid" => (218, 112, 214),
+            "palegoldenrod" => (238, 232, 170),
+            "palegreen" => (152, 251, 152),
+            "paleturquoise" => (175, 238, 238),
+            "palevioletred" => (219, 112, 147),
+            "papayawhip" => (255, 239, 213),
+            "peachpuff" => (255, 218, 185),
+            "peru" => (205, 133, 63),
+            "pink" => (255, 192, 203),
+            "plum" => (221, 160, 221),
+            "powderblue" => (176, 224, 230),
+            "purple" => (128, 0, 128),
+            "rebeccapurple" => (102, 51, 153),
+            "rosybrown" => (188, 143, 143),
+            "royalblue" => (65, 105, 225),
+            "saddlebrown" => (139, 69, 19),
+            "salmon" => (250, 128, 114),
+            "sandybrown" => (244, 164, 96),
+            "seagreen" => (46, 139, 87),
+            "seashell" => (255, 245, 238),
+            "sienna" => (160, 82, 45),
+            "silver" => (192, 192, 192),
+            "skyblue" => (135, 206, 235),
+            "slateblue" => (106, 90, 205),
+            "slategray" | "slategrey" => (112, 128, 144),
+            "snow" => (255, 250, 250),
+            "springgreen" => (0, 255, 127),
+            "steelblue" => (70, 130, 180),
+            "tan" => (210, 180, 140),
+            "teal" => (0, 128, 128),
+            "thistle" => (216, 191, 216),
+            "tomato" => (255, 99, 71),
+            "turquoise" => (64, 224, 208),
+            "violet" => (238, 130, 238),
+            "wheat" => (245, 222, 179),
+            "whitesmoke" => (245, 245, 245),
+            "yellowgreen" => (154, 205, 50),
+            _ => return None,
+        })
+    }
+
     fn parse_hex(hex: &str) -> Option<Self> {
         match hex.len() {
             3 => {
@@ -209,30 +425,49 @@ impl Color {
             Color::Default => Color::Default,
             Color::Ansi256(_) => *self,
             Color::Rgb { r, g, b } => {
-                // Find nearest color in the 256-color palette using Euclidean distance
-                let mut min_dist = u32::MAX;
-                let mut best_idx = 0;
-
-                // Standard colors (0-15)
-                // 6x6x6 Color Cube (16-231)
-                // Grayscale (232-255)
-                // We'll iterate through all generated RGB values for 0-255
-                for i in 0..=255 {
-                    let (pr, pg, pb) = Self::ansi256_to_rgb_values(i);
-                    let dr = i32::from(*r) - i32::from(pr);
-                    let dg = i32::from(*g) - i32::from(pg);
-                    let db = i32::from(*b) - i32::from(pb);
-                    let dist = (dr * dr + dg * dg + db * db) as u32;
-
-                    if dist < min_dist {
-                        min_dist = dist;
-                        best_idx = i;
-                        if dist == 0 {
-                            break;
-                        } // Exact match
-                    }
-                }
-                Color::Ansi256(best_idx)
+                // Quantize each channel to the nearest of the cube's 6 levels
+                // to land directly on a candidate in the 16..=231 color cube,
+                // then separately find the nearest of the 24 grayscale ramp
+                // entries (232..=255), and keep whichever candidate is
+                // closer by squared RGB distance -- the standard xterm-256
+                // approximation, avoiding a brute-force scan of all 256.
+                const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+                let sq_dist = |a: (u8, u8, u8), b: (u8, u8, u8)| -> u32 {
+                    let dr = a.0 as i32 - b.0 as i32;
+                    let dg = a.1 as i32 - b.1 as i32;
+                    let db = a.2 as i32 - b.2 as i32;
+                    (dr * dr + dg * dg + db * db) as u32
+                };
+                let quantize = |c: u8| -> usize {
+                    (0..6)
+                        .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+                        .unwrap()
+                };
+
+                let (r_idx, g_idx, b_idx) = (quantize(*r), quantize(*g), quantize(*b));
+                let cube_rgb = (
+                    CUBE_LEVELS[r_idx],
+                    CUBE_LEVELS[g_idx],
+                    CUBE_LEVELS[b_idx],
+                );
+                let cube_code = (16 + 36 * r_idx + 6 * g_idx + b_idx) as u8;
+                let cube_dist = sq_dist((*r, *g, *b), cube_rgb);
+
+                let gray_idx = (0u8..24)
+                    .min_by_key(|&i| {
+                        let val = i * 10 + 8;
+                        sq_dist((*r, *g, *b), (val, val, val))
+                    })
+                    .unwrap();
+                let gray_val = gray_idx * 10 + 8;
+                let gray_dist = sq_dist((*r, *g, *b), (gray_val, gray_val, gray_val));
+
+                Color::Ansi256(if cube_dist <= gray_dist {
+                    cube_code
+                } else {
+                    232 + gray_idx
+                })
             }
             // Map named colors to their specific ANSI codes
             Color::Black => Color::Ansi256(0),
@@ -310,10 +545,7 @@ impl Color {
                 let mut best_idx = 0;
 
                 for (i, (pr, pg, pb)) in palette.iter().enumerate() {
-                    let dr = i32::from(*r) - pr;
-                    let dg = i32::from(*g) - pg;
-                    let db = i32::from(*b) - pb;
-                    let dist = (dr * dr + dg * dg + db * db) as u32;
+                    let dist = Self::redmean_distance((*r, *g, *b), (*pr as u8, *pg as u8, *pb as u8));
                     if dist < min_dist {
                         min_dist = dist;
                         best_idx = i;
@@ -347,11 +579,40 @@ impl Color {
             Color::BrightCyan => "\x1b[96m".to_string(),
             Color::BrightWhite => "\x1b[97m".to_string(),
             Color::Default => "\x1b[39m".to_string(),
-            // For others, fall back to csi-wrapper (should be handled by downsampling first)
-            _ => String::new(),
+            Color::Ansi256(code) => format!("\x1b[38;5;{code}m"),
+            Color::Rgb { r, g, b } => format!("\x1b[38;2;{r};{g};{b}m"),
         }
     }
 
+    /// The SGR parameter(s) for this color as a foreground, without the
+    /// leading `\x1b[` / trailing `m` -- used by [`Style::to_ansi_prefix`] to
+    /// fold multiple attributes into one escape sequence.
+    fn to_sgr_fg_params(&self) -> String {
+        match self {
+            Color::Ansi256(code) => format!("38;5;{code}"),
+            Color::Rgb { r, g, b } => format!("38;2;{r};{g};{b}"),
+            _ => Self::sgr_params(&self.to_sgr_fg()),
+        }
+    }
+
+    /// The SGR parameter(s) for this color as a background, without the
+    /// leading `\x1b[` / trailing `m`.
+    fn to_sgr_bg_params(&self) -> String {
+        match self {
+            Color::Ansi256(code) => format!("48;5;{code}"),
+            Color::Rgb { r, g, b } => format!("48;2;{r};{g};{b}"),
+            _ => Self::sgr_params(&self.to_sgr_bg()),
+        }
+    }
+
+    /// Strip the `\x1b[`/`m` wrapper off a single SGR sequence.
+    fn sgr_params(sequence: &str) -> String {
+        sequence
+            .trim_start_matches("\x1b[")
+            .trim_end_matches('m')
+            .to_string()
+    }
+
     /// Get the SGR background sequence for this color (Standard system only).
     pub fn to_sgr_bg(&self) -> String {
         match self {
@@ -372,7 +633,8 @@ impl Color {
             Color::BrightCyan => "\x1b[106m".to_string(),
             Color::BrightWhite => "\x1b[107m".to_string(),
             Color::Default => "\x1b[49m".to_string(),
-            _ => String::new(),
+            Color::Ansi256(code) => format!("\x1b[48;5;{code}m"),
+            Color::Rgb { r, g, b } => format!("\x1b[48;2;{r};{g};{b}m"),
         }
     }
     /// Helper to convert standard ANSI code (0-15) to Color.
@@ -398,6 +660,126 @@ impl Color {
         }
     }
 
+    /// Promote this color to its RGB triple, converting named/256-color
+    /// values via [`Self::ansi256_to_rgb_values`]. The inverse of
+    /// [`Self::to_ansi256`] for the purpose of numeric interpolation.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Color::Rgb { r, g, b } => (*r, *g, *b),
+            Color::Ansi256(code) => Self::ansi256_to_rgb_values(*code),
+            Color::Default => (0, 0, 0),
+            _ => {
+                if let Color::Ansi256(code) = self.to_ansi256() {
+                    Self::ansi256_to_rgb_values(code)
+                } else {
+                    (0, 0, 0)
+                }
+            }
+        }
+    }
+
+    /// Linearly interpolate from this color to `other`, promoting both to
+    /// RGB first. `alpha` is clamped to `0.0..=1.0`; `0.0` returns `self`,
+    /// `1.0` returns `other`.
+    pub fn blend(&self, other: &Color, alpha: f32) -> Color {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let (r0, g0, b0) = self.to_rgb();
+        let (r1, g1, b1) = other.to_rgb();
+        let lerp = |c0: u8, c1: u8| (c0 as f32 * (1.0 - alpha) + c1 as f32 * alpha).round() as u8;
+        Color::Rgb {
+            r: lerp(r0, r1),
+            g: lerp(g0, g1),
+            b: lerp(b0, b1),
+        }
+    }
+
+    /// Convert to an `(h, s, l)` triple (hue in degrees, saturation/lightness
+    /// in `0.0..=1.0`), promoting to RGB first via [`Self::to_rgb`].
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb();
+        Self::rgb_to_hsl(r, g, b)
+    }
+
+    /// Build an RGB color from an `(h, s, l)` triple, the inverse of
+    /// [`Color::to_hsl`].
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        let (r, g, b) = Self::hsl_to_rgb(h, s, l);
+        Color::Rgb { r, g, b }
+    }
+
+    /// The WCAG relative luminance of this color: each sRGB channel is
+    /// linearized (`c <= 0.03928 ? c/12.92 : ((c+0.055)/1.055)^2.4`) and
+    /// combined as `L = 0.2126*R + 0.7152*G + 0.0722*B`.
+    pub fn relative_luminance(&self) -> f32 {
+        fn linearize(channel: u8) -> f32 {
+            let c = channel as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = self.to_rgb();
+        0.2126 * linearize(r) + 0.7152 * linearize(g) + 0.0722 * linearize(b)
+    }
+
+    /// The WCAG contrast ratio between this color and `other`:
+    /// `(L_lighter + 0.05) / (L_darker + 0.05)`, always `>= 1.0`.
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (l1, l2) = (self.relative_luminance(), other.relative_luminance());
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Composite this color, treated as a semi-transparent foreground with
+    /// opacity `alpha`, over an opaque `background`.
+    pub fn blend_over(&self, background: &Color, alpha: f32) -> Color {
+        background.blend(self, alpha)
+    }
+
+    /// Blend this color toward white by `amount` (0.0 = unchanged, 1.0 = white).
+    pub fn lighten(&self, amount: f32) -> Color {
+        let white = Color::Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+        self.blend(&white, amount)
+    }
+
+    /// Blend this color toward black by `amount` (0.0 = unchanged, 1.0 = black).
+    pub fn darken(&self, amount: f32) -> Color {
+        self.blend(&Color::Rgb { r: 0, g: 0, b: 0 }, amount)
+    }
+
+    /// Convert this color to the best representation a terminal with the
+    /// given [`ColorSystem`] can display, downsampling `Rgb`/`Ansi256`
+    /// toward the 256-color cube or the standard 16 colors as needed.
+    pub fn downgrade(&self, system: ColorSystem) -> Color {
+        match system {
+            ColorSystem::NoColor => Color::Default,
+            ColorSystem::TrueColor => *self,
+            ColorSystem::EightBit => self.to_ansi256(),
+            ColorSystem::Standard | ColorSystem::Windows => self.to_standard(),
+        }
+    }
+
+    /// "Redmean" weighted color distance, a cheap approximation of
+    /// perceptual difference that outperforms plain Euclidean RGB distance
+    /// (e.g. it doesn't mistake mid greens/browns for each other as often).
+    fn redmean_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+        let (r1, g1, b1) = (f64::from(a.0), f64::from(a.1), f64::from(a.2));
+        let (r2, g2, b2) = (f64::from(b.0), f64::from(b.1), f64::from(b.2));
+        let rmean = (r1 + r2) / 2.0;
+        let dr = r1 - r2;
+        let dg = g1 - g2;
+        let db = b1 - b2;
+        let dist = (2.0 + rmean / 256.0) * dr * dr
+            + 4.0 * dg * dg
+            + (2.0 + (255.0 - rmean) / 256.0) * db * db;
+        dist.round() as u32
+    }
+
     /// Helper to get RGB values for an ANSI 256 code.
     fn ansi256_to_rgb_values(code: u8) -> (u8, u8, u8) {
         if code < 16 {
@@ -442,6 +824,11 @@ impl Color {
 }
 
 /// Style attributes for text.
+///
+/// The boolean attributes are tri-state (`Option<bool>`): `None` means "no
+/// opinion, inherit from whatever this style is combined onto", `Some(true)`
+/// forces the attribute on, and `Some(false)` forces it off -- so a theme
+/// override can turn off a `bold` that a base style turned on.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Style {
     /// Foreground color
@@ -449,21 +836,23 @@ pub struct Style {
     /// Background color
     pub background: Option<Color>,
     /// Bold text
-    pub bold: bool,
+    pub bold: Option<bool>,
     /// Dim/faint text
-    pub dim: bool,
+    pub dim: Option<bool>,
     /// Italic text
-    pub italic: bool,
+    pub italic: Option<bool>,
     /// Underlined text
-    pub underline: bool,
+    pub underline: Option<bool>,
     /// Blinking text
-    pub blink: bool,
+    pub blink: Option<bool>,
     /// Reversed colors (fg/bg swapped)
-    pub reverse: bool,
+    pub reverse: Option<bool>,
     /// Hidden/invisible text
-    pub hidden: bool,
+    pub hidden: Option<bool>,
     /// Strikethrough text
-    pub strikethrough: bool,
+    pub strikethrough: Option<bool>,
+    /// Overlined text
+    pub overline: Option<bool>,
 }
 
 impl Style {
@@ -472,14 +861,15 @@ impl Style {
         Style {
             foreground: None,
             background: None,
-            bold: false,
-            dim: false,
-            italic: false,
-            underline: false,
-            blink: false,
-            reverse: false,
-            hidden: false,
-            strikethrough: false,
+            bold: None,
+            dim: None,
+            italic: None,
+            underline: None,
+            blink: None,
+            reverse: None,
+            hidden: None,
+            strikethrough: None,
+            overline: None,
         }
     }
 
@@ -505,90 +895,156 @@ impl Style {
         self.background(color)
     }
 
-    /// Enable bold.
+    /// Force bold on.
     pub fn bold(mut self) -> Self {
-        self.bold = true;
+        self.bold = Some(true);
+        self
+    }
+
+    /// Force bold off, overriding any base style that turned it on.
+    pub fn not_bold(mut self) -> Self {
+        self.bold = Some(false);
         self
     }
 
-    /// Enable dim/faint.
+    /// Force dim/faint on.
     pub fn dim(mut self) -> Self {
-        self.dim = true;
+        self.dim = Some(true);
+        self
+    }
+
+    /// Force dim/faint off, overriding any base style that turned it on.
+    pub fn not_dim(mut self) -> Self {
+        self.dim = Some(false);
         self
     }
 
-    /// Enable italic.
+    /// Force italic on.
     pub fn italic(mut self) -> Self {
-        self.italic = true;
+        self.italic = Some(true);
+        self
+    }
+
+    /// Force italic off, overriding any base style that turned it on.
+    pub fn not_italic(mut self) -> Self {
+        self.italic = Some(false);
         self
     }
 
-    /// Enable underline.
+    /// Force underline on.
     pub fn underline(mut self) -> Self {
-        self.underline = true;
+        self.underline = Some(true);
         self
     }
 
-    /// Enable blink.
+    /// Force underline off, overriding any base style that turned it on.
+    pub fn not_underline(mut self) -> Self {
+        self.underline = Some(false);
+        self
+    }
+
+    /// Force blink on.
     pub fn blink(mut self) -> Self {
-        self.blink = true;
+        self.blink = Some(true);
+        self
+    }
+
+    /// Force blink off, overriding any base style that turned it on.
+    pub fn not_blink(mut self) -> Self {
+        self.blink = Some(false);
         self
     }
 
-    /// Enable reverse (swap fg/bg).
+    /// Force reverse (swap fg/bg) on.
     pub fn reverse(mut self) -> Self {
-        self.reverse = true;
+        self.reverse = Some(true);
         self
     }
 
-    /// Enable hidden/invisible.
+    /// Force reverse off, overriding any base style that turned it on.
+    pub fn not_reverse(mut self) -> Self {
+        self.reverse = Some(false);
+        self
+    }
+
+    /// Force hidden/invisible on.
     pub fn hidden(mut self) -> Self {
-        self.hidden = true;
+        self.hidden = Some(true);
         self
     }
 
-    /// Enable strikethrough.
+    /// Force hidden/invisible off, overriding any base style that turned it on.
+    pub fn not_hidden(mut self) -> Self {
+        self.hidden = Some(false);
+        self
+    }
+
+    /// Force strikethrough on.
     pub fn strikethrough(mut self) -> Self {
-        self.strikethrough = true;
+        self.strikethrough = Some(true);
         self
     }
 
-    /// Combine this style with another, with `other` taking precedence.
+    /// Force strikethrough off, overriding any base style that turned it on.
+    pub fn not_strikethrough(mut self) -> Self {
+        self.strikethrough = Some(false);
+        self
+    }
+
+    /// Force overline on.
+    pub fn overline(mut self) -> Self {
+        self.overline = Some(true);
+        self
+    }
+
+    /// Force overline off, overriding any base style that turned it on.
+    pub fn not_overline(mut self) -> Self {
+        self.overline = Some(false);
+        self
+    }
+
+    /// Combine this style with another, with `other`'s explicit attributes
+    /// (`Some(_)`) overriding `self`'s, and `other`'s `None` attributes
+    /// inheriting from `self`.
     pub fn combine(&self, other: &Style) -> Style {
         Style {
             foreground: other.foreground.or(self.foreground),
             background: other.background.or(self.background),
-            bold: self.bold || other.bold,
-            dim: self.dim || other.dim,
-            italic: self.italic || other.italic,
-            underline: self.underline || other.underline,
-            blink: self.blink || other.blink,
-            reverse: self.reverse || other.reverse,
-            hidden: self.hidden || other.hidden,
-            strikethrough: self.strikethrough || other.strikethrough,
+            bold: other.bold.or(self.bold),
+            dim: other.dim.or(self.dim),
+            italic: other.italic.or(self.italic),
+            underline: other.underline.or(self.underline),
+            blink: other.blink.or(self.blink),
+            reverse: other.reverse.or(self.reverse),
+            hidden: other.hidden.or(self.hidden),
+            strikethrough: other.strikethrough.or(self.strikethrough),
+            overline: other.overline.or(self.overline),
         }
     }
 
-    /// Check if this style has any attributes set.
+    /// Check if this style has any attributes set (on or explicitly off).
     pub fn is_empty(&self) -> bool {
         self.foreground.is_none()
             && self.background.is_none()
-            && !self.bold
-            && !self.dim
-            && !self.italic
-            && !self.underline
-            && !self.blink
-            && !self.reverse
-            && !self.hidden
-            && !self.strikethrough
+            && self.bold.is_none()
+            && self.dim.is_none()
+            && self.italic.is_none()
+            && self.underline.is_none()
+            && self.blink.is_none()
+            && self.reverse.is_none()
+            && self.hidden.is_none()
+            && self.strikethrough.is_none()
+            && self.overline.is_none()
     }
 
     /// Parse a style from a string.
     ///
-    /// Supports space-separated attributes: "bold red on blue"
+    /// Supports space-separated attributes: "bold red on blue". A `"not"`
+    /// token negates the attribute that follows it, e.g. "bold not underline".
     pub fn parse(s: &str) -> Self {
         let mut style = Style::new();
         let mut on_background = false;
+        let mut negate_next = false;
 
         for part in s.split_whitespace() {
             let part_lower = part.to_lowercase();
@@ -598,20 +1054,23 @@ impl Style {
                 continue;
             }
 
+            if part_lower == "not" {
+                negate_next = true;
+                continue;
+            }
+
             // Check for attributes
+            let value = Some(!negate_next);
             match part_lower.as_str() {
-                "bold" | "b" => style.bold = true,
-                "dim" => style.dim = true,
-                "italic" | "i" => style.italic = true,
-                "underline" | "u" => style.underline = true,
-                "blink" => style.blink = true,
-                "reverse" => style.reverse = true,
-                "hidden" => style.hidden = true,
-                "strike" | "strikethrough" | "s" => style.strikethrough = true,
-                "not" => {
-                    // "not bold" etc. - skip for now, just consume
-                    continue;
-                }
+                "bold" | "b" => style.bold = value,
+                "dim" => style.dim = value,
+                "italic" | "i" => style.italic = value,
+                "underline" | "u" => style.underline = value,
+                "blink" => style.blink = value,
+                "reverse" => style.reverse = value,
+                "hidden" | "conceal" => style.hidden = value,
+                "strike" | "strikethrough" | "s" => style.strikethrough = value,
+                "overline" => style.overline = value,
                 _ => {
                     // Try to parse as color
                     if let Some(color) = Color::parse(&part_lower) {
@@ -624,6 +1083,7 @@ impl Style {
                     }
                 }
             }
+            negate_next = false;
         }
 
         style
@@ -634,28 +1094,28 @@ impl Style {
         use crossterm::style::Attribute;
         let mut attrs = crossterm::style::Attributes::default();
 
-        if self.bold {
+        if self.bold == Some(true) {
             attrs.set(Attribute::Bold);
         }
-        if self.dim {
+        if self.dim == Some(true) {
             attrs.set(Attribute::Dim);
         }
-        if self.italic {
+        if self.italic == Some(true) {
             attrs.set(Attribute::Italic);
         }
-        if self.underline {
+        if self.underline == Some(true) {
             attrs.set(Attribute::Underlined);
         }
-        if self.blink {
+        if self.blink == Some(true) {
             attrs.set(Attribute::SlowBlink);
         }
-        if self.reverse {
+        if self.reverse == Some(true) {
             attrs.set(Attribute::Reverse);
         }
-        if self.hidden {
+        if self.hidden == Some(true) {
             attrs.set(Attribute::Hidden);
         }
-        if self.strikethrough {
+        if self.strikethrough == Some(true) {
             attrs.set(Attribute::CrossedOut);
         }
 
@@ -674,45 +1134,499 @@ impl Style {
         if let Some(ref bg) = self.background {
             parts.push(format!("background-color: {}", bg.to_css()));
         }
-        if self.bold {
+        if self.bold == Some(true) {
             parts.push("font-weight: bold".to_string());
         }
-        if self.italic {
+        if self.italic == Some(true) {
             parts.push("font-style: italic".to_string());
         }
-        if self.underline {
+        if self.underline == Some(true) {
             parts.push("text-decoration: underline".to_string());
         }
-        if self.strikethrough {
+        if self.strikethrough == Some(true) {
             parts.push("text-decoration: line-through".to_string());
         }
-        if self.dim {
+        if self.dim == Some(true) {
             parts.push("opacity: 0.5".to_string());
         }
 
         parts.join("; ")
     }
+
+    /// Compose every set attribute and color into a single SGR escape
+    /// sequence, e.g. `\x1b[1;4;38;2;255;0;0m`, rather than one escape per
+    /// attribute.
+    pub fn to_ansi_prefix(&self) -> String {
+        let mut params = Vec::new();
+
+        if self.bold == Some(true) {
+            params.push("1".to_string());
+        }
+        if self.dim == Some(true) {
+            params.push("2".to_string());
+        }
+        if self.italic == Some(true) {
+            params.push("3".to_string());
+        }
+        if self.underline == Some(true) {
+            params.push("4".to_string());
+        }
+        if self.blink == Some(true) {
+            params.push("5".to_string());
+        }
+        if self.reverse == Some(true) {
+            params.push("7".to_string());
+        }
+        if self.hidden == Some(true) {
+            params.push("8".to_string());
+        }
+        if self.strikethrough == Some(true) {
+            params.push("9".to_string());
+        }
+        if self.overline == Some(true) {
+            params.push("53".to_string());
+        }
+        if let Some(ref fg) = self.foreground {
+            params.push(fg.to_sgr_fg_params());
+        }
+        if let Some(ref bg) = self.background {
+            params.push(bg.to_sgr_bg_params());
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", params.join(";"))
+        }
+    }
+
+    /// The SGR reset sequence that undoes [`Style::to_ansi_prefix`].
+    pub fn to_ansi_suffix(&self) -> String {
+        "\x1b[0m".to_string()
+    }
+
+    /// Parse a style from a string containing one or more ANSI SGR escape
+    /// sequences (any literal text between them is ignored), applying codes
+    /// in order the way a terminal would -- so a later `\x1b[0m` resets
+    /// everything set by an earlier one.
+    pub fn from_ansi(input: &str) -> Style {
+        AnsiElementIterator::new(input)
+            .filter_map(|element| match element {
+                AnsiElement::Sgr(style) => Some(style),
+                _ => None,
+            })
+            .last()
+            .unwrap_or_default()
+    }
+
+    /// Apply one escape's semicolon-separated SGR parameters to this style
+    /// in place. Code `0` resets to the default style; `38;5;n`/`48;5;n` and
+    /// `38;2;r;g;b`/`48;2;r;g;b` set 256-color/truecolor foreground and
+    /// background, consuming the extra parameters that follow them.
+    fn apply_sgr_params(&mut self, params: &str) {
+        let tokens: Vec<&str> = params.split(';').collect();
+        let tokens: Vec<&str> = if tokens == [""] { vec!["0"] } else { tokens };
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let code: i64 = match tokens[i].parse() {
+                Ok(code) => code,
+                Err(_) => {
+                    i += 1;
+                    continue;
+                }
+            };
+
+            match code {
+                0 => *self = Style::new(),
+                1 => self.bold = Some(true),
+                2 => self.dim = Some(true),
+                3 => self.italic = Some(true),
+                4 => self.underline = Some(true),
+                5 => self.blink = Some(true),
+                7 => self.reverse = Some(true),
+                8 => self.hidden = Some(true),
+                9 => self.strikethrough = Some(true),
+                53 => self.overline = Some(true),
+                30..=37 => self.foreground = Some(Color::from_ansi_standard_code((code - 30) as u8)),
+                39 => self.foreground = Some(Color::Default),
+                40..=47 => self.background = Some(Color::from_ansi_standard_code((code - 40) as u8)),
+                49 => self.background = Some(Color::Default),
+                90..=97 => {
+                    self.foreground = Some(Color::from_ansi_standard_code((code - 90) as u8 + 8))
+                }
+                100..=107 => {
+                    self.background = Some(Color::from_ansi_standard_code((code - 100) as u8 + 8))
+                }
+                38 | 48 => {
+                    let is_foreground = code == 38;
+                    match tokens.get(i + 1).copied() {
+                        Some("5") => {
+                            if let Some(n) = tokens.get(i + 2).and_then(|s| s.parse::<u8>().ok()) {
+                                let color = Color::Ansi256(n);
+                                if is_foreground {
+                                    self.foreground = Some(color);
+                                } else {
+                                    self.background = Some(color);
+                                }
+                            }
+                            i += 2;
+                        }
+                        Some("2") => {
+                            let rgb = (
+                                tokens.get(i + 2).and_then(|s| s.parse::<u8>().ok()),
+                                tokens.get(i + 3).and_then(|s| s.parse::<u8>().ok()),
+                                tokens.get(i + 4).and_then(|s| s.parse::<u8>().ok()),
+                            );
+                            if let (Some(r), Some(g), Some(b)) = rgb {
+                                let color = Color::Rgb { r, g, b };
+                                if is_foreground {
+                                    self.foreground = Some(color);
+                                } else {
+                                    self.background = Some(color);
+                                }
+                            }
+                            i += 4;
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+/// One element yielded by [`AnsiElementIterator`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnsiElement<'a> {
+    /// A run of plain text between escape sequences.
+    Text(&'a str),
+    /// The cumulative style in effect after an SGR escape sequence.
+    Sgr(Style),
+    /// The hyperlink target in effect after an OSC 8 escape sequence
+    /// (`ESC]8;;url ST`), or `None` once a closing `ESC]8;;ST` turns it
+    /// back off -- mirroring how [`AnsiElement::Sgr`] carries the
+    /// cumulative style rather than just the one escape's raw params.
+    Link(Option<String>),
+}
+
+/// Scans a string for CSI SGR escapes (`ESC [ params m`) and OSC 8
+/// hyperlink escapes (`ESC ] 8 ; ; url ST`, terminated by either the
+/// standard ST sequence `ESC \` or the common BEL `\x07` variant), yielding
+/// interleaved plain-text runs, the cumulative [`Style`] in effect after
+/// each SGR escape (code `0` resets back to the default style), and the
+/// active hyperlink target after each OSC 8 escape. Any other OSC sequence
+/// this parser doesn't model is consumed without being yielded at all, so
+/// it's dropped cleanly rather than leaking its raw escape bytes into a
+/// [`AnsiElement::Text`] run the way it would if this iterator only knew
+/// about `ESC[`.
+pub struct AnsiElementIterator<'a> {
+    remaining: &'a str,
+    current: Style,
+    current_link: Option<String>,
+}
+
+impl<'a> AnsiElementIterator<'a> {
+    /// Create an iterator over the CSI SGR escapes, OSC 8 hyperlinks, and
+    /// text runs in `input`.
+    pub fn new(input: &'a str) -> Self {
+        AnsiElementIterator {
+            remaining: input,
+            current: Style::new(),
+            current_link: None,
+        }
+    }
+}
+
+impl<'a> Iterator for AnsiElementIterator<'a> {
+    type Item = AnsiElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        if let Some(rest) = self.remaining.strip_prefix("\x1b[") {
+            if let Some(end) = rest.find('m') {
+                let params = &rest[..end];
+                self.remaining = &rest[end + 1..];
+                self.current.apply_sgr_params(params);
+                return Some(AnsiElement::Sgr(self.current));
+            }
+        }
+
+        if let Some(rest) = self.remaining.strip_prefix("\x1b]") {
+            // OSC sequences end at the ST control sequence (`ESC \`) or,
+            // for terminals following the older convention, a bare BEL.
+            // An input truncated mid-sequence (no terminator found at all)
+            // is consumed to the end rather than left to be misread as text.
+            let bel = rest.find('\x07').map(|at| (at, at + 1));
+            let st = rest.find("\x1b\\").map(|at| (at, at + 2));
+            let (end, consumed) = match (bel, st) {
+                (Some(b), Some(s)) => if b.0 < s.0 { b } else { s },
+                (found, other) => found.or(other).unwrap_or((rest.len(), rest.len())),
+            };
+            let payload = &rest[..end];
+            self.remaining = &rest[consumed..];
+
+            if let Some(url) = payload.strip_prefix("8;;") {
+                self.current_link = if url.is_empty() { None } else { Some(url.to_string()) };
+                return Some(AnsiElement::Link(self.current_link.clone()));
+            }
+            // Not a hyperlink -- some other OSC code this parser doesn't
+            // model. Already consumed above; keep scanning rather than
+            // yielding anything for it.
+            return self.next();
+        }
+
+        let first = self.remaining.chars().next().map_or(1, char::len_utf8);
+        let split_at = match ["\x1b[", "\x1b]"]
+            .iter()
+            .filter_map(|escape| self.remaining[first..].find(escape))
+            .min()
+        {
+            Some(offset) => offset + first,
+            None => self.remaining.len(),
+        };
+        let (text, rest) = self.remaining.split_at(split_at);
+        self.remaining = rest;
+        Some(AnsiElement::Text(text))
+    }
+}
+
+/// Split `s`, which may already contain SGR escape sequences, at display
+/// column `col`, tracking the active style across the cut via
+/// [`AnsiElementIterator`] so neither half loses color/attribute state: the
+/// left half gets a trailing `\x1b[0m` reset, and the right half is
+/// re-prefixed with whatever style was active at the cut point. Display
+/// columns are counted with `char_indices`/`chars().count()`, one column per
+/// `char`: a wide glyph counts as one column here (see
+/// [`ansi_slice_by_width`] for a width-aware cut). Escape sequences themselves never count toward `col` and are
+/// never split apart.
+pub fn ansi_split_at(s: &str, col: usize) -> (String, String) {
+    let mut left = String::new();
+    let mut right = String::new();
+    let mut column = 0usize;
+    let mut active = Style::new();
+    let mut cut = false;
+
+    for element in AnsiElementIterator::new(s) {
+        match element {
+            AnsiElement::Sgr(style) => {
+                active = style;
+                if cut {
+                    right.push_str(&style.to_ansi_prefix());
+                } else {
+                    left.push_str(&style.to_ansi_prefix());
+                }
+            }
+            AnsiElement::Text(text) => {
+                for ch in text.chars() {
+                    if !cut && column >= col {
+                        cut = true;
+                        left.push_str("\x1b[0m");
+                        right.push_str(&active.to_ansi_prefix());
+                    }
+                    if cut {
+                        right.push(ch);
+                    } else {
+                        left.push(ch);
+                        column += 1;
+                    }
+                }
+            }
+            // Hyperlinks aren't part of this function's SGR-only contract;
+            // dropping them here is strictly better than the pre-OSC-aware
+            // behavior, which would have scattered the escape's raw bytes
+            // into whichever half it happened to land in.
+            AnsiElement::Link(_) => {}
+        }
+    }
+
+    if !cut {
+        left.push_str("\x1b[0m");
+    }
+
+    (left, right)
+}
+
+/// Truncate `s`, which may already contain SGR escape sequences, to at most
+/// `width` display columns, discarding the remainder and closing any
+/// still-active style with a trailing `\x1b[0m`. See [`ansi_split_at`] for
+/// how the active style and display width are tracked.
+pub fn ansi_truncate(s: &str, width: usize) -> String {
+    ansi_split_at(s, width).0
+}
+
+/// Cut `s`, which may already contain SGR escape sequences, down to the
+/// visible-column range `[start, end)`, re-opening whatever style was
+/// active at `start` so the slice is self-contained and closing it with a
+/// single trailing `\x1b[0m`, the way `ansi-str`/`ansi-cut`'s `ansi_get`
+/// does. Unlike [`ansi_split_at`], columns are measured with each
+/// character's actual display width (`unicode_width`), so a wide CJK glyph
+/// counts for two columns rather than one -- the dependency wasn't
+/// available yet when `ansi_split_at` was first written, but is now used
+/// crate-wide (see [`crate::bidi::display_width`]). As with
+/// `ansi_split_at`, escape sequences never count toward the column total
+/// and are never split apart; a character whose column range would
+/// straddle `start` or `end` is dropped entirely rather than rendered as a
+/// half-width glyph.
+pub fn ansi_slice_by_width(s: &str, start: usize, end: usize) -> String {
+    use unicode_width::UnicodeWidthChar;
+
+    let mut out = String::new();
+    let mut column = 0usize;
+    let mut active = Style::new();
+    let mut opened = false;
+
+    'outer: for element in AnsiElementIterator::new(s) {
+        match element {
+            AnsiElement::Sgr(style) => {
+                active = style;
+            }
+            AnsiElement::Text(text) => {
+                for ch in text.chars() {
+                    if column >= end {
+                        break 'outer;
+                    }
+
+                    let char_width = ch.width().unwrap_or(0);
+                    if column >= start && column + char_width <= end {
+                        if !opened {
+                            out.push_str(&active.to_ansi_prefix());
+                            opened = true;
+                        }
+                        out.push(ch);
+                    }
+                    column += char_width;
+                }
+            }
+            // Same rationale as ansi_split_at: this function only models
+            // SGR columns, so a hyperlink is dropped rather than leaked.
+            AnsiElement::Link(_) => {}
+        }
+    }
+
+    if opened {
+        out.push_str("\x1b[0m");
+    }
+
+    out
+}
+
+/// Alias for [`StyleDiff`], `Style::difference`'s return type, under the name
+/// a minimal-transition-codes renderer would look for.
+pub type StyleDelta = StyleDiff;
+
+/// The result of [`Style::difference`]: how to get from one style to the
+/// next with the fewest emitted SGR codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleDiff {
+    /// `next` is identical to the style it's being compared against; emit nothing.
+    NoChange,
+    /// `next` only adds attributes/colors on top of the previous style, so
+    /// emitting just these (without a reset) reproduces `next`.
+    ExtraOnly(Style),
+    /// `next` turns off something the previous style had on, or changes a
+    /// color that was set; emit `\x1b[0m` followed by this style's full prefix.
+    Reset(Style),
+}
+
+impl Style {
+    /// Compute the minimal update needed to go from `self` to `next` when
+    /// rendering a stream of styled spans, so a renderer can avoid re-emitting
+    /// a full SGR prefix (and a reset) for every span.
+    ///
+    /// If `next` can be reached by adding attributes/colors on top of `self`
+    /// (never turning one off or changing an already-set color), returns
+    /// [`StyleDiff::ExtraOnly`] with just the newly added fields. Otherwise,
+    /// since SGR codes can't be undone additively, returns [`StyleDiff::Reset`]
+    /// with the full `next` style to apply after a reset.
+    pub fn difference(&self, next: &Style) -> StyleDiff {
+        if self == next {
+            return StyleDiff::NoChange;
+        }
+
+        let color_needs_reset = |current: Option<Color>, next: Option<Color>| {
+            current.is_some() && current != next
+        };
+        // An unset (`None`) attribute renders as off, same as `Some(false)`.
+        let effective = |attr: Option<bool>| attr.unwrap_or(false);
+        let bool_needs_reset =
+            |current: Option<bool>, next: Option<bool>| effective(current) && !effective(next);
+
+        let needs_reset = color_needs_reset(self.foreground, next.foreground)
+            || color_needs_reset(self.background, next.background)
+            || bool_needs_reset(self.bold, next.bold)
+            || bool_needs_reset(self.dim, next.dim)
+            || bool_needs_reset(self.italic, next.italic)
+            || bool_needs_reset(self.underline, next.underline)
+            || bool_needs_reset(self.blink, next.blink)
+            || bool_needs_reset(self.reverse, next.reverse)
+            || bool_needs_reset(self.hidden, next.hidden)
+            || bool_needs_reset(self.strikethrough, next.strikethrough)
+            || bool_needs_reset(self.overline, next.overline);
+
+        if needs_reset {
+            return StyleDiff::Reset(*next);
+        }
+
+        let newly_on = |current: Option<bool>, next: Option<bool>| {
+            if effective(next) && !effective(current) {
+                Some(true)
+            } else {
+                None
+            }
+        };
+
+        StyleDiff::ExtraOnly(Style {
+            foreground: if next.foreground != self.foreground {
+                next.foreground
+            } else {
+                None
+            },
+            background: if next.background != self.background {
+                next.background
+            } else {
+                None
+            },
+            bold: newly_on(self.bold, next.bold),
+            dim: newly_on(self.dim, next.dim),
+            italic: newly_on(self.italic, next.italic),
+            underline: newly_on(self.underline, next.underline),
+            blink: newly_on(self.blink, next.blink),
+            reverse: newly_on(self.reverse, next.reverse),
+            hidden: newly_on(self.hidden, next.hidden),
+            strikethrough: newly_on(self.strikethrough, next.strikethrough),
+            overline: newly_on(self.overline, next.overline),
+        })
+    }
 }
 
 impl fmt::Display for Style {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut parts = Vec::new();
 
-        if self.bold {
+        if self.bold == Some(true) {
             parts.push("bold");
         }
-        if self.dim {
+        if self.dim == Some(true) {
             parts.push("dim");
         }
-        if self.italic {
+        if self.italic == Some(true) {
             parts.push("italic");
         }
-        if self.underline {
+        if self.underline == Some(true) {
             parts.push("underline");
         }
-        if self.strikethrough {
+        if self.strikethrough == Some(true) {
             parts.push("strikethrough");
         }
+        if self.overline == Some(true) {
+            parts.push("overline");
+        }
 
         write!(f, "{}", parts.join(" "))
     }
@@ -770,19 +1684,34 @@ mod tests {
     #[test]
     fn test_style_parse() {
         let style = Style::parse("bold red on blue");
-        assert!(style.bold);
+        assert_eq!(style.bold, Some(true));
         assert_eq!(style.foreground, Some(Color::Red));
         assert_eq!(style.background, Some(Color::Blue));
     }
 
+    #[test]
+    fn test_style_parse_negation() {
+        let style = Style::parse("bold not underline");
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(false));
+    }
+
     #[test]
     fn test_style_builder() {
         let style = Style::new().foreground(Color::Green).bold().underline();
 
-        assert!(style.bold);
-        assert!(style.underline);
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(true));
         assert_eq!(style.foreground, Some(Color::Green));
-        assert!(!style.italic);
+        assert_eq!(style.italic, None);
+    }
+
+    #[test]
+    fn test_style_not_bold_overrides_base() {
+        let base = Style::new().bold();
+        let overlay = Style::new().not_bold();
+        let combined = base.combine(&overlay);
+        assert_eq!(combined.bold, Some(false));
     }
 
     #[test]
@@ -792,8 +1721,8 @@ mod tests {
         let combined = base.combine(&overlay);
 
         assert_eq!(combined.foreground, Some(Color::Blue)); // overlay wins
-        assert!(combined.bold); // kept from base
-        assert!(combined.italic); // added from overlay
+        assert_eq!(combined.bold, Some(true)); // kept from base
+        assert_eq!(combined.italic, Some(true)); // added from overlay
     }
 
     #[test]
@@ -801,4 +1730,418 @@ mod tests {
         assert!(Style::new().is_empty());
         assert!(!Style::new().bold().is_empty());
     }
+
+    #[test]
+    fn test_style_difference_no_change() {
+        let style = Style::new().foreground(Color::Red).bold();
+        assert_eq!(style.difference(&style), StyleDiff::NoChange);
+    }
+
+    #[test]
+    fn test_style_difference_extra_only() {
+        let base = Style::new().bold();
+        let next = Style::new().bold().underline();
+        match base.difference(&next) {
+            StyleDiff::ExtraOnly(extra) => {
+                assert_eq!(extra.bold, None); // already active, not re-emitted
+                assert_eq!(extra.underline, Some(true)); // newly added
+            }
+            other => panic!("expected ExtraOnly, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_style_difference_reset_on_turn_off() {
+        let base = Style::new().bold().underline();
+        let next = Style::new().bold();
+        assert_eq!(base.difference(&next), StyleDiff::Reset(next));
+    }
+
+    #[test]
+    fn test_style_difference_reset_on_color_change() {
+        let base = Style::new().foreground(Color::Red);
+        let next = Style::new().foreground(Color::Blue);
+        assert_eq!(base.difference(&next), StyleDiff::Reset(next));
+    }
+
+    #[test]
+    fn test_color_to_rgb() {
+        assert_eq!(Color::Rgb { r: 1, g: 2, b: 3 }.to_rgb(), (1, 2, 3));
+        assert_eq!(Color::Black.to_rgb(), (0, 0, 0));
+        assert_eq!(Color::BrightRed.to_rgb(), (255, 0, 0));
+    }
+
+    #[test]
+    fn test_to_sgr_fg_bg_ansi256_and_rgb() {
+        assert_eq!(Color::Ansi256(196).to_sgr_fg(), "\x1b[38;5;196m");
+        assert_eq!(Color::Ansi256(196).to_sgr_bg(), "\x1b[48;5;196m");
+        assert_eq!(
+            Color::Rgb { r: 255, g: 0, b: 0 }.to_sgr_fg(),
+            "\x1b[38;2;255;0;0m"
+        );
+        assert_eq!(
+            Color::Rgb { r: 255, g: 0, b: 0 }.to_sgr_bg(),
+            "\x1b[48;2;255;0;0m"
+        );
+    }
+
+    #[test]
+    fn test_to_ansi_prefix_combines_attributes_and_truecolor() {
+        let style = Style::new()
+            .bold()
+            .underline()
+            .foreground(Color::Rgb { r: 255, g: 0, b: 0 });
+        assert_eq!(style.to_ansi_prefix(), "\x1b[1;4;38;2;255;0;0m");
+        assert_eq!(style.to_ansi_suffix(), "\x1b[0m");
+    }
+
+    #[test]
+    fn test_to_ansi_prefix_empty_style() {
+        assert_eq!(Style::new().to_ansi_prefix(), "");
+    }
+
+    #[test]
+    fn test_color_blend_halfway() {
+        let black = Color::Rgb { r: 0, g: 0, b: 0 };
+        let white = Color::Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+        assert_eq!(
+            black.blend(&white, 0.5),
+            Color::Rgb {
+                r: 128,
+                g: 128,
+                b: 128
+            }
+        );
+        assert_eq!(black.blend(&white, 0.0), black);
+        assert_eq!(black.blend(&white, 1.0), white);
+    }
+
+    #[test]
+    fn test_color_blend_over() {
+        let background = Color::Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+        let foreground = Color::Rgb { r: 0, g: 0, b: 0 };
+        assert_eq!(
+            foreground.blend_over(&background, 0.25),
+            Color::Rgb {
+                r: 191,
+                g: 191,
+                b: 191
+            }
+        );
+    }
+
+    #[test]
+    fn test_color_lighten_darken() {
+        let mid = Color::Rgb {
+            r: 100,
+            g: 100,
+            b: 100,
+        };
+        assert_eq!(
+            mid.lighten(1.0),
+            Color::Rgb {
+                r: 255,
+                g: 255,
+                b: 255
+            }
+        );
+        assert_eq!(mid.darken(1.0), Color::Rgb { r: 0, g: 0, b: 0 });
+    }
+
+    #[test]
+    fn test_to_standard_uses_redmean_distance() {
+        let pure_red = Color::Rgb { r: 255, g: 0, b: 0 };
+        assert_eq!(pure_red.to_standard(), Color::BrightRed);
+
+        let olive_green = Color::Rgb {
+            r: 110,
+            g: 120,
+            b: 40,
+        };
+        assert_eq!(olive_green.to_standard(), Color::Yellow);
+    }
+
+    #[test]
+    fn test_to_ansi256_quantizes_to_nearest_cube_corner() {
+        // Pure white sits exactly on the color cube's (255,255,255) corner,
+        // code 16 + 36*5 + 6*5 + 5 = 231.
+        assert_eq!(
+            Color::Rgb {
+                r: 255,
+                g: 255,
+                b: 255
+            }
+            .to_ansi256(),
+            Color::Ansi256(231)
+        );
+        // A mid-gray lands in the 24-step grayscale ramp rather than the cube.
+        assert_eq!(
+            Color::Rgb {
+                r: 128,
+                g: 128,
+                b: 128
+            }
+            .to_ansi256(),
+            Color::Ansi256(244)
+        );
+    }
+
+    #[test]
+    fn test_color_downgrade_per_system() {
+        let truecolor = Color::Rgb {
+            r: 12,
+            g: 34,
+            b: 56,
+        };
+        assert_eq!(truecolor.downgrade(ColorSystem::TrueColor), truecolor);
+        assert_eq!(
+            truecolor.downgrade(ColorSystem::EightBit),
+            truecolor.to_ansi256()
+        );
+        assert_eq!(
+            truecolor.downgrade(ColorSystem::Standard),
+            truecolor.to_standard()
+        );
+        assert_eq!(truecolor.downgrade(ColorSystem::NoColor), Color::Default);
+    }
+
+    #[test]
+    fn test_ansi_element_iterator_splits_text_and_sgr() {
+        let input = "\x1b[1mhello\x1b[0m world";
+        let elements: Vec<_> = AnsiElementIterator::new(input).collect();
+        assert_eq!(
+            elements,
+            vec![
+                AnsiElement::Sgr(Style::new().bold()),
+                AnsiElement::Text("hello"),
+                AnsiElement::Sgr(Style::new()),
+                AnsiElement::Text(" world"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_style_from_ansi_basic_attributes() {
+        let style = Style::from_ansi("\x1b[1;4;31m");
+        assert_eq!(style.bold, Some(true));
+        assert_eq!(style.underline, Some(true));
+        assert_eq!(style.foreground, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_style_from_ansi_256_and_truecolor() {
+        let style = Style::from_ansi("\x1b[38;5;196;48;2;0;0;255m");
+        assert_eq!(style.foreground, Some(Color::Ansi256(196)));
+        assert_eq!(style.background, Some(Color::Rgb { r: 0, g: 0, b: 255 }));
+    }
+
+    #[test]
+    fn test_style_from_ansi_reset_clears_earlier_codes() {
+        let style = Style::from_ansi("\x1b[1;31m\x1b[0m\x1b[4m");
+        assert_eq!(style.bold, None);
+        assert_eq!(style.foreground, None);
+        assert_eq!(style.underline, Some(true));
+    }
+
+    #[test]
+    fn test_overline_builder_and_display() {
+        let style = Style::new().overline();
+        assert_eq!(style.overline, Some(true));
+        assert_eq!(style.to_string(), "overline");
+        assert_eq!(style.to_ansi_prefix(), "\x1b[53m");
+
+        let style = style.not_overline();
+        assert_eq!(style.overline, Some(false));
+    }
+
+    #[test]
+    fn test_parse_overline_and_conceal() {
+        let style = Style::parse("overline");
+        assert_eq!(style.overline, Some(true));
+
+        let style = Style::parse("conceal");
+        assert_eq!(style.hidden, Some(true));
+        assert_eq!(style.overline, None);
+    }
+
+    #[test]
+    fn test_style_from_ansi_overline() {
+        let style = Style::from_ansi("\x1b[53m");
+        assert_eq!(style.overline, Some(true));
+    }
+
+    #[test]
+    fn test_color_parse_css_names() {
+        assert_eq!(
+            Color::parse("rebeccapurple"),
+            Some(Color::Rgb { r: 102, g: 51, b: 153 })
+        );
+        assert_eq!(
+            Color::parse("dodgerblue"),
+            Some(Color::Rgb { r: 30, g: 144, b: 255 })
+        );
+        assert_eq!(
+            Color::parse("CORAL"),
+            Some(Color::Rgb { r: 255, g: 127, b: 80 })
+        );
+        assert_eq!(Color::parse("not-a-color"), None);
+    }
+
+    #[test]
+    fn test_ansi_truncate_preserves_style_and_closes_it() {
+        let input = "\x1b[1;31mhello world\x1b[0m";
+        let truncated = ansi_truncate(input, 5);
+        assert!(truncated.starts_with("\x1b[1;31m"));
+        assert!(truncated.ends_with("\x1b[0m"));
+        let plain: String = AnsiElementIterator::new(&truncated)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(plain, "hello");
+    }
+
+    #[test]
+    fn test_ansi_split_at_reproduces_both_halves_styled() {
+        let input = "\x1b[1mfoo\x1b[0mbar";
+        let (left, right) = ansi_split_at(input, 3);
+
+        let left_plain: String = AnsiElementIterator::new(&left)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        let right_plain: String = AnsiElementIterator::new(&right)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(left_plain, "foo");
+        assert_eq!(right_plain, "bar");
+        assert!(left.starts_with("\x1b[1m"));
+        assert!(left.ends_with("\x1b[0m"));
+        assert_eq!(Style::from_ansi(&right).bold, None);
+    }
+
+    #[test]
+    fn test_ansi_split_at_never_breaks_an_escape_sequence() {
+        let input = "\x1b[31mabcdef\x1b[0m";
+        let (left, right) = ansi_split_at(input, 0);
+        assert!(!left.contains('a'));
+        assert_eq!(Style::from_ansi(&right).foreground, Some(Color::Red));
+        let right_plain: String = AnsiElementIterator::new(&right)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(right_plain, "abcdef");
+    }
+
+    #[test]
+    fn test_ansi_slice_by_width_reopens_the_active_style_at_the_cut() {
+        let input = "\x1b[31mRed\x1b[0m \x1b[34mBlue\x1b[0m";
+        let slice = ansi_slice_by_width(input, 4, 8);
+
+        assert!(slice.starts_with("\x1b[34m"));
+        assert!(slice.ends_with("\x1b[0m"));
+        let plain: String = AnsiElementIterator::new(&slice)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(plain, "Blue");
+    }
+
+    #[test]
+    fn test_ansi_slice_by_width_excludes_a_wide_glyph_straddling_the_boundary() {
+        // "\u{4e2d}" (中) is a double-width CJK glyph occupying columns 0-1.
+        let input = "\u{4e2d}ab";
+        // Slicing from column 1 would cut the glyph in half -- it should be
+        // dropped, not rendered as a single column.
+        let slice = ansi_slice_by_width(input, 1, 4);
+        let plain: String = AnsiElementIterator::new(&slice)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(plain, "ab");
+    }
+
+    #[test]
+    fn test_ansi_slice_by_width_counts_wide_glyphs_as_two_columns() {
+        let input = "\u{4e2d}\u{6587}ab";
+        // The two double-width glyphs occupy columns 0-3; slicing to width 4
+        // should capture only them, not the following "ab".
+        let slice = ansi_slice_by_width(input, 0, 4);
+        let plain: String = AnsiElementIterator::new(&slice)
+            .filter_map(|e| match e {
+                AnsiElement::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(plain, "\u{4e2d}\u{6587}");
+    }
+
+    #[test]
+    fn test_color_parse_hsl() {
+        assert_eq!(
+            Color::parse("hsl(0, 100%, 50%)"),
+            Some(Color::Rgb { r: 255, g: 0, b: 0 })
+        );
+        assert_eq!(
+            Color::parse("hsl(120, 100%, 50%)"),
+            Some(Color::Rgb { r: 0, g: 255, b: 0 })
+        );
+        assert_eq!(
+            Color::parse("hsl(240, 100%, 50%)"),
+            Some(Color::Rgb { r: 0, g: 0, b: 255 })
+        );
+        assert_eq!(
+            Color::parse("hsl(0, 0%, 100%)"),
+            Some(Color::Rgb { r: 255, g: 255, b: 255 })
+        );
+    }
+
+    #[test]
+    fn test_to_hsl_is_the_inverse_of_from_hsl() {
+        let color = Color::Rgb { r: 64, g: 200, b: 96 };
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), color);
+    }
+
+    #[test]
+    fn test_relative_luminance_of_black_and_white() {
+        assert!((Color::Rgb { r: 0, g: 0, b: 0 }.relative_luminance() - 0.0).abs() < 1e-6);
+        assert!((Color::Rgb { r: 255, g: 255, b: 255 }.relative_luminance() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_contrast_ratio_of_black_on_white_is_maximal() {
+        let black = Color::Rgb { r: 0, g: 0, b: 0 };
+        let white = Color::Rgb { r: 255, g: 255, b: 255 };
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-3);
+        // Symmetric regardless of which color is the receiver.
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_contrast_ratio_of_a_color_against_itself_is_one() {
+        let color = Color::Rgb { r: 120, g: 80, b: 200 };
+        assert!((color.contrast_ratio(&color) - 1.0).abs() < 1e-6);
+    }
 }
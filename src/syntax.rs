@@ -0,0 +1,190 @@
+//! Syntax highlighting for code blocks.
+//!
+//! Wraps [`crate::highlighter::SyntaxHighlighter`] (itself backed by
+//! `syntect`'s bundled syntax/theme sets) behind a small [`Theme`] enum so
+//! callers can pick a built-in theme by name, or load their own color
+//! scheme from a TextMate/Sublime `.tmTheme` file via
+//! [`Theme::from_tmtheme_file`]/[`Theme::from_tmtheme_str`] instead of
+//! being limited to the built-in set.
+
+use crate::console::RenderContext;
+use crate::highlighter::{Highlighter, SyntaxHighlighter};
+use crate::renderable::{Renderable, Segment};
+use crate::text::Text;
+use std::fmt;
+use std::path::Path;
+
+/// A syntax highlighting color scheme.
+#[derive(Clone)]
+pub enum Theme {
+    /// The Monokai color scheme.
+    Monokai,
+    /// The `base16-ocean.dark` color scheme.
+    Base16OceanDark,
+    /// The Solarized Dark color scheme.
+    SolarizedDark,
+    /// A theme parsed from TextMate/Sublime `.tmTheme` plist content, e.g.
+    /// via [`Theme::from_tmtheme_file`] or [`Theme::from_tmtheme_str`].
+    Custom(Box<syntect::highlighting::Theme>),
+}
+
+impl fmt::Debug for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Theme::Monokai => write!(f, "Monokai"),
+            Theme::Base16OceanDark => write!(f, "Base16OceanDark"),
+            Theme::SolarizedDark => write!(f, "SolarizedDark"),
+            Theme::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl Theme {
+    /// The bundled `syntect` theme-set key this built-in theme maps to.
+    /// `None` for [`Theme::Custom`], which carries its own parsed theme
+    /// instead of looking one up in the bundled set.
+    fn bundled_key(&self) -> Option<&'static str> {
+        match self {
+            Theme::Monokai => Some("Monokai Extended"),
+            Theme::Base16OceanDark => Some("base16-ocean.dark"),
+            Theme::SolarizedDark => Some("Solarized (dark)"),
+            Theme::Custom(_) => None,
+        }
+    }
+
+    /// Load a theme from a `.tmTheme` file on disk.
+    pub fn from_tmtheme_file<P: AsRef<Path>>(path: P) -> Result<Self, SyntaxError> {
+        let theme = syntect::highlighting::ThemeSet::get_theme(path).map_err(SyntaxError)?;
+        Ok(Theme::Custom(Box::new(theme)))
+    }
+
+    /// Parse a theme from `.tmTheme` XML/plist content already in memory.
+    pub fn from_tmtheme_str(xml: &str) -> Result<Self, SyntaxError> {
+        let mut reader = xml.as_bytes();
+        let theme = syntect::highlighting::ThemeSet::load_from_reader(&mut reader).map_err(SyntaxError)?;
+        Ok(Theme::Custom(Box::new(theme)))
+    }
+}
+
+/// Error loading or parsing a `.tmTheme` color scheme.
+#[derive(Debug)]
+pub struct SyntaxError(syntect::LoadingError);
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to load .tmTheme color scheme: {}", self.0)
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+/// A syntax-highlighted block of source code.
+#[derive(Clone)]
+pub struct Syntax {
+    code: String,
+    language: String,
+    theme: Theme,
+}
+
+impl Syntax {
+    /// Create a syntax-highlighted block of `code` for `language` (matched
+    /// by syntax token name or file extension, e.g. `"rust"`, `"rs"`,
+    /// `"json"`), using the default [`Theme::Base16OceanDark`].
+    pub fn new(code: impl Into<String>, language: impl Into<String>) -> Self {
+        Syntax {
+            code: code.into(),
+            language: language.into(),
+            theme: Theme::Base16OceanDark,
+        }
+    }
+
+    /// Use a specific color theme, built-in or loaded from a `.tmTheme`
+    /// file via [`Theme::from_tmtheme_file`]/[`Theme::from_tmtheme_str`].
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn highlighter(&self) -> SyntaxHighlighter {
+        match &self.theme {
+            Theme::Custom(theme) => SyntaxHighlighter::with_custom_theme(&self.language, (**theme).clone()),
+            built_in => {
+                let highlighter = SyntaxHighlighter::for_language(&self.language);
+                match built_in.bundled_key() {
+                    Some(key) => highlighter.with_theme(key),
+                    None => highlighter,
+                }
+            }
+        }
+    }
+}
+
+impl Renderable for Syntax {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        let spans = self.highlighter().highlight(&self.code);
+        Text::from_spans(spans).render(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_debug_names_built_in_variants() {
+        assert_eq!(format!("{:?}", Theme::Base16OceanDark), "Base16OceanDark");
+        assert_eq!(format!("{:?}", Theme::Monokai), "Monokai");
+        assert_eq!(format!("{:?}", Theme::SolarizedDark), "SolarizedDark");
+    }
+
+    #[test]
+    fn test_syntax_renders_non_empty_for_plain_text() {
+        let context = RenderContext {
+            width: 80,
+            height: None,
+            direction: Default::default(),
+        };
+        let syntax = Syntax::new("let x = 1;", "rust");
+        let segments = syntax.render(&context);
+        assert!(!segments.is_empty());
+    }
+
+    #[test]
+    fn test_from_tmtheme_str_parses_minimal_theme() {
+        let tm_theme = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>name</key>
+	<string>Test Theme</string>
+	<key>settings</key>
+	<array>
+		<dict>
+			<key>settings</key>
+			<dict>
+				<key>background</key>
+				<string>#272822</string>
+				<key>foreground</key>
+				<string>#F8F8F2</string>
+			</dict>
+		</dict>
+		<dict>
+			<key>name</key>
+			<string>Comment</string>
+			<key>scope</key>
+			<string>comment</string>
+			<key>settings</key>
+			<dict>
+				<key>foreground</key>
+				<string>#75715E</string>
+			</dict>
+		</dict>
+	</array>
+</dict>
+</plist>
+"#;
+        let theme = Theme::from_tmtheme_str(tm_theme);
+        assert!(theme.is_ok());
+        assert!(matches!(theme.unwrap(), Theme::Custom(_)));
+    }
+}
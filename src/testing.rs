@@ -0,0 +1,191 @@
+//! Golden-file snapshot testing for [`Renderable`] implementations.
+//!
+//! Gated behind the `testing` feature. Captures a renderable's ANSI output
+//! via [`Console::capture`] and compares it against an on-disk golden file,
+//! printing a colorized, control-code-visible diff on mismatch. Set the
+//! `UPDATE_SNAPSHOTS` environment variable to rewrite the golden file in
+//! place instead of asserting, the same workflow as `expect_test`'s
+//! `UPDATE_EXPECT`.
+//!
+//! This supersedes hand-rolled `rust_<name>.txt` / `python_<name>.txt`
+//! comparison harnesses: downstream crates get a supported way to
+//! regression-test their own custom `Renderable`s without copying one.
+
+use crate::console::Console;
+use crate::renderable::Renderable;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Captures and compares a [`Renderable`]'s ANSI output against an on-disk
+/// golden file.
+pub struct RenderSnapshot;
+
+impl RenderSnapshot {
+    /// Render `renderable` at `width` through [`Console::capture`] with
+    /// color forced on, and return the raw ANSI output.
+    pub fn capture(renderable: &dyn Renderable, width: usize) -> String {
+        let console = Console::capture().width(width).force_color(true);
+        console.print_renderable(renderable);
+        console.get_captured_output()
+    }
+
+    /// Render `renderable` at `width` and assert it matches the golden file
+    /// at `path`.
+    ///
+    /// If `UPDATE_SNAPSHOTS` is set in the environment, the golden file is
+    /// (re)written with the freshly rendered output instead, and the
+    /// assertion is skipped -- the usual way to accept an intentional
+    /// rendering change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the golden file doesn't exist (and `UPDATE_SNAPSHOTS` isn't
+    /// set), or if the rendered output doesn't match it.
+    pub fn assert_matches(renderable: &dyn Renderable, width: usize, path: impl AsRef<Path>) {
+        let actual = Self::capture(renderable, width);
+        let path = path.as_ref();
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            Self::write(path, &actual);
+            return;
+        }
+
+        let expected = fs::read_to_string(path).unwrap_or_else(|e| {
+            panic!(
+                "render snapshot {} not found ({e}); run with UPDATE_SNAPSHOTS=1 to create it",
+                path.display()
+            )
+        });
+
+        if actual != expected {
+            panic!(
+                "render snapshot mismatch for {}\n\n{}\n\nrun with UPDATE_SNAPSHOTS=1 to accept the new output",
+                path.display(),
+                render_diff(&expected, &actual)
+            );
+        }
+    }
+
+    fn write(path: &Path, actual: &str) {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("failed to write render snapshot {}: {e}", path.display()));
+    }
+}
+
+/// Render a single non-printable byte as a visible escape marker, e.g. ESC
+/// (`0x1b`) becomes `␛[1b]`.
+fn visible_byte(byte: u8) -> String {
+    if byte == b'\n' {
+        "\u{21b5}".to_string()
+    } else if byte.is_ascii_graphic() || byte == b' ' {
+        (byte as char).to_string()
+    } else {
+        format!("\u{241b}[{byte:02x}]")
+    }
+}
+
+fn visible(bytes: &[u8]) -> String {
+    bytes.iter().copied().map(visible_byte).collect()
+}
+
+/// Build a human-readable diff: the byte offset of the first mismatch, plus
+/// a window of surrounding text from each side with control codes rendered
+/// visibly, colorized red (expected/golden) and green (actual).
+fn render_diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 24;
+
+    let exp = expected.as_bytes();
+    let act = actual.as_bytes();
+    let first_diff = exp
+        .iter()
+        .zip(act.iter())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| exp.len().min(act.len()));
+
+    let start = first_diff.saturating_sub(CONTEXT);
+    let exp_end = (first_diff + CONTEXT).min(exp.len());
+    let act_end = (first_diff + CONTEXT).min(act.len());
+
+    format!(
+        "first differing byte at offset {first_diff} (expected {} bytes, got {} bytes)\n\
+         \x1b[31m- golden: ...{}...\x1b[0m\n\
+         \x1b[32m+ actual: ...{}...\x1b[0m",
+        exp.len(),
+        act.len(),
+        visible(&exp[start..exp_end]),
+        visible(&act[start..act_end]),
+    )
+}
+
+/// Resolve a golden file path under `tests/snapshots/` relative to the
+/// crate invoking [`assert_render_snapshot!`].
+#[doc(hidden)]
+pub fn snapshot_path(name: &str) -> PathBuf {
+    Path::new("tests/snapshots").join(name)
+}
+
+/// Assert that rendering `$renderable` at `$width` matches the golden file
+/// at `$path`, or rewrite that file when `UPDATE_SNAPSHOTS` is set.
+///
+/// ```no_run
+/// use fast_rich::assert_render_snapshot;
+/// use fast_rich::prelude::*;
+///
+/// let text = Text::plain("Hello, World!");
+/// assert_render_snapshot!(&text, 60, "tests/snapshots/hello.ansi");
+/// ```
+#[macro_export]
+macro_rules! assert_render_snapshot {
+    ($renderable:expr, $width:expr, $path:expr) => {
+        $crate::testing::RenderSnapshot::assert_matches($renderable, $width, $path)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, Style};
+    use crate::text::Text;
+
+    #[test]
+    fn test_capture_renders_plain_text() {
+        let text = Text::plain("Hello").style(Style::new().foreground(Color::Red));
+        let output = RenderSnapshot::capture(&text, 20);
+        assert!(output.contains("Hello"));
+    }
+
+    #[test]
+    fn test_assert_matches_passes_on_identical_output() {
+        let dir = std::env::temp_dir().join(format!(
+            "fast_rich_snapshot_test_{}",
+            std::process::id()
+        ));
+        let path = dir.join("match.ansi");
+        let text = Text::plain("Snapshot me");
+        let actual = RenderSnapshot::capture(&text, 40);
+        RenderSnapshot::write(&path, &actual);
+
+        RenderSnapshot::assert_matches(&text, 40, &path);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    #[should_panic(expected = "render snapshot mismatch")]
+    fn test_assert_matches_panics_on_mismatch() {
+        let dir = std::env::temp_dir().join(format!(
+            "fast_rich_snapshot_test_mismatch_{}",
+            std::process::id()
+        ));
+        let path = dir.join("mismatch.ansi");
+        RenderSnapshot::write(&path, "stale golden output");
+
+        let text = Text::plain("Fresh output");
+        RenderSnapshot::assert_matches(&text, 40, &path);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! Themes provide predefined color palettes and style configurations.
 
+use crate::console::Console;
+use crate::log::ConsoleLog;
 use crate::style::{Color, Style};
 use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
 
 /// A theme defines colors and styles for different semantic elements.
 #[derive(Debug, Clone)]
@@ -24,6 +29,148 @@ pub struct Theme {
     pub muted: Color,
     /// Custom named colors
     pub custom: HashMap<String, Color>,
+    /// The theme's declared `name`, set when loaded from a TOML file via
+    /// [`Theme::from_toml_str`]/[`Theme::load_from_dir`]. `None` for
+    /// built-in or programmatically constructed themes.
+    pub name: Option<String>,
+}
+
+/// Errors from parsing or loading a [`Theme`] from TOML.
+#[derive(Debug)]
+pub enum ThemeError {
+    /// A field or `[custom]` entry's value isn't a color [`Color::parse`]
+    /// understands.
+    InvalidColor {
+        /// The field or `custom.<name>` key whose value failed to parse.
+        field: String,
+        /// The offending value.
+        value: String,
+    },
+    /// `extends` named a base theme that is neither a built-in nor (for
+    /// [`Theme::load_from_dir`]) another theme file in the same directory.
+    UnknownBase(String),
+    /// `extends` formed a cycle between theme files in the same directory.
+    Cycle(String),
+    /// Failed to read a theme file or directory.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::InvalidColor { field, value } => {
+                write!(f, "theme field \"{}\" has an invalid color: \"{}\"", field, value)
+            }
+            ThemeError::UnknownBase(base) => write!(f, "unknown base theme \"{}\"", base),
+            ThemeError::Cycle(stem) => write!(f, "theme \"{}\" extends itself via a cycle", stem),
+            ThemeError::Io(e) => write!(f, "theme IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(e: std::io::Error) -> Self {
+        ThemeError::Io(e)
+    }
+}
+
+/// The unresolved contents of a theme TOML file: its own fields, plus an
+/// optional `extends` base still to be resolved.
+struct RawTheme {
+    name: Option<String>,
+    extends: Option<String>,
+    fields: HashMap<String, String>,
+    custom: HashMap<String, String>,
+}
+
+/// Parse the small subset of TOML theme files use: flat `key = "value"`
+/// pairs at the top level (plus the reserved `name`/`extends` keys) and a
+/// `[custom]` table of further `key = "value"` color entries.
+fn parse_raw_toml(input: &str) -> RawTheme {
+    let mut raw = RawTheme {
+        name: None,
+        extends: None,
+        fields: HashMap::new(),
+        custom: HashMap::new(),
+    };
+    let mut section = String::new();
+
+    for line in input.lines() {
+        let mut in_quotes = false;
+        let comment_at = line
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    in_quotes = !in_quotes;
+                }
+                c == '#' && !in_quotes
+            })
+            .map_or(line.len(), |(at, _)| at);
+        let line = line[..comment_at].trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(inner) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section = inner.trim().to_string();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_string();
+        let value = value.trim().trim_matches('"').to_string();
+
+        match section.as_str() {
+            "" if key == "name" => raw.name = Some(value),
+            "" if key == "extends" => raw.extends = Some(value),
+            "" => {
+                raw.fields.insert(key, value);
+            }
+            "custom" => {
+                raw.custom.insert(key, value);
+            }
+            _ => {}
+        }
+    }
+
+    raw
+}
+
+fn apply_fields(theme: &mut Theme, fields: &HashMap<String, String>) -> Result<(), ThemeError> {
+    for (key, value) in fields {
+        let color = Color::parse(value).ok_or_else(|| ThemeError::InvalidColor {
+            field: key.clone(),
+            value: value.clone(),
+        })?;
+        match key.as_str() {
+            "primary" => theme.primary = color,
+            "secondary" => theme.secondary = color,
+            "success" => theme.success = color,
+            "warning" => theme.warning = color,
+            "error" => theme.error = color,
+            "info" => theme.info = color,
+            "muted" => theme.muted = color,
+            other => {
+                theme.custom.insert(other.to_string(), color);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_custom(theme: &mut Theme, custom: &HashMap<String, String>) -> Result<(), ThemeError> {
+    for (key, value) in custom {
+        let color = Color::parse(value).ok_or_else(|| ThemeError::InvalidColor {
+            field: format!("custom.{key}"),
+            value: value.clone(),
+        })?;
+        theme.custom.insert(key.clone(), color);
+    }
+    Ok(())
 }
 
 impl Theme {
@@ -37,10 +184,28 @@ impl Theme {
             error: Color::Red,
             info: Color::Cyan,
             muted: Color::BrightBlack,
-            custom: HashMap::new(),
+            custom: Theme::default_repr_colors(),
+            name: None,
         }
     }
 
+    /// Default colors for the `repr.*` style names [`crate::highlighter::ReprHighlighter`]
+    /// resolves against, matching Rich's own `repr.*` defaults closely enough
+    /// to be recognizable.
+    fn default_repr_colors() -> HashMap<String, Color> {
+        let mut colors = HashMap::new();
+        colors.insert("repr.number".to_string(), Color::Cyan);
+        colors.insert("repr.str".to_string(), Color::Green);
+        colors.insert("repr.bool_true".to_string(), Color::BrightGreen);
+        colors.insert("repr.bool_false".to_string(), Color::BrightRed);
+        colors.insert("repr.none".to_string(), Color::Magenta);
+        colors.insert("repr.url".to_string(), Color::BrightBlue);
+        colors.insert("repr.path".to_string(), Color::Magenta);
+        colors.insert("repr.uuid".to_string(), Color::BrightYellow);
+        colors.insert("repr.ipv4".to_string(), Color::BrightGreen);
+        colors
+    }
+
     /// Get the default theme (similar to Rich's default).
     pub fn default_theme() -> Self {
         let mut theme = Theme::new();
@@ -65,6 +230,7 @@ impl Theme {
             info: Color::rgb(174, 129, 255),     // Purple
             muted: Color::rgb(117, 113, 94),     // Gray
             custom: HashMap::new(),
+            name: None,
         }
     }
 
@@ -79,12 +245,142 @@ impl Theme {
             info: Color::rgb(128, 203, 196),      // Teal
             muted: Color::rgb(99, 119, 119),      // Gray
             custom: HashMap::new(),
+            name: None,
+        }
+    }
+
+    /// Look up a built-in theme by name, for `extends` resolution.
+    fn named_builtin(name: &str) -> Option<Theme> {
+        match name {
+            "monokai" => Some(Theme::monokai()),
+            "night_owl" => Some(Theme::night_owl()),
+            "default" => Some(Theme::default_theme()),
+            _ => None,
+        }
+    }
+
+    /// Parse a theme from a TOML string. Recognizes a top-level `name`,
+    /// an `extends = "<base>"` key naming a built-in theme to inherit
+    /// from (its fields are applied first, then overridden by this file's
+    /// own), flat `key = "value"` color fields (`primary`, `secondary`,
+    /// ...), and a `[custom]` table of further named colors. Each value
+    /// may be a named color or a `#rrggbb`/`#rgb` hex string, anything
+    /// [`Color::parse`] accepts.
+    ///
+    /// To inherit from another theme file rather than a built-in, use
+    /// [`Theme::load_from_dir`], which resolves `extends` across an
+    /// entire directory of theme files.
+    pub fn from_toml_str(input: &str) -> Result<Self, ThemeError> {
+        let raw = parse_raw_toml(input);
+
+        let mut theme = match &raw.extends {
+            Some(base) => {
+                Theme::named_builtin(base).ok_or_else(|| ThemeError::UnknownBase(base.clone()))?
+            }
+            None => Theme::new(),
+        };
+
+        apply_fields(&mut theme, &raw.fields)?;
+        apply_custom(&mut theme, &raw.custom)?;
+        theme.name = raw.name;
+
+        Ok(theme)
+    }
+
+    /// Load every `.toml` file in `dir` as a theme, keyed by filename
+    /// stem. `extends` may name a built-in theme or another file in the
+    /// same directory (resolved recursively); a theme whose declared
+    /// `name` doesn't match its filename logs a warning, matching how
+    /// Atuin handles user theme directories.
+    pub fn load_from_dir(dir: impl AsRef<Path>) -> Result<HashMap<String, Theme>, ThemeError> {
+        let dir = dir.as_ref();
+        let mut raw_by_stem: HashMap<String, RawTheme> = HashMap::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+                continue;
+            }
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let contents = fs::read_to_string(&path)?;
+            let raw = parse_raw_toml(&contents);
+
+            if let Some(declared) = &raw.name {
+                if declared != &stem {
+                    Console::new().warn(&format!(
+                        "theme file \"{}\" declares name \"{}\", which doesn't match its filename",
+                        stem, declared
+                    ));
+                }
+            }
+
+            raw_by_stem.insert(stem, raw);
         }
+
+        let mut resolved = HashMap::new();
+        for stem in raw_by_stem.keys().cloned().collect::<Vec<_>>() {
+            let theme = Theme::resolve_raw(&stem, &raw_by_stem, &mut Vec::new())?;
+            resolved.insert(stem, theme);
+        }
+        Ok(resolved)
+    }
+
+    /// Resolve one entry of a directory load, recursing through
+    /// `extends` chains that point at sibling files and detecting
+    /// cycles via `visiting`.
+    fn resolve_raw(
+        stem: &str,
+        raw_by_stem: &HashMap<String, RawTheme>,
+        visiting: &mut Vec<String>,
+    ) -> Result<Theme, ThemeError> {
+        if visiting.iter().any(|v| v == stem) {
+            return Err(ThemeError::Cycle(stem.to_string()));
+        }
+        let raw = raw_by_stem
+            .get(stem)
+            .ok_or_else(|| ThemeError::UnknownBase(stem.to_string()))?;
+
+        let mut theme = match &raw.extends {
+            Some(base) => {
+                if let Some(builtin) = Theme::named_builtin(base) {
+                    builtin
+                } else if raw_by_stem.contains_key(base) {
+                    visiting.push(stem.to_string());
+                    let base_theme = Theme::resolve_raw(base, raw_by_stem, visiting)?;
+                    visiting.pop();
+                    base_theme
+                } else {
+                    return Err(ThemeError::UnknownBase(base.clone()));
+                }
+            }
+            None => Theme::new(),
+        };
+
+        apply_fields(&mut theme, &raw.fields)?;
+        apply_custom(&mut theme, &raw.custom)?;
+        theme.name = raw.name.clone();
+
+        Ok(theme)
     }
 
     /// Get a style for a semantic element.
     pub fn get_style(&self, name: &str) -> Style {
-        let color = match name {
+        Style::new().foreground(self.resolve_color(name))
+    }
+
+    /// Add a custom color to the theme.
+    pub fn add_color(&mut self, name: impl Into<String>, color: Color) {
+        self.custom.insert(name.into(), color);
+    }
+
+    /// Resolve a semantic element's color, falling back to
+    /// [`Color::Default`] for an unknown custom name.
+    fn resolve_color(&self, name: &str) -> Color {
+        match name {
             "primary" => self.primary,
             "secondary" => self.secondary,
             "success" => self.success,
@@ -93,13 +389,60 @@ impl Theme {
             "info" => self.info,
             "muted" => self.muted,
             _ => self.custom.get(name).copied().unwrap_or(Color::Default),
-        };
-        Style::new().foreground(color)
+        }
     }
 
-    /// Add a custom color to the theme.
-    pub fn add_color(&mut self, name: impl Into<String>, color: Color) {
-        self.custom.insert(name.into(), color);
+    /// Get a style for `name` with its foreground adjusted to stay legible
+    /// against `background`, using the default WCAG contrast threshold of
+    /// `4.5` (see [`Theme::readable_style_with_threshold`]).
+    pub fn readable_style(&self, name: &str, background: Color) -> Style {
+        self.readable_style_with_threshold(name, background, 4.5)
+    }
+
+    /// Get a style for `name` with its foreground iteratively stepped in
+    /// HSL lightness -- toward white or black, whichever direction
+    /// increases contrast -- until its WCAG contrast ratio against
+    /// `background` reaches `min_contrast`, or lightness saturates.
+    pub fn readable_style_with_threshold(
+        &self,
+        name: &str,
+        background: Color,
+        min_contrast: f32,
+    ) -> Style {
+        let color = self.resolve_color(name);
+        Style::new().foreground(Self::adjust_for_contrast(color, background, min_contrast))
+    }
+
+    /// Step `color`'s HSL lightness toward white or black -- whichever
+    /// single step increases its contrast ratio against `background` more
+    /// -- until `min_contrast` is met or lightness saturates at `0.0`/`1.0`.
+    fn adjust_for_contrast(color: Color, background: Color, min_contrast: f32) -> Color {
+        const STEP: f32 = 0.05;
+        const MAX_STEPS: usize = 21;
+
+        if color.contrast_ratio(&background) >= min_contrast {
+            return color;
+        }
+
+        let (h, s, l) = color.to_hsl();
+        let lighter_contrast = Color::from_hsl(h, s, (l + STEP).min(1.0)).contrast_ratio(&background);
+        let darker_contrast = Color::from_hsl(h, s, (l - STEP).max(0.0)).contrast_ratio(&background);
+        let toward_white = lighter_contrast >= darker_contrast;
+
+        let mut lightness = l;
+        let mut best = color;
+        for _ in 0..MAX_STEPS {
+            lightness = if toward_white {
+                (lightness + STEP).min(1.0)
+            } else {
+                (lightness - STEP).max(0.0)
+            };
+            best = Color::from_hsl(h, s, lightness);
+            if best.contrast_ratio(&background) >= min_contrast || lightness <= 0.0 || lightness >= 1.0 {
+                break;
+            }
+        }
+        best
     }
 }
 
@@ -108,3 +451,148 @@ impl Default for Theme {
         Theme::default_theme()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml_str_parses_flat_fields_and_custom_table() {
+        let toml = r#"
+            name = "sunset"
+            primary = "#ff8800"
+            secondary = "cyan"
+
+            [custom]
+            accent = "#00ff00"
+        "#;
+        let theme = Theme::from_toml_str(toml).unwrap();
+
+        assert_eq!(theme.name.as_deref(), Some("sunset"));
+        assert_eq!(theme.primary, Color::rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.secondary, Color::Cyan);
+        assert_eq!(theme.custom.get("accent"), Some(&Color::rgb(0, 0xff, 0)));
+    }
+
+    #[test]
+    fn test_from_toml_str_extends_builtin_and_overrides_one_field() {
+        let toml = r#"
+            extends = "monokai"
+            warning = "#ffffff"
+        "#;
+        let theme = Theme::from_toml_str(toml).unwrap();
+        let monokai = Theme::monokai();
+
+        assert_eq!(theme.primary, monokai.primary);
+        assert_eq!(theme.warning, Color::rgb(0xff, 0xff, 0xff));
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_invalid_color() {
+        let err = Theme::from_toml_str(r#"primary = "not-a-color""#).unwrap_err();
+        assert!(matches!(err, ThemeError::InvalidColor { .. }));
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_unknown_extends_base() {
+        let err = Theme::from_toml_str(r#"extends = "no-such-theme""#).unwrap_err();
+        assert!(matches!(err, ThemeError::UnknownBase(base) if base == "no-such-theme"));
+    }
+
+    #[test]
+    fn test_load_from_dir_resolves_extends_across_sibling_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "fast_rich_theme_test_{}_{}",
+            std::process::id(),
+            "sibling"
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("base.toml"), "name = \"base\"\nprimary = \"red\"\n").unwrap();
+        fs::write(
+            dir.join("child.toml"),
+            "name = \"child\"\nextends = \"base\"\nsecondary = \"green\"\n",
+        )
+        .unwrap();
+
+        let themes = Theme::load_from_dir(&dir).unwrap();
+
+        assert_eq!(themes["child"].primary, Color::Red);
+        assert_eq!(themes["child"].secondary, Color::Green);
+        assert_eq!(themes["base"].primary, Color::Red);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_from_dir_detects_extends_cycle() {
+        let dir = std::env::temp_dir().join(format!(
+            "fast_rich_theme_test_{}_{}",
+            std::process::id(),
+            "cycle"
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.toml"), "extends = \"b\"\n").unwrap();
+        fs::write(dir.join("b.toml"), "extends = \"a\"\n").unwrap();
+
+        let err = Theme::load_from_dir(&dir).unwrap_err();
+        assert!(matches!(err, ThemeError::Cycle(_)));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_readable_style_leaves_already_readable_colors_alone() {
+        let mut theme = Theme::new();
+        theme.primary = Color::Rgb { r: 0, g: 0, b: 0 };
+        let style = theme.readable_style("primary", Color::Rgb { r: 255, g: 255, b: 255 });
+        assert_eq!(style.foreground, Some(Color::Rgb { r: 0, g: 0, b: 0 }));
+    }
+
+    #[test]
+    fn test_readable_style_lightens_a_low_contrast_color_on_a_dark_background() {
+        let mut theme = Theme::new();
+        theme.primary = Color::Rgb { r: 40, g: 40, b: 45 };
+        let background = Color::Rgb { r: 20, g: 20, b: 25 };
+
+        let adjusted = theme
+            .readable_style("primary", background)
+            .foreground
+            .unwrap();
+
+        assert!(adjusted.contrast_ratio(&background) >= 4.5);
+        // Should have moved toward white, not black, on a dark background.
+        assert!(adjusted.relative_luminance() > Color::Rgb { r: 40, g: 40, b: 45 }.relative_luminance());
+    }
+
+    #[test]
+    fn test_readable_style_darkens_a_low_contrast_color_on_a_light_background() {
+        let mut theme = Theme::new();
+        theme.primary = Color::Rgb { r: 220, g: 220, b: 215 };
+        let background = Color::Rgb { r: 240, g: 240, b: 235 };
+
+        let adjusted = theme
+            .readable_style("primary", background)
+            .foreground
+            .unwrap();
+
+        assert!(adjusted.contrast_ratio(&background) >= 4.5);
+        assert!(
+            adjusted.relative_luminance()
+                < Color::Rgb { r: 220, g: 220, b: 215 }.relative_luminance()
+        );
+    }
+
+    #[test]
+    fn test_readable_style_with_threshold_uses_the_given_minimum() {
+        let mut theme = Theme::new();
+        theme.primary = Color::Rgb { r: 128, g: 128, b: 128 };
+        let background = Color::Rgb { r: 100, g: 100, b: 100 };
+
+        let adjusted = theme
+            .readable_style_with_threshold("primary", background, 2.0)
+            .foreground
+            .unwrap();
+
+        assert!(adjusted.contrast_ratio(&background) >= 2.0);
+    }
+}
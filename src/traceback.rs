@@ -0,0 +1,364 @@
+//! Rendering of Rust panics and chained errors as Python-style tracebacks.
+//!
+//! `Traceback` captures one or more related errors -- an error and the
+//! `source()` chain behind it, or a panic -- as a sequence of framed blocks,
+//! joined by the connector text Python uses for `__cause__`/`__context__`
+//! chains, so a handler's own exception doesn't hide the one that caused it.
+
+use crate::console::{Console, RenderContext};
+use crate::panel::{BorderStyle, Panel};
+use crate::renderable::{Renderable, Segment};
+use crate::style::{Color, Style};
+use crate::syntax::Syntax;
+use crate::text::Span;
+
+/// How one exception in a chain relates to the next (outer) one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainLink {
+    /// Raised explicitly from another error (Rust: reported via `source()`).
+    Cause,
+    /// Raised while handling another error, without an explicit cause.
+    Context,
+}
+
+impl ChainLink {
+    fn connector_text(self) -> &'static str {
+        match self {
+            ChainLink::Cause => {
+                "The above exception was the direct cause of the following exception:"
+            }
+            ChainLink::Context => {
+                "During handling of the above exception, another exception occurred:"
+            }
+        }
+    }
+}
+
+/// A single stack frame within a traceback.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub file: String,
+    pub line: usize,
+    pub function: String,
+    /// Full source text of `file`, if available, used to show context lines.
+    pub source: Option<String>,
+    pub locals: Vec<(String, String)>,
+}
+
+impl Frame {
+    /// Create a new frame at `file:line` inside `function`.
+    pub fn new(file: impl Into<String>, line: usize, function: impl Into<String>) -> Self {
+        Frame {
+            file: file.into(),
+            line,
+            function: function.into(),
+            source: None,
+            locals: Vec::new(),
+        }
+    }
+
+    /// Attach the source text of `file`, enabling source-context display.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Record a local variable's name and its debug representation.
+    pub fn with_local(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.locals.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// One exception/error in a chain: its message, frames, and the connector
+/// linking it to the next (outer) exception, if any.
+#[derive(Debug, Clone)]
+struct ExceptionBlock {
+    message: String,
+    frames: Vec<Frame>,
+    link_to_next: Option<ChainLink>,
+}
+
+/// Configuration for how a `Traceback` renders.
+#[derive(Debug, Clone)]
+pub struct TracebackConfig {
+    /// Whether to show source-context lines around each frame's error line.
+    pub show_source: bool,
+    /// Whether to show local variables captured on each frame.
+    pub show_locals: bool,
+    /// Number of context lines shown above/below the error line.
+    pub context_lines: usize,
+    /// Border style used for each chained block's panel.
+    pub border_style: BorderStyle,
+    /// Theme used when highlighting source context.
+    pub syntax_theme: crate::syntax::Theme,
+}
+
+impl Default for TracebackConfig {
+    fn default() -> Self {
+        TracebackConfig {
+            show_source: true,
+            show_locals: false,
+            context_lines: 3,
+            border_style: BorderStyle::Rounded,
+            syntax_theme: crate::syntax::Theme::Monokai,
+        }
+    }
+}
+
+/// A Python-style traceback of one or more chained exceptions.
+pub struct Traceback {
+    chain: Vec<ExceptionBlock>,
+    config: TracebackConfig,
+}
+
+impl Traceback {
+    /// Build a single-exception traceback from an error message, with no frames yet.
+    pub fn from_error(message: impl Into<String>) -> Self {
+        Traceback {
+            chain: vec![ExceptionBlock {
+                message: message.into(),
+                frames: Vec::new(),
+                link_to_next: None,
+            }],
+            config: TracebackConfig::default(),
+        }
+    }
+
+    /// Build a traceback from a `std::error::Error`, walking its `source()`
+    /// chain so the whole chain -- not just the innermost error -- is shown,
+    /// oldest cause first, each linked by [`ChainLink::Cause`].
+    pub fn from_std_error(error: &(dyn std::error::Error + 'static)) -> Self {
+        let mut chain = Vec::new();
+        let mut current: Option<&(dyn std::error::Error + 'static)> = Some(error);
+        while let Some(err) = current {
+            chain.push(ExceptionBlock {
+                message: err.to_string(),
+                frames: Vec::new(),
+                link_to_next: None,
+            });
+            current = err.source();
+        }
+        chain.reverse();
+        let last = chain.len().saturating_sub(1);
+        for link in chain.iter_mut().take(last) {
+            link.link_to_next = Some(ChainLink::Cause);
+        }
+        Traceback {
+            chain,
+            config: TracebackConfig::default(),
+        }
+    }
+
+    /// Add a stack frame to the most recently appended exception block.
+    pub fn add_frame(mut self, frame: Frame) -> Self {
+        if let Some(block) = self.chain.last_mut() {
+            block.frames.push(frame);
+        }
+        self
+    }
+
+    /// Append another exception to the chain, linked to the previous one.
+    pub fn chain(mut self, message: impl Into<String>, link: ChainLink) -> Self {
+        if let Some(last) = self.chain.last_mut() {
+            last.link_to_next = Some(link);
+        }
+        self.chain.push(ExceptionBlock {
+            message: message.into(),
+            frames: Vec::new(),
+            link_to_next: None,
+        });
+        self
+    }
+
+    /// Replace the rendering configuration.
+    pub fn with_config(mut self, config: TracebackConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The lines of `source` from `line - context_lines` to `line + context_lines`
+    /// (1-based, clamped to the file), or `None` if no source was attached.
+    fn source_context(&self, frame: &Frame) -> Option<String> {
+        let source = frame.source.as_ref()?;
+        let lines: Vec<&str> = source.split('\n').collect();
+        let lo = frame.line.saturating_sub(self.config.context_lines).max(1);
+        let hi = (frame.line + self.config.context_lines).min(lines.len());
+        if lo > hi {
+            return None;
+        }
+        Some(lines[lo - 1..hi].join("\n"))
+    }
+
+    fn frame_language(frame: &Frame) -> &'static str {
+        if frame.file.ends_with(".rs") {
+            "rust"
+        } else {
+            "text"
+        }
+    }
+
+    fn render_block(&self, block: &ExceptionBlock, context: &RenderContext) -> Vec<Segment> {
+        let mut body = Vec::new();
+        body.push(Segment::line(vec![Span::styled(
+            "Traceback (most recent call last):".to_string(),
+            Style::new().dim(),
+        )]));
+
+        for frame in &block.frames {
+            body.push(Segment::line(vec![Span::raw(format!(
+                "  File \"{}\", line {}, in {}",
+                frame.file, frame.line, frame.function
+            ))]));
+
+            if self.config.show_source {
+                if let Some(context_source) = self.source_context(frame) {
+                    let syntax = Syntax::new(&context_source, Self::frame_language(frame))
+                        .theme(self.config.syntax_theme.clone())
+                        .line_numbers(true);
+                    body.extend(syntax.render(context));
+                }
+            }
+
+            if self.config.show_locals {
+                for (name, value) in &frame.locals {
+                    body.push(Segment::line(vec![Span::styled(
+                        format!("      {} = {}", name, value),
+                        Style::new().dim(),
+                    )]));
+                }
+            }
+        }
+
+        body.push(Segment::line(vec![Span::styled(
+            block.message.clone(),
+            Style::new().foreground(Color::Red).bold(),
+        )]));
+
+        let text: String = body
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Panel::new(text)
+            .border_style(self.config.border_style)
+            .style(Style::new().foreground(Color::Red))
+            .render(context)
+    }
+}
+
+impl Renderable for Traceback {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        for (i, block) in self.chain.iter().enumerate() {
+            if i > 0 {
+                let connector = self.chain[i - 1]
+                    .link_to_next
+                    .unwrap_or(ChainLink::Context)
+                    .connector_text();
+                segments.push(Segment::line(vec![Span::raw(String::new())]));
+                segments.push(Segment::line(vec![Span::styled(
+                    connector.to_string(),
+                    Style::new().dim(),
+                )]));
+                segments.push(Segment::line(vec![Span::raw(String::new())]));
+            }
+            segments.extend(self.render_block(block, context));
+        }
+        segments
+    }
+
+    fn min_width(&self) -> usize {
+        self.chain
+            .iter()
+            .map(|block| block.message.len() + 4)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Install a panic hook that prints a [`Traceback`] (with panic message and
+/// location) to stderr, then chains to whatever hook was previously
+/// installed rather than replacing it outright (with Rust's default hook in
+/// that place, its standard panic message follows the traceback) -- the same
+/// composable pattern
+/// [`screen::install_panic_hook`](crate::screen::install_panic_hook) uses, so
+/// the two can be combined. Install [`screen::install_panic_hook`] *after*
+/// this one (so it ends up as the outermost hook) if a full-screen app wants
+/// both: that way the terminal is restored before the traceback prints,
+/// instead of the traceback printing into a still-raw-mode terminal.
+pub fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+
+        let mut traceback = Traceback::from_error(message);
+        if let Some(location) = info.location() {
+            traceback = traceback.add_frame(Frame::new(
+                location.file(),
+                location.line() as usize,
+                "<panic>",
+            ));
+        }
+
+        Console::stderr().print_renderable(&traceback);
+        previous_hook(info);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_panic_hook_does_not_panic() {
+        // Can't easily assert on terminal/stderr state in CI, but installing
+        // the hook (and chaining onto whatever was there before, the way
+        // screen::install_panic_hook does) should never itself panic.
+        super::install_panic_hook();
+    }
+
+    #[test]
+    fn test_from_error_renders_message() {
+        let tb = Traceback::from_error("boom");
+        let context = RenderContext {
+            width: 60,
+            height: None,
+            direction: Default::default(),
+        };
+        let output: String = tb
+            .render(&context)
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(output.contains("boom"));
+    }
+
+    #[test]
+    fn test_chain_inserts_connector_text() {
+        let tb = Traceback::from_error("inner failure")
+            .chain("outer failure", ChainLink::Cause);
+        let context = RenderContext {
+            width: 60,
+            height: None,
+            direction: Default::default(),
+        };
+        let output: String = tb
+            .render(&context)
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(output.contains("inner failure"));
+        assert!(output.contains("outer failure"));
+        assert!(output.contains("direct cause"));
+    }
+}
@@ -0,0 +1,1065 @@
+//! Tree rendering for hierarchical data.
+//!
+//! `Tree` renders a root label and nested `TreeNode` children connected by
+//! guide lines, similar to the output of the `tree`(1) command or an IDE's
+//! file explorer.
+
+use crate::console::RenderContext;
+use crate::renderable::{BoxedRenderable, Renderable, Segment};
+use crate::style::{Color, Style};
+use crate::text::{Span, Text};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Character set used to draw the connector lines between tree nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GuideStyle {
+    /// Light box-drawing guides (`│`, `├──`, `└──`) -- the default.
+    #[default]
+    Unicode,
+    /// Plain ASCII guides (`|`, `|--`, `` `-- ``).
+    Ascii,
+    /// Heavy box-drawing guides (`┃`, `┣━━`, `┗━━`).
+    Bold,
+    /// Double-line box-drawing guides (`║`, `╠══`, `╚══`).
+    Double,
+    /// Light box-drawing guides with a rounded final corner (`│`, `├──`, `╰──`).
+    Rounded,
+}
+
+impl GuideStyle {
+    /// The (vertical, branch, last, blank) glyphs for this style, each padded
+    /// to the same column width so guides at any depth line up.
+    fn glyphs(self) -> (&'static str, &'static str, &'static str, &'static str) {
+        match self {
+            GuideStyle::Unicode => ("│   ", "├── ", "└── ", "    "),
+            GuideStyle::Ascii => ("|   ", "|-- ", "`-- ", "    "),
+            GuideStyle::Bold => ("┃   ", "┣━━ ", "┗━━ ", "    "),
+            GuideStyle::Double => ("║   ", "╠══ ", "╚══ ", "    "),
+            GuideStyle::Rounded => ("│   ", "├── ", "╰── ", "    "),
+        }
+    }
+}
+
+/// A sensible default 7-color palette for [`Tree::rainbow_guides`], cycling
+/// red, orange, yellow, green, cyan, blue, magenta by nesting depth.
+pub fn default_rainbow_palette() -> Vec<Color> {
+    vec![
+        Color::Red,
+        Color::Rgb { r: 255, g: 165, b: 0 }, // orange
+        Color::Yellow,
+        Color::Green,
+        Color::Cyan,
+        Color::Blue,
+        Color::Magenta,
+    ]
+}
+
+/// Icon flavor used by [`Tree::auto_icons`] to pick a folder/file glyph for
+/// nodes that don't carry an explicit [`TreeNode::icon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconSet {
+    /// Plain emoji folder/file markers, readable in any terminal.
+    #[default]
+    Default,
+    /// Nerd Font icons mapping common file/folder types to glyphs; needs a
+    /// terminal font patched with Nerd Fonts to render correctly.
+    NerdFonts,
+}
+
+impl IconSet {
+    /// Pick a glyph for a node, given whether it has children (treated as
+    /// a directory) and its label's plain text (for file extensions).
+    fn icon_for(self, is_dir: bool, label_text: &str) -> &'static str {
+        if is_dir {
+            return match self {
+                IconSet::Default => "\u{1f4c1}",    // 📁
+                IconSet::NerdFonts => "\u{f07c}",   //  nf-fa-folder_open
+            };
+        }
+
+        let ext = Self::extension_of(label_text).to_ascii_lowercase();
+        match self {
+            IconSet::Default => match ext.as_str() {
+                "png" | "jpg" | "jpeg" | "gif" | "svg" => "\u{1f5bc}", // 🖼
+                "lock" => "\u{1f512}",                                 // 🔒
+                _ => "\u{1f4c4}",                                      // 📄
+            },
+            IconSet::NerdFonts => match ext.as_str() {
+                "rs" => "\u{e7a8}",                // nf-seti-rust
+                "toml" => "\u{e6b2}",               // nf-seti-config
+                "json" => "\u{e60b}",               // nf-seti-json
+                "md" => "\u{f48a}",                 // nf-oct-markdown
+                "png" | "jpg" | "jpeg" | "gif" | "svg" => "\u{f1c5}", // nf-fa-file_image_o
+                "lock" => "\u{f023}",               // nf-fa-lock
+                "git" | "gitignore" => "\u{f1d3}",  // nf-fa-git
+                _ => "\u{f15b}",                    // nf-fa-file
+            },
+        }
+    }
+
+    /// The extension of `label_text` as written (the part after the last
+    /// `.`, trailing `/` stripped first), or `""` if there isn't one.
+    fn extension_of(label_text: &str) -> &str {
+        let trimmed = label_text.trim_end_matches('/');
+        match trimmed.rsplit_once('.') {
+            Some((_, ext)) if !ext.is_empty() => ext,
+            _ => "",
+        }
+    }
+}
+
+/// A single node in a `Tree`, holding a label and its nested children.
+pub struct TreeNode {
+    label: BoxedRenderable,
+    children: Vec<TreeNode>,
+    expanded: bool,
+    guide_style: Option<Style>,
+    icon: Option<String>,
+    icon_style: Option<Style>,
+}
+
+impl TreeNode {
+    /// Create a leaf node from any label convertible to `Text`.
+    pub fn new(label: impl Into<Text>) -> Self {
+        TreeNode {
+            label: Box::new(label.into()),
+            children: Vec::new(),
+            expanded: true,
+            guide_style: None,
+            icon: None,
+            icon_style: None,
+        }
+    }
+
+    /// Create a node wrapping an arbitrary renderable label instead of plain text.
+    pub fn from_renderable(label: impl Renderable + Send + Sync + 'static) -> Self {
+        TreeNode {
+            label: Box::new(label),
+            children: Vec::new(),
+            expanded: true,
+            guide_style: None,
+            icon: None,
+            icon_style: None,
+        }
+    }
+
+    /// Override the style of this node's own branch connector (the `├──` or
+    /// `└──` that points at it), independent of the tree's depth-based
+    /// [`Tree::guide_styles`] palette. Ancestor guides above it are
+    /// unaffected.
+    pub fn guide_style(mut self, style: Style) -> Self {
+        self.guide_style = Some(style);
+        self
+    }
+
+    /// Set an explicit icon glyph, rendered between the guide and the
+    /// label. Takes precedence over [`Tree::auto_icons`], which only fills
+    /// in an icon for nodes that don't already have one set this way.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Style the icon independently of the label's own style.
+    pub fn icon_style(mut self, style: Style) -> Self {
+        self.icon_style = Some(style);
+        self
+    }
+
+    /// Add a child node, returning it so further children can be nested onto it.
+    pub fn add(&mut self, child: impl Into<TreeNode>) -> &mut TreeNode {
+        self.children.push(child.into());
+        self.children.last_mut().expect("just pushed")
+    }
+
+    /// Start this node collapsed (its children hidden until expanded).
+    pub fn collapsed(mut self) -> Self {
+        self.expanded = false;
+        self
+    }
+
+    /// Whether this node's children are currently shown.
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Toggle whether this node's children are shown.
+    pub fn toggle_expanded(&mut self) {
+        self.expanded = !self.expanded;
+    }
+
+    /// A single-line plain-text rendering of this node's label, used by
+    /// [`Tree::auto_icons`] to sniff a filename extension. Renders at a
+    /// generous width so realistic labels never wrap.
+    fn label_plain_text(&self) -> String {
+        let context = RenderContext {
+            width: 10_000,
+            height: None,
+            direction: Default::default(),
+        };
+        self.label
+            .render(&context)
+            .first()
+            .map(|segment| segment.plain_text())
+            .unwrap_or_default()
+    }
+}
+
+impl From<&str> for TreeNode {
+    fn from(label: &str) -> Self {
+        TreeNode::new(label)
+    }
+}
+
+impl From<String> for TreeNode {
+    fn from(label: String) -> Self {
+        TreeNode::new(label)
+    }
+}
+
+impl From<Text> for TreeNode {
+    fn from(label: Text) -> Self {
+        TreeNode::new(label)
+    }
+}
+
+/// A tree renderable: a root `TreeNode` connected to its descendants by guide lines.
+pub struct Tree {
+    root: TreeNode,
+    guide_style: GuideStyle,
+    guide_styles: Vec<Style>,
+    icon_set: IconSet,
+    auto_icons: bool,
+}
+
+/// Options controlling how [`Tree::from_path`] walks the filesystem.
+///
+/// Not `Clone`/`Debug` -- neither is derived; the optional `filter` closure
+/// doesn't implement `Debug`, and deriving around it would mean wrapping
+/// every other field for no benefit.
+pub struct FromPathOptions {
+    /// Stop descending once this many levels below the root have been
+    /// walked (default: `None`, no limit).
+    pub max_depth: Option<usize>,
+    /// Follow symlinks instead of listing them as leaves (default: `false`,
+    /// to avoid following a symlink cycle into an infinite walk).
+    pub follow_symlinks: bool,
+    /// Append each file's size to its label, formatted with
+    /// [`crate::filesize::format_bytes`] (default: `false`).
+    pub show_sizes: bool,
+    /// Skip an entry -- and, for a directory, its entire subtree -- when
+    /// this returns `false` (default: `None`, keep everything).
+    pub filter: Option<Arc<dyn Fn(&Path) -> bool + Send + Sync>>,
+}
+
+impl Default for FromPathOptions {
+    fn default() -> Self {
+        FromPathOptions {
+            max_depth: None,
+            follow_symlinks: false,
+            show_sizes: false,
+            filter: None,
+        }
+    }
+}
+
+impl Tree {
+    /// Create a new tree rooted at `label`.
+    pub fn new(label: impl Into<TreeNode>) -> Self {
+        Tree {
+            root: label.into(),
+            guide_style: GuideStyle::default(),
+            guide_styles: Vec::new(),
+            icon_set: IconSet::default(),
+            auto_icons: false,
+        }
+    }
+
+    /// Add a top-level child, returning it so further children can be nested onto it.
+    pub fn add(&mut self, child: impl Into<TreeNode>) -> &mut TreeNode {
+        self.root.add(child)
+    }
+
+    /// Choose the connector glyph set (Unicode, ASCII, Bold, Double, or Rounded).
+    pub fn guide_style(mut self, style: GuideStyle) -> Self {
+        self.guide_style = style;
+        self
+    }
+
+    /// Set a palette of styles applied to the guide glyphs, cycled by nesting
+    /// depth modulo the palette length.
+    ///
+    /// Only the connector glyphs are recolored this way -- each node's own
+    /// label keeps whatever style it already carries.
+    pub fn guide_styles(mut self, styles: Vec<Style>) -> Self {
+        self.guide_styles = styles;
+        self
+    }
+
+    /// Color guide connectors cyclically by nesting depth using `palette`
+    /// -- `palette[depth % palette.len()]` for each guide segment, leaving
+    /// node labels untouched. A thin convenience over [`Tree::guide_styles`]
+    /// for the common case of a plain color palette; pass
+    /// [`default_rainbow_palette`] for a sensible 7-color default.
+    pub fn rainbow_guides(self, palette: Vec<Color>) -> Self {
+        let styles = palette
+            .into_iter()
+            .map(|color| Style::new().foreground(color))
+            .collect();
+        self.guide_styles(styles)
+    }
+
+    /// Choose the icon flavor used by [`Tree::auto_icons`] (the default
+    /// flavor is readable in any terminal; [`IconSet::NerdFonts`] needs a
+    /// terminal font patched with Nerd Fonts).
+    pub fn icon_set(mut self, icon_set: IconSet) -> Self {
+        self.icon_set = icon_set;
+        self
+    }
+
+    /// Automatically assign a folder/file icon to every node that doesn't
+    /// already carry an explicit [`TreeNode::icon`], based on whether it
+    /// has children and, for files, its label's extension.
+    pub fn auto_icons(mut self, enabled: bool) -> Self {
+        self.auto_icons = enabled;
+        self
+    }
+
+    /// Build a tree by walking `path` on disk, using the default
+    /// [`FromPathOptions`]. See [`Tree::from_path_with_options`] for control
+    /// over depth, symlinks, filtering, and size annotations.
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Tree> {
+        Tree::from_path_with_options(path, &FromPathOptions::default())
+    }
+
+    /// Build a tree by recursively walking `path` on disk: directories
+    /// become parent nodes (listed before files, then alphabetically) and
+    /// files become leaves. An entry that can't be read -- permission
+    /// denied, a broken symlink -- becomes a styled placeholder leaf
+    /// instead of aborting the rest of the walk.
+    pub fn from_path_with_options(path: impl AsRef<Path>, options: &FromPathOptions) -> io::Result<Tree> {
+        let path = path.as_ref();
+        let name = Self::path_label(path);
+        Ok(Tree::new(Self::node_from_path(path, name, 0, options)))
+    }
+
+    fn path_label(path: &Path) -> String {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned())
+    }
+
+    fn node_from_path(path: &Path, name: String, depth: usize, options: &FromPathOptions) -> TreeNode {
+        let metadata = if options.follow_symlinks {
+            fs::metadata(path)
+        } else {
+            fs::symlink_metadata(path)
+        };
+        let metadata = match metadata {
+            Ok(metadata) => metadata,
+            Err(error) => return Self::error_node(&name, &error),
+        };
+
+        if !metadata.is_dir() {
+            let label = if options.show_sizes {
+                format!("{} ({})", name, crate::filesize::format_bytes(metadata.len()))
+            } else {
+                name
+            };
+            return TreeNode::new(label);
+        }
+
+        let mut node = TreeNode::new(name);
+        if options.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            return node;
+        }
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(error) => {
+                node.add(Self::error_node("<permission denied>", &error));
+                return node;
+            }
+        };
+
+        let mut entries: Vec<_> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                options
+                    .filter
+                    .as_ref()
+                    .map_or(true, |filter| filter(&entry.path()))
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            let a_is_dir = a.file_type().map_or(false, |file_type| file_type.is_dir());
+            let b_is_dir = b.file_type().map_or(false, |file_type| file_type.is_dir());
+            match (a_is_dir, b_is_dir) {
+                (true, false) => std::cmp::Ordering::Less,
+                (false, true) => std::cmp::Ordering::Greater,
+                _ => a.file_name().cmp(&b.file_name()),
+            }
+        });
+
+        for entry in entries {
+            let entry_name = entry.file_name().to_string_lossy().into_owned();
+            node.add(Self::node_from_path(&entry.path(), entry_name, depth + 1, options));
+        }
+        node
+    }
+
+    fn error_node(name: &str, error: &io::Error) -> TreeNode {
+        let style = Style::new().foreground(Color::Red).italic();
+        TreeNode::new(Text::from_spans(vec![Span::styled(
+            format!("{} ({})", name, error),
+            style,
+        )]))
+    }
+
+    fn style_for_depth(&self, depth: usize) -> Style {
+        if self.guide_styles.is_empty() {
+            Style::new()
+        } else {
+            self.guide_styles[depth % self.guide_styles.len()]
+        }
+    }
+
+    /// The icon span for `node` (its own explicit icon, or one chosen by
+    /// [`Tree::auto_icons`]), if either applies, followed by a single space
+    /// separating it from the label.
+    fn icon_span(&self, node: &TreeNode) -> Option<Span> {
+        let icon = match &node.icon {
+            Some(icon) => icon.clone(),
+            None if self.auto_icons => {
+                let is_dir = !node.children.is_empty();
+                let label_text = node.label_plain_text();
+                self.icon_set.icon_for(is_dir, &label_text).to_string()
+            }
+            None => return None,
+        };
+        let style = node.icon_style.unwrap_or_default();
+        Some(Span::styled(format!("{} ", icon), style))
+    }
+
+    fn render_node(
+        &self,
+        node: &TreeNode,
+        depth: usize,
+        ancestors_last: &[bool],
+        is_last: bool,
+        context: &RenderContext,
+        segments: &mut Vec<Segment>,
+    ) {
+        let (vertical, branch, last, blank) = self.guide_style.glyphs();
+
+        let mut prefix: Vec<Span> = Vec::new();
+        for (level, &ancestor_last) in ancestors_last.iter().enumerate() {
+            let glyph = if ancestor_last { blank } else { vertical };
+            prefix.push(Span::styled(glyph.to_string(), self.style_for_depth(level)));
+        }
+        if depth > 0 {
+            let connector = if is_last { last } else { branch };
+            let connector_style = node.guide_style.unwrap_or_else(|| self.style_for_depth(depth - 1));
+            prefix.push(Span::styled(connector.to_string(), connector_style));
+        }
+        let prefix_width: usize = prefix.iter().map(|span| span.width()).sum();
+
+        let icon_span = self.icon_span(node);
+        let icon_width = icon_span.as_ref().map(|span| span.width()).unwrap_or(0);
+
+        let label_context = RenderContext {
+            width: context.width.saturating_sub(prefix_width + icon_width),
+            height: context.height,
+            direction: context.direction,
+        };
+        let label_segments = node.label.render(&label_context);
+
+        if label_segments.is_empty() {
+            let mut spans = prefix.clone();
+            spans.extend(icon_span.clone());
+            segments.push(Segment::line(spans));
+        }
+        for (i, label_segment) in label_segments.into_iter().enumerate() {
+            let mut spans = if i == 0 {
+                let mut first = prefix.clone();
+                first.extend(icon_span.clone());
+                first
+            } else {
+                // Continuation lines of a wrapped/multi-line label align under
+                // the label column rather than repeating the branch connector.
+                let mut cont: Vec<Span> = ancestors_last
+                    .iter()
+                    .map(|&ancestor_last| {
+                        Span::raw(if ancestor_last { blank } else { vertical }.to_string())
+                    })
+                    .collect();
+                if depth > 0 {
+                    cont.push(Span::raw(
+                        if is_last { blank } else { vertical }.to_string(),
+                    ));
+                }
+                if icon_width > 0 {
+                    cont.push(Span::raw(" ".repeat(icon_width)));
+                }
+                cont
+            };
+            spans.extend(label_segment.spans);
+            segments.push(Segment::line(spans));
+        }
+
+        if !node.expanded {
+            return;
+        }
+
+        let mut child_ancestors = ancestors_last.to_vec();
+        if depth > 0 {
+            child_ancestors.push(is_last);
+        }
+        let child_count = node.children.len();
+        for (index, child) in node.children.iter().enumerate() {
+            self.render_node(
+                child,
+                depth + 1,
+                &child_ancestors,
+                index + 1 == child_count,
+                context,
+                segments,
+            );
+        }
+    }
+
+    fn min_width_of(&self, node: &TreeNode, depth: usize, guide_width: usize) -> usize {
+        let icon_width = self.icon_span(node).map(|span| span.width()).unwrap_or(0);
+        let own = node.label.min_width() + depth * guide_width + icon_width;
+        node.children
+            .iter()
+            .map(|child| self.min_width_of(child, depth + 1, guide_width))
+            .fold(own, usize::max)
+    }
+
+    /// Paths (child index chains from the root) of every currently visible
+    /// row, in display order, skipping the children of collapsed nodes.
+    fn visible_paths(&self) -> Vec<Vec<usize>> {
+        let mut paths = vec![Vec::new()];
+        Self::collect_visible(&self.root, Vec::new(), &mut paths);
+        paths
+    }
+
+    fn collect_visible(node: &TreeNode, path: Vec<usize>, paths: &mut Vec<Vec<usize>>) {
+        if !node.expanded {
+            return;
+        }
+        for (index, child) in node.children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(index);
+            paths.push(child_path.clone());
+            Self::collect_visible(child, child_path, paths);
+        }
+    }
+
+    fn node_at_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let mut node = &mut self.root;
+        for &index in path {
+            node = node.children.get_mut(index)?;
+        }
+        Some(node)
+    }
+
+    /// Render the tree with the row at `cursor`'s position highlighted, and
+    /// the visible window scrolled (to `context.height` rows, if set) so the
+    /// cursor stays on screen.
+    pub fn render_with_cursor(
+        &self,
+        context: &RenderContext,
+        cursor: &mut TreeCursor,
+    ) -> Vec<Segment> {
+        let mut rows = Vec::new();
+        self.render_node(&self.root, 0, &[], true, context, &mut rows);
+
+        if let Some(height) = context.height {
+            cursor.sync_scroll(height);
+        }
+
+        let highlight = Style::new().reverse();
+        if let Some(row) = rows.get_mut(cursor.position) {
+            let highlighted_spans = row
+                .spans
+                .iter()
+                .map(|span| Span::styled(span.text.clone(), span.style.combine(&highlight)))
+                .collect();
+            *row = Segment::line(highlighted_spans);
+        }
+
+        match context.height {
+            Some(height) if height < rows.len() => {
+                rows.into_iter().skip(cursor.scroll_offset).take(height).collect()
+            }
+            _ => rows,
+        }
+    }
+}
+
+/// Navigation state for an interactive `Tree`: which visible row is
+/// highlighted, and how far the visible window has scrolled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TreeCursor {
+    position: usize,
+    scroll_offset: usize,
+}
+
+impl TreeCursor {
+    /// A cursor starting at the root row.
+    pub fn new() -> Self {
+        TreeCursor::default()
+    }
+
+    /// Move the highlighted row down by one, clamped to the last visible row.
+    pub fn move_down(&mut self, tree: &Tree) {
+        let last = tree.visible_paths().len().saturating_sub(1);
+        self.position = (self.position + 1).min(last);
+    }
+
+    /// Move the highlighted row up by one, clamped to the root row.
+    pub fn move_up(&mut self) {
+        self.position = self.position.saturating_sub(1);
+    }
+
+    /// Toggle expand/collapse of the node currently under the cursor.
+    pub fn toggle(&mut self, tree: &mut Tree) {
+        let paths = tree.visible_paths();
+        if let Some(path) = paths.get(self.position).cloned() {
+            if let Some(node) = tree.node_at_mut(&path) {
+                node.toggle_expanded();
+            }
+        }
+    }
+
+    /// Scroll so the cursor row stays within the `height`-row visible window.
+    fn sync_scroll(&mut self, height: usize) {
+        if height == 0 {
+            return;
+        }
+        if self.position < self.scroll_offset {
+            self.scroll_offset = self.position;
+        } else if self.position >= self.scroll_offset + height {
+            self.scroll_offset = self.position + 1 - height;
+        }
+    }
+}
+
+impl Renderable for Tree {
+    fn render(&self, context: &RenderContext) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        self.render_node(&self.root, 0, &[], true, context, &mut segments);
+        segments
+    }
+
+    fn min_width(&self) -> usize {
+        let guide_width = self.guide_style.glyphs().0.chars().count();
+        self.min_width_of(&self.root, 0, guide_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_basic_structure() {
+        let mut tree = Tree::new("Root");
+        tree.add("Child 1");
+        let child2 = tree.add("Child 2");
+        child2.add("Grandchild");
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let plain: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+        let joined = plain.join("\n");
+
+        assert!(joined.contains("Root"));
+        assert!(joined.contains("Child 1"));
+        assert!(joined.contains("Child 2"));
+        assert!(joined.contains("Grandchild"));
+        assert!(joined.contains("├──"));
+        assert!(joined.contains("└──"));
+    }
+
+    #[test]
+    fn test_tree_guide_colors_cycle_by_depth() {
+        let mut tree = Tree::new("Root").guide_styles(vec![
+            Style::new().foreground(crate::style::Color::Red),
+            Style::new().foreground(crate::style::Color::Green),
+        ]);
+        let child = tree.add("Child");
+        child.add("Grandchild");
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+
+        // The grandchild's connector is at depth 1 in the guide-style palette
+        // (index 1 % 2), independent of the label's own style.
+        let grandchild_line = segments
+            .iter()
+            .find(|s| s.plain_text().contains("Grandchild"))
+            .unwrap();
+        assert_eq!(
+            grandchild_line.spans[1].style.foreground,
+            Some(crate::style::Color::Green)
+        );
+    }
+
+    #[test]
+    fn test_cursor_toggle_collapses_children() {
+        let mut tree = Tree::new("Root");
+        let mut cursor = TreeCursor::new();
+        tree.add("Child");
+        cursor.move_down(&tree);
+        cursor.toggle(&mut tree);
+        assert!(!tree.root.children[0].is_expanded());
+    }
+
+    #[test]
+    fn test_cursor_scroll_keeps_cursor_in_view() {
+        let mut tree = Tree::new("Root");
+        for i in 0..10 {
+            tree.add(format!("Child {}", i));
+        }
+        let mut cursor = TreeCursor::new();
+        for _ in 0..8 {
+            cursor.move_down(&tree);
+        }
+
+        let context = RenderContext {
+            width: 40,
+            height: Some(3),
+            direction: Default::default(),
+        };
+        let rows = tree.render_with_cursor(&context, &mut cursor);
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().any(|r| r.plain_text().contains("Child 7")));
+    }
+
+    #[test]
+    fn test_tree_ascii_guide_style() {
+        let mut tree = Tree::new("Root").guide_style(GuideStyle::Ascii);
+        tree.add("Child");
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let joined: String = segments.iter().map(|s| s.plain_text()).collect();
+        assert!(joined.contains("`--") || joined.contains("|--"));
+    }
+
+    #[test]
+    fn test_tree_rounded_guide_style() {
+        let mut tree = Tree::new("Root").guide_style(GuideStyle::Rounded);
+        tree.add("Child");
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let joined: String = segments.iter().map(|s| s.plain_text()).collect();
+        assert!(joined.contains("╰──"));
+    }
+
+    #[test]
+    fn test_node_guide_style_override_wins_over_depth_palette() {
+        let mut tree = Tree::new("Root").guide_styles(vec![Style::new().foreground(crate::style::Color::Red)]);
+        let red_style = Style::new().foreground(crate::style::Color::Red);
+        tree.add(TreeNode::new("Plain"));
+        tree.add(TreeNode::new("Overridden").guide_style(Style::new().foreground(crate::style::Color::Blue)));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+
+        let plain_line = segments.iter().find(|s| s.plain_text().contains("Plain")).unwrap();
+        assert_eq!(plain_line.spans[0].style.foreground, red_style.foreground);
+
+        let overridden_line = segments.iter().find(|s| s.plain_text().contains("Overridden")).unwrap();
+        assert_eq!(
+            overridden_line.spans[0].style.foreground,
+            Some(crate::style::Color::Blue)
+        );
+    }
+
+    #[test]
+    fn test_explicit_icon_renders_between_guide_and_label() {
+        let mut tree = Tree::new("Root");
+        tree.add(TreeNode::new("Cargo.toml").icon("📄"));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let line = segments.iter().find(|s| s.plain_text().contains("Cargo.toml")).unwrap();
+        assert!(line.plain_text().contains("📄 Cargo.toml"));
+    }
+
+    #[test]
+    fn test_auto_icons_distinguishes_dirs_from_files() {
+        let mut tree = Tree::new("Root").auto_icons(true);
+        let src = tree.add(TreeNode::new("src"));
+        src.add(TreeNode::new("main.rs"));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let joined: String = segments.iter().map(|s| s.plain_text()).collect::<Vec<_>>().join("\n");
+
+        assert!(joined.contains("\u{1f4c1} src"));
+        assert!(joined.contains("\u{1f4c4} main.rs"));
+    }
+
+    #[test]
+    fn test_auto_icons_off_by_default() {
+        let mut tree = Tree::new("Root");
+        tree.add(TreeNode::new("main.rs"));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let line = segments.iter().find(|s| s.plain_text().contains("main.rs")).unwrap();
+        assert_eq!(line.plain_text().trim(), "└── main.rs");
+    }
+
+    #[test]
+    fn test_nerdfonts_icon_set_picks_a_different_glyph() {
+        let mut tree = Tree::new("Root").auto_icons(true).icon_set(IconSet::NerdFonts);
+        tree.add(TreeNode::new("main.rs"));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let joined: String = segments.iter().map(|s| s.plain_text()).collect::<Vec<_>>().join("\n");
+        assert!(joined.contains('\u{e7a8}'));
+    }
+
+    #[test]
+    fn test_explicit_icon_wins_over_auto_icons() {
+        let mut tree = Tree::new("Root").auto_icons(true);
+        tree.add(TreeNode::new("main.rs").icon("⭐"));
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let line = segments.iter().find(|s| s.plain_text().contains("main.rs")).unwrap();
+        assert!(line.plain_text().contains("⭐ main.rs"));
+    }
+
+    #[test]
+    fn test_min_width_accounts_for_icon_column() {
+        let with_icon = Tree::new("Root").auto_icons(true);
+        let mut with_icon = with_icon;
+        with_icon.add(TreeNode::new("x"));
+
+        let without_icon = {
+            let mut t = Tree::new("Root");
+            t.add(TreeNode::new("x"));
+            t
+        };
+
+        assert!(with_icon.min_width() > without_icon.min_width());
+    }
+
+    #[test]
+    fn test_rainbow_guides_colors_by_depth_and_wraps() {
+        let mut tree = Tree::new("Root").rainbow_guides(vec![Color::Red, Color::Green]);
+        let child = tree.add("Child");
+        child.add("Grandchild");
+
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+
+        let child_line = segments.iter().find(|s| s.plain_text().contains("Child") && !s.plain_text().contains("Grandchild")).unwrap();
+        assert_eq!(child_line.spans[0].style.foreground, Some(Color::Red));
+
+        let grandchild_line = segments.iter().find(|s| s.plain_text().contains("Grandchild")).unwrap();
+        // Depth 1 guide (the grandchild's own connector) wraps to palette[1 % 2] = Green.
+        assert_eq!(grandchild_line.spans[1].style.foreground, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_default_rainbow_palette_has_seven_colors() {
+        assert_eq!(default_rainbow_palette().len(), 7);
+    }
+
+    fn from_path_test_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "fast_rich_tree_from_path_test_{}_{}",
+            std::process::id(),
+            label
+        ))
+    }
+
+    #[test]
+    fn test_from_path_lists_dirs_before_files_alphabetically() {
+        let dir = from_path_test_dir("ordering");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+        fs::write(dir.join("README.md"), "").unwrap();
+        fs::write(dir.join("src").join("main.rs"), "").unwrap();
+
+        let tree = Tree::from_path(&dir).unwrap();
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let segments = tree.render(&context);
+        let lines: Vec<String> = segments.iter().map(|s| s.plain_text()).collect();
+
+        let src_line = lines.iter().position(|l| l.contains("src")).unwrap();
+        let cargo_line = lines.iter().position(|l| l.contains("Cargo.toml")).unwrap();
+        let readme_line = lines.iter().position(|l| l.contains("README.md")).unwrap();
+        assert!(src_line < cargo_line);
+        assert!(cargo_line < readme_line);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_from_path_with_options_respects_max_depth() {
+        let dir = from_path_test_dir("max_depth");
+        fs::create_dir_all(dir.join("a").join("b")).unwrap();
+        fs::write(dir.join("a").join("b").join("deep.txt"), "").unwrap();
+
+        let options = FromPathOptions {
+            max_depth: Some(1),
+            ..FromPathOptions::default()
+        };
+        let tree = Tree::from_path_with_options(&dir, &options).unwrap();
+        let context = RenderContext {
+            width: 40,
+            height: None,
+            direction: Default::default(),
+        };
+        let joined: String = tree
+            .render(&context)
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(joined.contains("a"));
+        assert!(!joined.contains("deep.txt"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_from_path_with_options_filter_excludes_entries() {
+        let dir = from_path_test_dir("filter");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("keep.txt"), "").unwrap();
+        fs::write(dir.join("skip.log"), "").unwrap();
+
+        let options = FromPathOptions {
+            filter: Some(Arc::new(|path: &Path| {
+                path.extension().and_then(|ext| ext.to_str()) != Some("log")
+            })),
+            ..FromPathOptions::default()
+        };
+        let tree = Tree::from_path_with_options(&dir, &options).unwrap();
+        let joined: String = tree
+            .render(&RenderContext {
+                width: 40,
+                height: None,
+                direction: Default::default(),
+            })
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(joined.contains("keep.txt"));
+        assert!(!joined.contains("skip.log"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_from_path_with_options_show_sizes_annotates_files() {
+        let dir = from_path_test_dir("sizes");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("data.bin"), vec![0u8; 10]).unwrap();
+
+        let options = FromPathOptions {
+            show_sizes: true,
+            ..FromPathOptions::default()
+        };
+        let tree = Tree::from_path_with_options(&dir, &options).unwrap();
+        let joined: String = tree
+            .render(&RenderContext {
+                width: 40,
+                height: None,
+                direction: Default::default(),
+            })
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(joined.contains("data.bin ("));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_from_path_nonexistent_path_yields_an_error_placeholder() {
+        let dir = from_path_test_dir("missing");
+
+        let tree = Tree::from_path(&dir).unwrap();
+        let joined: String = tree
+            .render(&RenderContext {
+                width: 80,
+                height: None,
+                direction: Default::default(),
+            })
+            .iter()
+            .map(|s| s.plain_text())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(joined.contains("missing"));
+    }
+}
@@ -13,6 +13,7 @@ fn test_bar_chart_creation() {
     let context = RenderContext {
         width: 60,
         height: None,
+        direction: Default::default(),
     };
     let segments = chart.render(&context);
 
@@ -44,6 +45,7 @@ fn test_multiple_bars() {
     let context = RenderContext {
         width: 60,
         height: None,
+        direction: Default::default(),
     };
     let segments = chart.render(&context);
 
@@ -56,6 +58,7 @@ fn test_empty_chart() {
     let context = RenderContext {
         width: 60,
         height: None,
+        direction: Default::default(),
     };
     let segments = chart.render(&context);
 